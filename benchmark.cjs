@@ -28,7 +28,7 @@ async function main() {
 
 async function startServer() {
     return new Promise(resolve => {
-        let process = spawn(COMPILER_PATH, ['--server']);
+        let process = spawn(COMPILER_PATH, ['lsp']);
         process.stdout.once('data', () => resolve(process));
     });
 }
@@ -0,0 +1,34 @@
+// Generates the version of the on-disk parse cache (see `src/utils/parse_cache.rs`).
+// It is a hash of the compiler version and of the content of all parsed item definitions, attributes included,
+// so that any change to the grammar or to the cached representation of the items invalidates the existing cache entries.
+
+use std::{env, fs, path::Path, collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+
+const ITEMS_DIR_PATH : &str = "src/items";
+const OUTPUT_FILE_NAME : &str = "parse_cache_version.rs";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", ITEMS_DIR_PATH);
+
+    let mut file_paths : Vec<_> = fs::read_dir(ITEMS_DIR_PATH).unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect();
+
+    file_paths.sort();
+
+    let mut hasher = DefaultHasher::new();
+
+    env::var("CARGO_PKG_VERSION").unwrap().hash(&mut hasher);
+
+    for file_path in &file_paths {
+        file_path.file_name().hash(&mut hasher);
+        fs::read_to_string(file_path).unwrap().hash(&mut hasher);
+    }
+
+    let code = format!("pub const PARSE_CACHE_VERSION : u64 = {};\n", hasher.finish());
+    let output_path = Path::new(&env::var("OUT_DIR").unwrap()).join(OUTPUT_FILE_NAME);
+
+    fs::write(output_path, code).unwrap();
+}
@@ -1,5 +1,3 @@
-export const WAT2WASM_BINARY_PATH = 'wat2wasm';
-export const WAT2WASM_OPTIONS = ['--debug-names'];
 export const OUTPUT_WAT_FILE_NAME = 'module.wat';
 export const OUTPUT_WASM_FILE_NAME = 'module.wasm';
 
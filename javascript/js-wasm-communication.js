@@ -45,6 +45,16 @@ export function writeNetworkEventToBuffer(event, buffer) {
     buffer.writeBuffer(messagePayload);
 }
 
+export function writeNumberToMemory(memoryAsInt32Array, value, resultAddr) {
+    let str = '' + value;
+
+    memoryAsInt32Array[resultAddr] = str.length;
+
+    for (let i = 0; i < str.length; ++i) {
+        memoryAsInt32Array[resultAddr + i + 2] = str.charCodeAt(i);
+    }
+}
+
 export function readStringFromMemory(memoryAsInt32Array, stringAddr) {
     if (!stringAddr) {
         return null;
@@ -6,12 +6,14 @@ import { camelToKebabCase } from './utils.js';
 
 const STRING_TYPE = { check: x => typeof x === 'string', name: 'string' };
 const BOOL_TYPE = { check: x => typeof x === 'boolean', name: 'boolean' };
+const BOOL_OR_STRING_TYPE = { check: x => typeof x === 'boolean' || typeof x === 'string', name: 'boolean or string' };
 const POSITIVE_INT = { check: x => typeof x === 'number' && x >= 0 && x % 1 === 0, name: 'positive integer' };
 const STRING_ARRAY = { check: x => Array.isArray(x) && x.every(item => typeof item === 'string' && item), name: 'string array' };
 
 const FIELDS = {
     name: [STRING_TYPE, root => path.basename(root)],
-    framework: [BOOL_TYPE, false],
+    framework: [BOOL_OR_STRING_TYPE, false],
+    prelude: [STRING_TYPE, ''],
     title: [STRING_TYPE, ''],
     port: [POSITIVE_INT, DEFAULT_HTTP_PORT],
     remote: [STRING_TYPE, ''],
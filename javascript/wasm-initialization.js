@@ -1,5 +1,5 @@
 import { FileSystemManager } from './file-system-manager.js';
-import { KEYBOARD_CODES, readStringFromMemory, writeNetworkEventToBuffer, writeWindowEventToBuffer } from './js-wasm-communication.js';
+import { KEYBOARD_CODES, readStringFromMemory, writeNetworkEventToBuffer, writeNumberToMemory, writeWindowEventToBuffer } from './js-wasm-communication.js';
 import { KeyboardManager } from './keyboard-manager.js';
 import { MemoryBuffer } from './memory-buffer.js';
 import { MemoryManager } from './memory-manager.js';
@@ -57,14 +57,10 @@ async function getWasmImportsObject(env) {
                 }
             },
             float_to_string(value, resultAddr) {
-                let memory = env.getMemory();
-                let str = '' + value;
-
-                memory[resultAddr] = str.length;
-
-                for (let i = 0; i < str.length; ++i) {
-                    memory[resultAddr + i + 2] = str.charCodeAt(i);
-                }
+                writeNumberToMemory(env.getMemory(), value, resultAddr);
+            },
+            double_to_string(value, resultAddr) {
+                writeNumberToMemory(env.getMemory(), value, resultAddr);
             }
         },
         env: {
@@ -81,6 +77,10 @@ async function getWasmImportsObject(env) {
             get_current_time() {
                 return Date.now();
             },
+
+            get_current_timestamp() {
+                return Date.now();
+            },
             
             init_window(aspectRatio) {
                 windowManager.init(aspectRatio);
@@ -4,8 +4,7 @@ import { execSync } from 'child_process';
 import { copyFile, copyFileSync, cpSync, existsSync, mkdirSync, readdirSync, readFileSync, rmSync, statSync, writeFileSync } from 'fs';
 import { join, resolve } from 'path';
 import esbuild from 'esbuild';
-import wabt from 'wabt';
-import { ASSETS_DIR_NAME, OUTPUT_WASM_FILE_NAME, OUTPUT_WAT_FILE_NAME, SERVER_EXTERNAL_MODULES } from '../javascript/constants.js';
+import { ASSETS_DIR_NAME, OUTPUT_WASM_FILE_NAME, SERVER_EXTERNAL_MODULES } from '../javascript/constants.js';
 import { ROOT_DIR_PATH } from '../javascript/paths.js';
 import chalk from 'chalk';
 
@@ -33,7 +32,6 @@ async function main() {
 
     let buildDir = resolve(inputDir, 'build');
     let publicDir = resolve(buildDir, 'public');
-    let watPath = join(buildDir, OUTPUT_WAT_FILE_NAME);
     let wasmPath = join(buildDir, OUTPUT_WASM_FILE_NAME);
     let clientOutputPath = join(publicDir, 'client-bundle.js');
     let serverOutputPath = join(buildDir, 'server-bundle.js');
@@ -44,11 +42,8 @@ async function main() {
     logStep(`Creating build directory`);
     mkdirSync(publicDir, { recursive: true });
 
-    logStep(`Compiling source to WAT`);
-    runCommand(`${COMPILER_PATH} ${inputDir} ${watPath} --app --silent`);
-
-    logStep(`Compiling WAT to WASM`);
-    await wat2wasm(watPath, wasmPath);
+    logStep(`Compiling source to WASM`);
+    runCommand(`${COMPILER_PATH} build ${inputDir} ${wasmPath} --app --emit=wasm --silent`);
     copyFileSync(wasmPath, join(publicDir, OUTPUT_WASM_FILE_NAME));
 
     logStep(`Compiling client bundle`);
@@ -94,15 +89,6 @@ function runCommand(command) {
     }
 }
 
-async function wat2wasm(inputPath, outputPath) {
-    let api = await wabt();
-    let wat = readFileSync(inputPath);
-    let module = api.parseWat('module', wat);
-    let { buffer } = module.toBinary({ write_debug_names: true });
-
-    writeFileSync(outputPath, buffer, 'binary');
-}
-
 async function buildBundle(inputPath, outputPath, isServer) {
     return esbuild
         .build({
@@ -17,9 +17,6 @@ const WORKSHOP_DIR = path.join(ROOT_DIR, 'workshop');
 const BUILD_DIR = path.join(WORKSHOP_DIR, 'build');
 const SRC_DIR = path.join(WORKSHOP_DIR, 'src');
 
-const WAT2WASM_BINARY_PATH = 'wat2wasm';
-const WAT2WASM_OPTIONS = [];
-
 const MOCHA_BINARY_PATH = path.join(ROOT_DIR, 'node_modules', '.bin', 'mocha');
 
 const SRC_DIR_NAME = 'src';
@@ -98,7 +95,7 @@ async function main() {
             command = `1##validate##${commandLineNames[0] || WORKSHOP_DIR}##`;
         }
 
-        let { result } = runCommand(`${compilerPath} --server --command=${command}`);
+        let { result } = runCommand(`${compilerPath} lsp --command=${command}`);
 
         console.log(result.split('\n')[0].substring(2).split(', ').join('\n'));
     } else if (clean) {
@@ -194,19 +191,19 @@ function compileLotus({ inputPath, outputPath, inheritStdio, showDetails, mode,
     let compilerPath = path.join(ROOT_DIR, 'target', mode, 'lotus-compiler');
     let silentOption = inheritStdio ? '' : '--silent';
     let detailsOption = showDetails ? '--details' : '';
-    let validateOption = validate ? '--validate' : '';
-    let benchmarkOption = benchmark ? '--benchmark' : '';
-    let command = `${compilerPath} ${inputPath} ${outputPath} ${silentOption} ${detailsOption} ${validateOption} ${benchmarkOption}`;
+    let command = `${compilerPath} build ${inputPath} ${outputPath} ${silentOption} ${detailsOption}`;
+
+    if (benchmark) {
+        command = `${compilerPath} bench ${inputPath}`;
+    } else if (validate) {
+        command = `${compilerPath} check ${inputPath} ${silentOption} ${detailsOption}`;
+    }
 
     // console.log(command);
 
     return runCommand(command, inheritStdio);
 }
 
-function compileWat(inputPath, outputPath, inheritStdio) {
-    return runCommand(`${WAT2WASM_BINARY_PATH} ${WAT2WASM_OPTIONS.join(' ')} ${inputPath} -o ${outputPath} --debug-names`, inheritStdio);
-}
-
 async function runWasm(wasmPath, inheritStdio, displayMemory) {
     let lines = [];
     let log = inheritStdio ? console.log : value => lines.push(value.toString());
@@ -237,16 +234,13 @@ async function runWasm(wasmPath, inheritStdio, displayMemory) {
 async function runTest(sourceDirPath, buildDirectory, { inheritStdio = false, displayMemory = false, onlyCompileWat = false, showDetails = false, mode = 'debug', validate = false, benchmark = false } = {}) {
     let watPath = path.join(buildDirectory, WAT_FILE_NAME);
     let wasmPath = path.join(buildDirectory, WASM_FILE_NAME);
+    let outputPath = onlyCompileWat ? watPath : wasmPath;
     let commandChain = [
-        () => compileLotus({ inputPath: sourceDirPath, outputPath: watPath, inheritStdio, mode, showDetails, validate, benchmark }),
-        () => compileWat(watPath, wasmPath, inheritStdio),
+        () => compileLotus({ inputPath: sourceDirPath, outputPath, inheritStdio, mode, showDetails, validate, benchmark }),
         () => runWasm(wasmPath, inheritStdio, displayMemory)
     ];
 
-    if (validate || benchmark) {
-        commandChain.pop();
-        commandChain.pop();
-    } else if (onlyCompileWat) {
+    if (validate || benchmark || onlyCompileWat) {
         commandChain.pop();
     }
 
@@ -0,0 +1,42 @@
+#[derive(Debug, Clone, Copy)]
+pub struct CommandFlag {
+    pub name: &'static str,
+    pub value_name: Option<&'static str>,
+    pub description: &'static str,
+}
+
+pub const APP_FLAG : CommandFlag = CommandFlag { name: "--app", value_name: None, description: "compile as an app (client & server) instead of a command line program" };
+pub const SILENT_FLAG : CommandFlag = CommandFlag { name: "--silent", value_name: None, description: "do not print anything on success" };
+pub const DETAILS_FLAG : CommandFlag = CommandFlag { name: "--details", value_name: None, description: "print the duration of each compilation step" };
+pub const FRAMEWORK_FLAG : CommandFlag = CommandFlag { name: "--framework", value_name: Some("path"), description: "use an alternate framework directory" };
+pub const PRELUDE_FLAG : CommandFlag = CommandFlag { name: "--prelude", value_name: Some("path"), description: "use an alternate prelude directory" };
+pub const MESSAGE_FORMAT_FLAG : CommandFlag = CommandFlag { name: "--message-format", value_name: Some("human|json"), description: "format of the diagnostics (`json` prints one record per line)" };
+pub const EMIT_FLAG : CommandFlag = CommandFlag { name: "--emit", value_name: Some("wat|wasm"), description: "output format (default: inferred from the output file extension)" };
+pub const WATCH_FLAG : CommandFlag = CommandFlag { name: "--watch", value_name: None, description: "keep running and compile again whenever a source file changes" };
+pub const DENY_WARNINGS_FLAG : CommandFlag = CommandFlag { name: "--deny-warnings", value_name: None, description: "treat warnings as errors" };
+pub const ITERATIONS_FLAG : CommandFlag = CommandFlag { name: "--iterations", value_name: Some("count"), description: "number of validations to run (default: 3)" };
+pub const COMMAND_FLAG : CommandFlag = CommandFlag { name: "--command", value_name: Some("command"), description: "run a single language server command, print its output and exit" };
+pub const STDIO_FLAG : CommandFlag = CommandFlag { name: "--stdio", value_name: None, description: "speak the Language Server Protocol over the standard input and output" };
+pub const FILTER_FLAG : CommandFlag = CommandFlag { name: "--filter", value_name: Some("pattern"), description: "only run the tests whose name contains the pattern (comma-separated list)" };
+pub const CHECK_FLAG : CommandFlag = CommandFlag { name: "--check", value_name: None, description: "do not write the files, only list the ones that are not formatted" };
+pub const HELP_FLAG : CommandFlag = CommandFlag { name: "--help", value_name: None, description: "print help" };
+
+impl CommandFlag {
+    pub fn get_display_name(&self) -> String {
+        match self.value_name {
+            Some(value_name) => format!("{}=<{}>", self.name, value_name),
+            None => self.name.to_string(),
+        }
+    }
+
+    pub fn matches(&self, arg: &str) -> bool {
+        match self.value_name {
+            Some(_) => arg.starts_with(self.name) && arg.as_bytes().get(self.name.len()) == Some(&b'='),
+            None => arg == self.name,
+        }
+    }
+
+    pub fn get_value<'a>(&self, arg: &'a str) -> &'a str {
+        &arg[self.name.len() + 1..]
+    }
+}
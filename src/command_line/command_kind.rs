@@ -0,0 +1,67 @@
+use enum_iterator::Sequence;
+use super::{CommandFlag, APP_FLAG, SILENT_FLAG, DETAILS_FLAG, FRAMEWORK_FLAG, PRELUDE_FLAG, MESSAGE_FORMAT_FLAG, EMIT_FLAG, WATCH_FLAG, DENY_WARNINGS_FLAG, ITERATIONS_FLAG, COMMAND_FLAG, STDIO_FLAG, FILTER_FLAG, CHECK_FLAG};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Sequence)]
+pub enum CommandKind {
+    Build,
+    Check,
+    Run,
+    Lsp,
+    Bench,
+    Test,
+    Fmt,
+}
+
+impl CommandKind {
+    pub fn from_str(string: &str) -> Option<Self> {
+        enum_iterator::all::<Self>().find(|kind| kind.get_name() == string)
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            CommandKind::Build => "build",
+            CommandKind::Check => "check",
+            CommandKind::Run => "run",
+            CommandKind::Lsp => "lsp",
+            CommandKind::Bench => "bench",
+            CommandKind::Test => "test",
+            CommandKind::Fmt => "fmt",
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            CommandKind::Build => "compile a package into a WebAssembly module",
+            CommandKind::Check => "check a package for errors without generating any output",
+            CommandKind::Run => "compile a command line package and execute it with the embedded WebAssembly interpreter",
+            CommandKind::Lsp => "start the language server",
+            CommandKind::Bench => "measure the time taken to validate a package",
+            CommandKind::Test => "compile and run each test of a directory, comparing its output with `output.txt`",
+            CommandKind::Fmt => "format the source files of a directory (or a single source file)",
+        }
+    }
+
+    pub fn get_arguments(&self) -> &'static [&'static str] {
+        match self {
+            CommandKind::Build => &["input_path", "output_path"],
+            CommandKind::Check => &["input_path"],
+            CommandKind::Run => &["input_path"],
+            CommandKind::Lsp => &[],
+            CommandKind::Bench => &["input_path"],
+            CommandKind::Test => &["test_directory_path"],
+            CommandKind::Fmt => &["input_path"],
+        }
+    }
+
+    pub fn get_flags(&self) -> &'static [CommandFlag] {
+        match self {
+            CommandKind::Build => &[APP_FLAG, EMIT_FLAG, WATCH_FLAG, SILENT_FLAG, DETAILS_FLAG, DENY_WARNINGS_FLAG, MESSAGE_FORMAT_FLAG, FRAMEWORK_FLAG, PRELUDE_FLAG],
+            CommandKind::Check => &[WATCH_FLAG, SILENT_FLAG, DETAILS_FLAG, DENY_WARNINGS_FLAG, MESSAGE_FORMAT_FLAG, FRAMEWORK_FLAG, PRELUDE_FLAG],
+            CommandKind::Run => &[SILENT_FLAG, DENY_WARNINGS_FLAG, MESSAGE_FORMAT_FLAG, FRAMEWORK_FLAG, PRELUDE_FLAG],
+            CommandKind::Lsp => &[STDIO_FLAG, COMMAND_FLAG],
+            CommandKind::Bench => &[ITERATIONS_FLAG],
+            CommandKind::Test => &[FILTER_FLAG, SILENT_FLAG, DETAILS_FLAG, PRELUDE_FLAG],
+            CommandKind::Fmt => &[CHECK_FLAG, SILENT_FLAG],
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmitKind {
+    Wat,
+    Wasm
+}
+
+impl EmitKind {
+    pub fn from_str(string: &str) -> Option<Self> {
+        match string {
+            "wat" => Some(Self::Wat),
+            "wasm" => Some(Self::Wasm),
+            _ => None
+        }
+    }
+
+    pub fn from_output_path(path: &str) -> Self {
+        match Path::new(path).extension().and_then(|extension| extension.to_str()) {
+            Some("wasm") => Self::Wasm,
+            _ => Self::Wat
+        }
+    }
+}
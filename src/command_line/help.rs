@@ -0,0 +1,45 @@
+use colored::Colorize;
+use enum_iterator::all;
+use super::{CommandKind, HELP_FLAG};
+
+pub fn get_usage_string(program_name: &str, kind: CommandKind) -> String {
+    let mut result = format!("{} {}", program_name, kind.get_name());
+
+    for argument in kind.get_arguments() {
+        result.push_str(&format!(" <{}>", argument));
+    }
+
+    if !kind.get_flags().is_empty() {
+        result.push_str(" [options]");
+    }
+
+    result
+}
+
+pub fn display_help(program_name: &str, kind: Option<CommandKind>) {
+    match kind {
+        Some(kind) => {
+            println!("{}", kind.get_description());
+            println!();
+            println!("{} {}", "usage:".magenta().bold(), get_usage_string(program_name, kind));
+            println!();
+            println!("{}", "options:".magenta().bold());
+
+            for flag in kind.get_flags().iter().chain([HELP_FLAG].iter()) {
+                println!("  {: <24} {}", flag.get_display_name().bold(), flag.description);
+            }
+        },
+        None => {
+            println!("{} {} <command> [arguments] [options]", "usage:".magenta().bold(), program_name);
+            println!();
+            println!("{}", "commands:".magenta().bold());
+
+            for kind in all::<CommandKind>() {
+                println!("  {: <10} {}", kind.get_name().bold(), kind.get_description());
+            }
+
+            println!();
+            println!("Run `{} help <command>` for more information on a specific command.", program_name);
+        },
+    }
+}
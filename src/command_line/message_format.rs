@@ -0,0 +1,21 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    Human,
+    Json
+}
+
+impl Default for MessageFormat {
+    fn default() -> Self {
+        Self::Human
+    }
+}
+
+impl MessageFormat {
+    pub fn from_str(string: &str) -> Option<Self> {
+        match string {
+            "human" => Some(Self::Human),
+            "json" => Some(Self::Json),
+            _ => None
+        }
+    }
+}
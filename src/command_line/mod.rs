@@ -2,8 +2,18 @@ mod options;
 mod log_level;
 mod timer;
 mod program_step;
+mod command_kind;
+mod command_flag;
+mod help;
+mod message_format;
+mod emit_kind;
 
 pub use options::*;
 pub use log_level::*;
 pub use timer::*;
-pub use program_step::*;
\ No newline at end of file
+pub use program_step::*;
+pub use command_kind::*;
+pub use command_flag::*;
+pub use help::*;
+pub use message_format::*;
+pub use emit_kind::*;
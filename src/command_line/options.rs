@@ -1,90 +1,266 @@
-use std::{path::{PathBuf, Path}};
-use crate::{program::{SourceDirectory, PRELUDE_NAMESPACE, SELF_NAMESPACE}, language_server::LanguageServerCommandKind};
-use super::{LogLevel};
+use std::path::Path;
+use super::{LogLevel, MessageFormat, EmitKind, EMIT_FLAG, WATCH_FLAG, DENY_WARNINGS_FLAG, CommandKind, CommandFlag, APP_FLAG, FRAMEWORK_FLAG, PRELUDE_FLAG, MESSAGE_FORMAT_FLAG, ITERATIONS_FLAG, COMMAND_FLAG, STDIO_FLAG, HELP_FLAG, FILTER_FLAG, CHECK_FLAG};
+
+const DEFAULT_BENCHMARK_ITERATIONS : usize = 3;
+
+#[derive(Debug)]
+pub enum CommandLineOptions {
+    Build(CompileOptions),
+    Check(CompileOptions),
+    Run(CompileOptions),
+    Lsp(LspOptions),
+    Bench(BenchOptions),
+    Test(TestOptions),
+    Fmt(FmtOptions),
+    Help(Option<CommandKind>),
+}
 
 #[derive(Debug)]
-pub struct CommandLineOptions {
-    pub input_path: Option<String>,
+pub struct CompileOptions {
+    pub input_path: String,
     pub output_path: Option<String>,
+    pub emit: EmitKind,
     pub framework: Option<String>,
+    pub prelude: Option<String>,
     pub app_mode: bool,
+    pub watch: bool,
+    pub deny_warnings: bool,
     pub log_level: LogLevel,
-    pub validate: bool,
-    pub run_as_server: bool,
-    pub run_benchmark: bool,
+    pub message_format: MessageFormat,
+}
+
+#[derive(Debug)]
+pub struct LspOptions {
     pub command: Option<String>,
+    pub stdio: bool,
+}
+
+#[derive(Debug)]
+pub struct BenchOptions {
+    pub input_path: String,
+    pub iterations: usize,
+}
+
+#[derive(Debug)]
+pub struct TestOptions {
+    pub test_directory_path: String,
+    pub filters: Vec<String>,
+    pub prelude: Option<String>,
+    pub log_level: LogLevel,
+}
+
+#[derive(Debug)]
+pub struct FmtOptions {
+    pub input_path: String,
+    pub check: bool,
+    pub log_level: LogLevel,
+}
+
+#[derive(Debug)]
+pub struct CommandLineError {
+    pub command: Option<CommandKind>,
+    pub message: String,
+}
+
+struct ParsedArguments {
+    positional: Vec<String>,
+    flags: Vec<(CommandFlag, String)>,
+    log_level: LogLevel,
 }
 
 impl CommandLineOptions {
-    pub fn parse_from_args(args: Vec<String>) -> Self {
-        let mut options = Self {
-            input_path: None,
-            output_path: None,
-            framework: None,
-            app_mode: false,
-            log_level: LogLevel::Short,
-            validate: false,
-            run_as_server: false,
-            run_benchmark: false,
-            command: None,
+    pub fn parse_from_args(args: Vec<String>) -> Result<Self, CommandLineError> {
+        let command_name = match args.get(1) {
+            Some(name) => name.as_str(),
+            None => return Ok(Self::Help(None)),
+        };
+
+        if command_name == HELP_FLAG.name || command_name == "-h" {
+            return Ok(Self::Help(None));
+        }
+
+        if command_name == "help" {
+            return match args.get(2) {
+                Some(name) => match CommandKind::from_str(name) {
+                    Some(kind) => Ok(Self::Help(Some(kind))),
+                    None => Err(CommandLineError::new(None, format!("unknown command `{}`", name))),
+                },
+                None => Ok(Self::Help(None)),
+            };
+        }
+
+        let kind = match CommandKind::from_str(command_name) {
+            Some(kind) => kind,
+            None => return Err(CommandLineError::new(None, format!("unknown command `{}`", command_name))),
+        };
+
+        let arguments = match parse_arguments(kind, &args[2..])? {
+            Some(arguments) => arguments,
+            None => return Ok(Self::Help(Some(kind))),
         };
 
-        for arg in &args[1..] {
-            match is_option(arg) {
-                true => {
-                    if let Some(log_level) = LogLevel::from_command_line_arg(arg) {
-                        options.log_level = log_level;
-                    } else if arg == "--app" {
-                        options.app_mode = true;
-                    } else if arg == "--validate" || arg == "-v" {
-                        options.validate = true;
-                    } else if arg == "--benchmark" {
-                        options.run_benchmark = true;
-                    } else if arg == "--server" {
-                        options.run_as_server = true;
-                    } else if let Some(command) = get_option_value(arg, "--command") {
-                        options.command = Some(command);
-                    } else if let Some(framework) = get_option_value(arg, "--framework") {
-                        options.framework = Some(framework);
-                    } else {
-                        eprintln!("invalid option `{}`", arg);
-                    }
+        let options = match kind {
+            CommandKind::Build => Self::Build(CompileOptions {
+                input_path: to_absolute_path(kind, &arguments.positional[0])?,
+                output_path: Some(arguments.positional[1].clone()),
+                emit: match arguments.get_flag_value(EMIT_FLAG) {
+                    Some(value) => match EmitKind::from_str(&value) {
+                        Some(emit) => emit,
+                        None => return Err(CommandLineError::new(Some(kind), format!("invalid value `{}` for option `{}`", value, EMIT_FLAG.name))),
+                    },
+                    None => EmitKind::from_output_path(&arguments.positional[1]),
+                },
+                framework: get_path_flag_value(kind, &arguments, FRAMEWORK_FLAG)?,
+                prelude: get_path_flag_value(kind, &arguments, PRELUDE_FLAG)?,
+                app_mode: arguments.has_flag(APP_FLAG),
+                watch: arguments.has_flag(WATCH_FLAG),
+                deny_warnings: arguments.has_flag(DENY_WARNINGS_FLAG),
+                log_level: arguments.log_level,
+                message_format: get_message_format(kind, &arguments)?,
+            }),
+            CommandKind::Check => Self::Check(CompileOptions {
+                input_path: to_absolute_path(kind, &arguments.positional[0])?,
+                output_path: None,
+                emit: EmitKind::Wat,
+                framework: get_path_flag_value(kind, &arguments, FRAMEWORK_FLAG)?,
+                prelude: get_path_flag_value(kind, &arguments, PRELUDE_FLAG)?,
+                app_mode: false,
+                watch: arguments.has_flag(WATCH_FLAG),
+                deny_warnings: arguments.has_flag(DENY_WARNINGS_FLAG),
+                log_level: arguments.log_level,
+                message_format: get_message_format(kind, &arguments)?,
+            }),
+            CommandKind::Run => Self::Run(CompileOptions {
+                input_path: to_absolute_path(kind, &arguments.positional[0])?,
+                output_path: None,
+                emit: EmitKind::Wasm,
+                framework: get_path_flag_value(kind, &arguments, FRAMEWORK_FLAG)?,
+                prelude: get_path_flag_value(kind, &arguments, PRELUDE_FLAG)?,
+                app_mode: false,
+                watch: false,
+                deny_warnings: arguments.has_flag(DENY_WARNINGS_FLAG),
+                log_level: arguments.log_level,
+                message_format: get_message_format(kind, &arguments)?,
+            }),
+            CommandKind::Lsp => Self::Lsp(LspOptions {
+                command: arguments.get_flag_value(COMMAND_FLAG),
+                stdio: arguments.has_flag(STDIO_FLAG),
+            }),
+            CommandKind::Bench => Self::Bench(BenchOptions {
+                input_path: to_absolute_path(kind, &arguments.positional[0])?,
+                iterations: match arguments.get_flag_value(ITERATIONS_FLAG) {
+                    Some(value) => match value.parse::<usize>() {
+                        Ok(count) if count > 0 => count,
+                        _ => return Err(CommandLineError::new(Some(kind), format!("invalid value `{}` for option `{}`", value, ITERATIONS_FLAG.name))),
+                    },
+                    None => DEFAULT_BENCHMARK_ITERATIONS,
                 },
-                false => {
-                    if options.input_path.is_none() {
-                        options.input_path = string_to_absolute_path(&arg);
-                    } else if options.output_path.is_none() {
-                        options.output_path = Some(arg.clone());
-                    }
+            }),
+            CommandKind::Test => Self::Test(TestOptions {
+                test_directory_path: to_absolute_path(kind, &arguments.positional[0])?,
+                filters: match arguments.get_flag_value(FILTER_FLAG) {
+                    Some(value) => value.split(',').filter(|pattern| !pattern.is_empty()).map(|pattern| pattern.to_string()).collect(),
+                    None => vec![],
                 },
+                prelude: get_path_flag_value(kind, &arguments, PRELUDE_FLAG)?,
+                log_level: arguments.log_level,
+            }),
+            CommandKind::Fmt => Self::Fmt(FmtOptions {
+                input_path: to_absolute_path(kind, &arguments.positional[0])?,
+                check: arguments.has_flag(CHECK_FLAG),
+                log_level: arguments.log_level,
+            }),
+        };
+
+        Ok(options)
+    }
+}
+
+impl ParsedArguments {
+    fn has_flag(&self, flag: CommandFlag) -> bool {
+        self.flags.iter().any(|(f, _)| f.name == flag.name)
+    }
+
+    fn get_flag_value(&self, flag: CommandFlag) -> Option<String> {
+        self.flags.iter().rev().find(|(f, _)| f.name == flag.name).map(|(_, value)| value.clone())
+    }
+}
+
+impl CommandLineError {
+    pub fn new(command: Option<CommandKind>, message: String) -> Self {
+        Self { command, message }
+    }
+}
+
+fn parse_arguments(kind: CommandKind, args: &[String]) -> Result<Option<ParsedArguments>, CommandLineError> {
+    let expected_arguments = kind.get_arguments();
+    let allowed_flags = kind.get_flags();
+    let mut result = ParsedArguments {
+        positional: vec![],
+        flags: vec![],
+        log_level: LogLevel::default(),
+    };
+
+    for arg in args {
+        if !is_option(arg) {
+            if result.positional.len() == expected_arguments.len() {
+                return Err(CommandLineError::new(Some(kind), format!("unexpected argument `{}`", arg)));
             }
-        }
 
-        if options.validate && options.output_path.is_none() {
-            options.output_path = Some(String::new());
+            result.positional.push(arg.clone());
+        } else if arg == HELP_FLAG.name || arg == "-h" {
+            return Ok(None);
+        } else if let Some(flag) = allowed_flags.iter().find(|flag| flag.matches(arg)) {
+            if let Some(log_level) = LogLevel::from_command_line_arg(arg) {
+                result.log_level = log_level;
+            }
+
+            let value = match flag.value_name {
+                Some(_) => flag.get_value(arg).to_string(),
+                None => String::new(),
+            };
+
+            result.flags.push((*flag, value));
+        } else if let Some(flag) = allowed_flags.iter().find(|flag| flag.value_name.is_some() && arg == flag.name) {
+            return Err(CommandLineError::new(Some(kind), format!("option `{}` requires a value (`{}`)", arg, flag.get_display_name())));
+        } else {
+            return Err(CommandLineError::new(Some(kind), format!("unknown option `{}`", arg)));
         }
+    }
 
-        options
+    if let Some(missing) = expected_arguments.get(result.positional.len()) {
+        return Err(CommandLineError::new(Some(kind), format!("missing argument `<{}>`", missing)));
     }
+
+    Ok(Some(result))
 }
 
-fn string_to_absolute_path(string: &str) -> Option<String> {
+fn get_message_format(kind: CommandKind, arguments: &ParsedArguments) -> Result<MessageFormat, CommandLineError> {
+    match arguments.get_flag_value(MESSAGE_FORMAT_FLAG) {
+        Some(value) => match MessageFormat::from_str(&value) {
+            Some(message_format) => Ok(message_format),
+            None => Err(CommandLineError::new(Some(kind), format!("invalid value `{}` for option `{}`", value, MESSAGE_FORMAT_FLAG.name))),
+        },
+        None => Ok(MessageFormat::default()),
+    }
+}
+
+fn get_path_flag_value(kind: CommandKind, arguments: &ParsedArguments, flag: CommandFlag) -> Result<Option<String>, CommandLineError> {
+    match arguments.get_flag_value(flag) {
+        Some(value) => to_absolute_path(kind, &value).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn to_absolute_path(kind: CommandKind, string: &str) -> Result<String, CommandLineError> {
     let path = Path::new(string);
 
     match path.canonicalize() {
-        Ok(path_buf) => path_buf.as_os_str().to_str().map(|s| s.to_string()),
-        Err(error) => None,
+        Ok(path_buf) => Ok(path_buf.to_string_lossy().to_string()),
+        Err(_) => Err(CommandLineError::new(Some(kind), format!("path `{}` does not exist", string))),
     }
 }
 
 fn is_option(string: &str) -> bool {
-    string.starts_with("-")
+    string.starts_with('-')
 }
-
-fn get_option_value(arg: &str, option_name: &str) -> Option<String> {
-    if arg.starts_with(option_name) && arg.as_bytes().get(option_name.len()).unwrap_or(&0) == &(b'=') {
-        return Some(arg[option_name.len() + 1..].to_string());
-    }
-
-    None
-}
\ No newline at end of file
@@ -5,6 +5,7 @@ pub enum ProgramStep {
     Process,
     Resolve,
     Stringify,
+    Encode,
     Write,
     Total
 }
@@ -17,6 +18,7 @@ impl ProgramStep {
             ProgramStep::Process => "process",
             ProgramStep::Resolve => "resolve",
             ProgramStep::Stringify => "stringify",
+            ProgramStep::Encode => "encode",
             ProgramStep::Write => "write",
             ProgramStep::Total => "total",
         }
@@ -29,6 +31,7 @@ impl ProgramStep {
             ProgramStep::Process => false,
             ProgramStep::Resolve => false,
             ProgramStep::Stringify => false,
+            ProgramStep::Encode => false,
             ProgramStep::Write => true,
             ProgramStep::Total => false,
         }
@@ -0,0 +1,96 @@
+use std::ops::Range;
+use crate::program::COMMENT_START_TOKEN;
+
+// Returns the byte range of every `//` comment of the file, skipping the content of string, char and template string literals
+pub fn collect_comments(content: &str) -> Vec<Range<usize>> {
+    let mut result = vec![];
+    let bytes = content.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        index = match bytes[index] {
+            b'"' => skip_quoted(bytes, index),
+            b'\'' => skip_char_or_label(bytes, index),
+            b'`' => skip_template_string(bytes, index),
+            _ if content[index..].starts_with(COMMENT_START_TOKEN) => {
+                let end = content[index..].find('\n').map(|offset| index + offset).unwrap_or(content.len());
+
+                result.push(index..index + content[index..end].trim_end().len());
+                end
+            },
+            _ => index + 1,
+        };
+    }
+
+    result
+}
+
+fn skip_quoted(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut index = start + 1;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'\n' => return index,
+            byte if byte == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+// A loop label (`'outer`) is a quote followed by an identifier, with no closing quote
+fn skip_char_or_label(bytes: &[u8], start: usize) -> usize {
+    let mut index = start + 1;
+
+    while index < bytes.len() && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_') {
+        index += 1;
+    }
+
+    match index > start + 1 && bytes.get(index) != Some(&b'\'') {
+        true => index,
+        false => skip_quoted(bytes, start),
+    }
+}
+
+fn skip_template_string(bytes: &[u8], start: usize) -> usize {
+    let mut index = start + 1;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'`' => return index + 1,
+            b'$' if bytes.get(index + 1) == Some(&b'{') => {
+                let mut depth = 0;
+
+                index += 1;
+
+                while index < bytes.len() {
+                    match bytes[index] {
+                        b'{' => depth += 1,
+                        b'}' => {
+                            depth -= 1;
+
+                            if depth == 0 {
+                                break;
+                            }
+                        },
+                        b'"' | b'\'' => {
+                            index = skip_quoted(bytes, index);
+                            continue;
+                        },
+                        _ => {}
+                    }
+
+                    index += 1;
+                }
+
+                index += 1;
+            },
+            _ => index += 1,
+        }
+    }
+
+    bytes.len()
+}
@@ -0,0 +1 @@
+pub const INDENT : &'static str = "    ";
@@ -0,0 +1,60 @@
+use parsable::Parsable;
+use crate::items::{Identifier, Word, ParsedNoneLiteral, ParsedBooleanLiteral, ParsedNumberLiteral, ParsedCharLiteral, ParsedStringLiteral, ParsedColorLiteral, ParsedMacroExpression, ParsedMacroIdentifier, ParsedMacroType, ParsedVisibility, ParsedMethodQualifier, ParsedMethodMetaQualifier, ParsedVarDeclarationQualifier, ParsedBinaryOperator, ParsedUnaryOperator, ParsedAssignmentOperator, ParsedActionKeyword, ParsedVarPrefix, ParsedTypeSuffix, ParsedStackTypeDeclaration, ParsedEventCallbackQualifierKeyword, ParsedRootTagDeclaration, ParsedLoadDirective, ParsedWildcardToken, ParsedTopLevelBlockInProgress, ParsedLoopLabel, FlexWordItem, FlexPrefixedWordItem};
+use super::Formatter;
+
+pub trait Format {
+    fn format(&self, f: &mut Formatter);
+}
+
+impl<T : Format> Format for Option<T> {
+    fn format(&self, f: &mut Formatter) {
+        if let Some(item) = self {
+            item.format(f);
+        }
+    }
+}
+
+impl<T : Format> Format for Box<T> {
+    fn format(&self, f: &mut Formatter) {
+        self.as_ref().format(f);
+    }
+}
+
+impl<T : Format> Format for &T {
+    fn format(&self, f: &mut Formatter) {
+        (*self).format(f);
+    }
+}
+
+// Items that are written exactly as they appear in the source
+macro_rules! impl_token_format {
+    ($($name:ty),*) => {
+        $(
+            impl Format for $name {
+                fn format(&self, f: &mut Formatter) {
+                    f.write_token(self);
+                }
+            }
+        )*
+    };
+}
+
+impl_token_format!(
+    Identifier, Word, ParsedNoneLiteral, ParsedBooleanLiteral, ParsedNumberLiteral, ParsedCharLiteral, ParsedStringLiteral, ParsedColorLiteral,
+    ParsedMacroExpression, ParsedMacroIdentifier, ParsedMacroType, ParsedVisibility, ParsedMethodQualifier, ParsedMethodMetaQualifier,
+    ParsedVarDeclarationQualifier, ParsedBinaryOperator, ParsedUnaryOperator, ParsedAssignmentOperator, ParsedActionKeyword, ParsedVarPrefix,
+    ParsedTypeSuffix, ParsedStackTypeDeclaration, ParsedEventCallbackQualifierKeyword, ParsedRootTagDeclaration, ParsedLoadDirective,
+    ParsedWildcardToken, ParsedTopLevelBlockInProgress, ParsedLoopLabel
+);
+
+impl<T : Parsable> Format for FlexWordItem<T> {
+    fn format(&self, f: &mut Formatter) {
+        f.write_token(self);
+    }
+}
+
+impl<P : Parsable, W : Parsable> Format for FlexPrefixedWordItem<P, W> {
+    fn format(&self, f: &mut Formatter) {
+        f.write_token(self);
+    }
+}
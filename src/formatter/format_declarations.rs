@@ -0,0 +1,394 @@
+use parsable::Parsable;
+use crate::items::{ParsedSourceFile, ParsedTopLevelBlock, ParsedMainTypeDeclaration, ParsedTypedefDeclaration, ParsedUseDeclaration, ParsedUsePath, ParsedGlobalVarDeclaration, ParsedFunctionDeclaration, ParsedFunctionOrMethodContent, ParsedFunctionBody, ParsedFunctionSignature, ParsedFunctionArgument, ParsedVarTypeDeclaration, ParsedDefaultValueAssignment, ParsedTypeParameters, ParsedTypeParameter, ParsedTypeDeclaration, ParsedTypeExtend, ParsedTypeDeclarationBodyItem, ParsedEventCallbackDeclaration, ParsedEventCallbackIndex, ParsedAssociatedTypeDeclaration, ParsedSuperFieldDefaultValue, ParsedMethodDeclaration, ParsedFieldDeclaration, ParsedInterfaceDeclaration, ParsedInterfaceAssociatedTypeDeclaration, ParsedInterfaceMethodDeclaration, ParsedEnumVariantDeclaration};
+use super::{Format, Formatter};
+
+impl Format for ParsedSourceFile {
+    fn format(&self, f: &mut Formatter) {
+        f.write_top_level_lines(&self.blocks);
+    }
+}
+
+impl Format for ParsedTopLevelBlock {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedTopLevelBlock::RootTagDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::MainTypeDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::TypedefDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::InterfaceDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::TypeDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::FunctionDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::GlobalDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::UseDeclaration(value) => value.format(f),
+            ParsedTopLevelBlock::InProgress(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedMainTypeDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("#");
+        f.format(&self.name);
+
+        if self.equal.is_some() {
+            f.write(" = ");
+        }
+
+        f.format(&self.ty);
+    }
+}
+
+impl Format for ParsedTypedefDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        write_visibility(f, &self.location, &self.visibility);
+        f.write("type ");
+        f.format(&self.name);
+        f.write(" = ");
+        f.format(&self.target);
+        write_semicolon(f, self.semicolon.is_some());
+    }
+}
+
+impl Format for ParsedUseDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("use ");
+        f.format(&self.path);
+        write_semicolon(f, self.semicolon.is_some());
+    }
+}
+
+impl Format for ParsedUsePath {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.namespace);
+
+        for segment in &self.segments {
+            f.write("::");
+            f.format(&segment.name);
+        }
+    }
+}
+
+impl Format for ParsedGlobalVarDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        write_visibility(f, &self.location, &self.visibility);
+        f.format(&self.var_declaration);
+        write_semicolon(f, self.semicolon.is_some());
+    }
+}
+
+impl Format for ParsedFunctionDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        write_visibility(f, &self.location, &self.visibility);
+        f.write("fn ");
+        f.format(&self.content);
+    }
+}
+
+impl Format for ParsedFunctionOrMethodContent {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+
+        if let Some(meta_qualifier) = &self.meta_qualifier {
+            f.format(meta_qualifier);
+            f.write(" ");
+        }
+
+        if let Some(qualifier) = &self.qualifier {
+            f.format(qualifier);
+            f.write(" ");
+        }
+
+        f.format(&self.name);
+        f.format(&self.parameters);
+        f.format(&self.signature);
+
+        if let Some(body) = &self.body {
+            f.write(" ");
+            f.format(body);
+        }
+    }
+}
+
+impl Format for ParsedFunctionBody {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedFunctionBody::WebAssembly(wat) => f.write_verbatim(&wat.location),
+            ParsedFunctionBody::Block(block) => block.format(f),
+            ParsedFunctionBody::Import(import) => f.write_verbatim(&import.location),
+        }
+    }
+}
+
+impl Format for ParsedFunctionSignature {
+    fn format(&self, f: &mut Formatter) {
+        let open = f.find_char(self.location.start, '(') + 1;
+        let arguments : Vec<&ParsedFunctionArgument> = self.arguments.iter().collect();
+        let last_end = arguments.last().map(|argument| f.get_end(&argument.location)).unwrap_or(open);
+        let close = f.find_char(last_end, ')');
+
+        f.enter(&self.location);
+        f.write("(");
+        f.write_comma_list(&arguments, open, close);
+        f.write(")");
+
+        if let Some(return_type) = &self.return_type {
+            f.write(" -> ");
+            f.format(return_type);
+        }
+    }
+}
+
+impl Format for ParsedFunctionArgument {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.name);
+        f.format(&self.ty);
+        f.format(&self.default_value);
+    }
+}
+
+impl Format for ParsedVarTypeDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.write(": ");
+        f.format(&self.ty);
+    }
+}
+
+impl Format for ParsedDefaultValueAssignment {
+    fn format(&self, f: &mut Formatter) {
+        f.write(" = ");
+        f.format(&self.expression);
+    }
+}
+
+impl Format for ParsedTypeParameters {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("<");
+        f.write_separated(&self.list, ", ");
+
+        if self.closing_bracket.is_some() {
+            f.write(">");
+        }
+    }
+}
+
+impl Format for ParsedTypeParameter {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.name);
+
+        if !self.required_interfaces.is_empty() {
+            f.write(": ");
+            f.write_separated(&self.required_interfaces, " + ");
+        }
+    }
+}
+
+impl Format for ParsedTypeDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        write_visibility(f, &self.location, &self.visibility);
+        f.write(&self.qualifier.to_string());
+        f.format(&self.stack_type);
+        f.write(" ");
+        f.format(&self.name);
+        f.format(&self.parameters);
+
+        if let Some(parent) = &self.parent {
+            f.write(" ");
+            f.format(parent);
+        }
+
+        if let Some(body) = &self.body {
+            let items : Vec<&ParsedTypeDeclarationBodyItem> = body.items.iter().collect();
+            let open = f.get_start(&body.location) + 1;
+            let close = f.get_end(&body.location) - 1;
+            let multiline = f.is_multiline(open, items.first().map(|item| item.location()), close);
+
+            f.write(" ");
+            f.enter(&body.location);
+            f.write_braces(&items, close, multiline, |f, item, _| item.format(f));
+        }
+    }
+}
+
+impl Format for ParsedTypeExtend {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("extends");
+
+        if let Some(ty) = &self.ty {
+            f.write(" ");
+            f.format(ty);
+        }
+    }
+}
+
+impl Format for ParsedTypeDeclarationBodyItem {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedTypeDeclarationBodyItem::EventCallbackDeclaration(value) => value.format(f),
+            ParsedTypeDeclarationBodyItem::AssociatedTypeDeclaration(value) => value.format(f),
+            ParsedTypeDeclarationBodyItem::SuperFieldDefaultValue(value) => value.format(f),
+            ParsedTypeDeclarationBodyItem::MethodDeclaration(value) => value.format(f),
+            ParsedTypeDeclarationBodyItem::EnumVariantDeclaration(value) => value.format(f),
+            ParsedTypeDeclarationBodyItem::FieldDeclaration(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedEventCallbackDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.event_callback_qualifier);
+        f.format(&self.name);
+        f.format(&self.step);
+        f.format(&self.index);
+
+        if let Some(body) = &self.body {
+            f.write(" ");
+            f.format(body);
+        }
+    }
+}
+
+impl Format for ParsedEventCallbackIndex {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("[");
+        f.format(&self.expression);
+
+        if self.closing_bracket.is_some() {
+            f.write("]");
+        }
+    }
+}
+
+impl Format for ParsedAssociatedTypeDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("type ");
+        f.format(&self.name);
+        f.write(" = ");
+        f.format(&self.value);
+        f.write(";");
+    }
+}
+
+impl Format for ParsedSuperFieldDefaultValue {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("self");
+
+        if self.dot.is_some() {
+            f.write(".");
+        }
+
+        f.format(&self.name);
+
+        if self.equal.is_some() {
+            f.write(" = ");
+        }
+
+        f.format(&self.expression);
+    }
+}
+
+impl Format for ParsedMethodDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.content);
+    }
+}
+
+impl Format for ParsedEnumVariantDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        let fields : Vec<&ParsedFieldDeclaration> = self.fields.iter().collect();
+        let open = f.find_char(f.get_end(&self.name.location), '{') + 1;
+        let close = f.find_char(self.fields.last().map(|field| f.get_end(&field.location)).unwrap_or(open), '}');
+        let multiline = f.is_multiline(open, fields.first().map(|field| &field.location), close);
+
+        f.format(&self.name);
+        f.write(" ");
+        f.write_braces(&fields, close, multiline, |f, field, _| field.format(f));
+
+        if self.comma.is_some() {
+            f.write(",");
+        }
+    }
+}
+
+impl Format for ParsedFieldDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.name);
+        f.format(&self.ty);
+        f.format(&self.default_value);
+
+        if self.comma.is_some() {
+            f.write(",");
+        }
+    }
+}
+
+impl Format for ParsedInterfaceDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        write_visibility(f, &self.location, &self.visibility);
+        f.write("interface ");
+        f.format(&self.name);
+
+        if let Some(body) = &self.body {
+            let open = f.find_char(f.get_end(&self.name.location), '{') + 1;
+            let close = f.get_end(&self.location) - 1;
+            let associated_types : Vec<&ParsedInterfaceAssociatedTypeDeclaration> = body.associated_types.iter().collect();
+            let methods : Vec<&ParsedInterfaceMethodDeclaration> = body.methods.iter().collect();
+            let method_close = methods.first().map(|method| f.get_start(&method.location)).unwrap_or(close);
+
+            f.write(" ");
+
+            match associated_types.is_empty() && methods.is_empty() && !f.has_comment(open, close) {
+                true => f.write("{}"),
+                false => {
+                    // associated types always come before methods, so the two lists are written one after the other
+                    f.write("{");
+                    f.write_lines(&associated_types, method_close, |f, item, _| item.format(f));
+                    f.write_lines(&methods, close, |f, item, _| item.format(f));
+                    f.write("}");
+                }
+            }
+        }
+    }
+}
+
+impl Format for ParsedInterfaceAssociatedTypeDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("type ");
+        f.format(&self.name);
+        f.write(";");
+    }
+}
+
+impl Format for ParsedInterfaceMethodDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        if let Some(qualifier) = &self.qualifier {
+            f.format(qualifier);
+            f.write(" ");
+        }
+
+        f.format(&self.name);
+        f.format(&self.signature);
+        write_semicolon(f, self.semicolon.is_some());
+    }
+}
+
+fn write_visibility<T : Format>(f: &mut Formatter, location: &parsable::ItemLocation, visibility: &Option<T>) {
+    f.enter(location);
+
+    if let Some(visibility) = visibility {
+        f.format(visibility);
+        f.write(" ");
+    }
+}
+
+fn write_semicolon(f: &mut Formatter, has_semicolon: bool) {
+    if has_semicolon {
+        f.write(";");
+    }
+}
@@ -0,0 +1,778 @@
+use parsable::Parsable;
+use crate::items::{ParsedExpression, ParsedBinaryOperation, ParsedOperand, ParsedUnaryOperation, ParsedOperandBody, ParsedOperandSuffix, ParsedAssignmentRvalue, ParsedIsOperation, ParsedIsBinding, ParsedAsOperation, ParsedVarPath, ParsedVarPathRoot, ParsedVarPathSegment, ParsedFieldOrMethodAccess, ParsedBracketIndexing, ParsedArgumentList, ParsedIdentifierWrapper, ParsedVarRef, ParsedPrefixedVarRef, ParsedStaticFieldOrMethod, ParsedVarDeclaration, ParsedVarDeclarationNames, ParsedVarDeclarationNamesContent, ParsedVarDeclarationType, ParsedAction, ParsedMacroDebug, ParsedBlockExpression, ParsedBlockItem, ParsedIfBlock, ParsedBranch, ParsedWhileBlock, ParsedForBlock, ParsedLoopLabel, ParsedForIterator, ParsedIndexAndItem, ParsedIterFieldsBlock, ParsedIterVariantsBlock, ParsedIterAncestorsBlock, ParsedMatchBlock, ParsedMatchBranch, ParsedMatchBranchItem, ParsedMatchBranchLiteralItem, ParsedMatchBranchRangeItem, ParsedMatchBranchTypeItem, ParsedObjectLiteral, ParsedObjectInitializationItem, ParsedArrayLiteral, ParsedParenthesizedExpression, ParsedTemplateString, ParsedTemplateStringFragment, ParsedAnonymousFunction, ParsedAnonymousFunctionArguments, ParsedAnonymousFunctionArgument, ParsedAnonymousFunctionBody};
+use super::{Format, Formatter};
+
+impl Format for ParsedExpression {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.operation);
+    }
+}
+
+impl Format for ParsedBinaryOperation {
+    fn format(&self, f: &mut Formatter) {
+        let mut previous_end = f.get_end(self.first.location());
+        let mut indented = false;
+
+        f.format(&self.first);
+
+        for (operator, operand) in &self.others {
+            let operator_start = f.get_start(&operator.location);
+
+            // long conditions can be split before an operator
+            if f.has_line_break(previous_end, operator_start) {
+                if !indented {
+                    f.indent();
+                    indented = true;
+                }
+
+                f.new_line();
+            } else {
+                f.write(" ");
+            }
+
+            f.format(operator);
+            f.write(" ");
+            f.format(operand);
+            previous_end = f.get_end(operand.location());
+        }
+
+        if indented {
+            f.dedent();
+        }
+    }
+}
+
+impl Format for ParsedOperand {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedOperand::UnaryOperation(value) => value.format(f),
+            ParsedOperand::OperandBody(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedUnaryOperation {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.operator);
+        f.format(&self.operand);
+    }
+}
+
+impl Format for ParsedOperandBody {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.lvalue);
+        f.format(&self.suffix);
+    }
+}
+
+impl Format for ParsedOperandSuffix {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedOperandSuffix::Assignment(value) => value.format(f),
+            ParsedOperandSuffix::IsOperation(value) => value.format(f),
+            ParsedOperandSuffix::AsOperation(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedAssignmentRvalue {
+    fn format(&self, f: &mut Formatter) {
+        f.write(" ");
+        f.format(&self.operator);
+
+        if let Some(expression) = &self.expression {
+            f.write(" ");
+            f.format(expression);
+        }
+    }
+}
+
+impl Format for ParsedIsOperation {
+    fn format(&self, f: &mut Formatter) {
+        f.write(" ");
+        f.enter(&self.location);
+        f.write("is");
+
+        if let Some(ty) = &self.ty {
+            f.write(" ");
+            f.format(ty);
+        }
+
+        match &self.var_name {
+            Some(ParsedIsBinding::Parenthesized(names)) => {
+                f.write("(");
+                f.format(names);
+                f.write(")");
+            },
+            Some(ParsedIsBinding::Bare(names)) => {
+                f.write(" ");
+                f.format(names);
+            },
+            None => {},
+        }
+    }
+}
+
+impl Format for ParsedAsOperation {
+    fn format(&self, f: &mut Formatter) {
+        f.write(" ");
+        f.enter(&self.location);
+        f.write("as");
+
+        if let Some(ty) = &self.ty {
+            f.write(" ");
+            f.format(ty);
+        }
+    }
+}
+
+impl Format for ParsedVarPath {
+    fn format(&self, f: &mut Formatter) {
+        let mut previous_end = f.get_end(self.root.location());
+        let mut indented = false;
+
+        f.format(&self.root);
+
+        for segment in &self.path {
+            let segment_start = f.get_start(segment.location());
+
+            // method chains can be split before a `.`
+            if let ParsedVarPathSegment::FieldOrMethodAccess(_) = segment {
+                if f.has_line_break(previous_end, segment_start) {
+                    if !indented {
+                        f.indent();
+                        indented = true;
+                    }
+
+                    f.new_line();
+                }
+            }
+
+            f.format(segment);
+            previous_end = f.get_end(segment.location());
+        }
+
+        if indented {
+            f.dedent();
+        }
+    }
+}
+
+impl Format for ParsedVarPathRoot {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedVarPathRoot::LoadDirective(value) => value.format(f),
+            ParsedVarPathRoot::Macro(value) => value.format(f),
+            ParsedVarPathRoot::DebugMacro(value) => value.format(f),
+            ParsedVarPathRoot::VarDeclaration(value) => value.format(f),
+            ParsedVarPathRoot::Action(value) => value.format(f),
+            ParsedVarPathRoot::MatchBlock(value) => value.format(f),
+            ParsedVarPathRoot::IfBlock(value) => value.format(f),
+            ParsedVarPathRoot::IterFields(value) => value.format(f),
+            ParsedVarPathRoot::IterVariants(value) => value.format(f),
+            ParsedVarPathRoot::IterAncestors(value) => value.format(f),
+            ParsedVarPathRoot::WhileBlock(value) => value.format(f),
+            ParsedVarPathRoot::ForBlock(value) => value.format(f),
+            ParsedVarPathRoot::Block(value) => value.format(f),
+            ParsedVarPathRoot::NoneLiteral(value) => value.format(f),
+            ParsedVarPathRoot::BooleanLiteral(value) => value.format(f),
+            ParsedVarPathRoot::NumberLiteral(value) => value.format(f),
+            ParsedVarPathRoot::CharLiteral(value) => value.format(f),
+            ParsedVarPathRoot::StringLiteral(value) => value.format(f),
+            ParsedVarPathRoot::TemplateString(value) => value.format(f),
+            ParsedVarPathRoot::ArrayLiteral(value) => value.format(f),
+            ParsedVarPathRoot::StaticFieldOrMethod(value) => value.format(f),
+            ParsedVarPathRoot::ObjectLiteral(value) => value.format(f),
+            ParsedVarPathRoot::ColorLiteral(value) => value.format(f),
+            ParsedVarPathRoot::FunctionLiteral(value) => value.format(f),
+            ParsedVarPathRoot::Parenthesized(value) => value.format(f),
+            ParsedVarPathRoot::PrefixedVarRef(value) => value.format(f),
+            ParsedVarPathRoot::VarRef(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedVarPathSegment {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedVarPathSegment::FieldOrMethodAccess(value) => value.format(f),
+            ParsedVarPathSegment::BracketIndexing(value) => value.format(f),
+            ParsedVarPathSegment::FunctionCall(value) => value.arguments.format(f),
+        }
+    }
+}
+
+impl Format for ParsedFieldOrMethodAccess {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write(".");
+        f.format(&self.name);
+        f.format(&self.arguments);
+    }
+}
+
+impl Format for ParsedBracketIndexing {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("[");
+        f.format(&self.index_expr);
+        f.write("]");
+    }
+}
+
+impl Format for ParsedArgumentList {
+    fn format(&self, f: &mut Formatter) {
+        let open = f.get_end(&self.opening_bracket.location);
+        let close = f.get_start(&self.closing_bracket.location);
+        let mut arguments = vec![];
+
+        if let Some(content) = &self.content {
+            arguments.push(&content.first_argument);
+
+            for (i, other_argument) in content.other_arguments.iter().enumerate() {
+                match &other_argument.expression {
+                    Some(expression) => arguments.push(expression),
+                    None if i == content.other_arguments.len() - 1 => {},
+                    None => return f.write_token(self),
+                }
+            }
+        }
+
+        f.enter(&self.location);
+        f.write("(");
+        f.write_comma_list(&arguments, open, close);
+        f.write(")");
+    }
+}
+
+impl Format for ParsedIdentifierWrapper {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedIdentifierWrapper::Identifier(value) => value.format(f),
+            ParsedIdentifierWrapper::Macro(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedVarRef {
+    fn format(&self, f: &mut Formatter) {
+        for segment in &self.namespace {
+            f.format(segment);
+        }
+
+        f.format(&self.name);
+        f.format(&self.arguments);
+    }
+}
+
+impl Format for ParsedPrefixedVarRef {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.prefix);
+        f.format(&self.name);
+        f.format(&self.arguments);
+    }
+}
+
+impl Format for ParsedStaticFieldOrMethod {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.ty);
+        f.write("::");
+        f.format(&self.name);
+        f.format(&self.arguments);
+    }
+}
+
+impl Format for ParsedVarDeclaration {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.qualifier);
+        f.write(" ");
+        f.format(&self.var_names);
+        f.format(&self.var_type);
+
+        if self.equal.is_some() {
+            f.write(" = ");
+        }
+
+        f.format(&self.init_value);
+    }
+}
+
+impl Format for ParsedVarDeclarationNames {
+    fn format(&self, f: &mut Formatter) {
+        match &self.content {
+            ParsedVarDeclarationNamesContent::Single(name) => name.format(f),
+            ParsedVarDeclarationNamesContent::Multiple(names) => {
+                f.enter(&self.location);
+                f.write("(");
+                f.write_separated(names, ", ");
+                f.write(")");
+            },
+        }
+    }
+}
+
+impl Format for ParsedVarDeclarationType {
+    fn format(&self, f: &mut Formatter) {
+        if self.colon.is_some() {
+            f.write(" : ");
+        }
+
+        f.format(&self.var_type);
+    }
+}
+
+impl Format for ParsedAction {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.keyword);
+
+        if let Some(label) = &self.label {
+            f.write(" ");
+            f.format(label);
+        }
+
+        if let Some(expression) = &self.expression {
+            f.write(" ");
+            f.format(expression);
+        }
+    }
+}
+
+impl Format for ParsedMacroDebug {
+    fn format(&self, f: &mut Formatter) {
+        match &self.expression {
+            Some(expression) => {
+                f.enter(&self.location);
+                f.write("#DEBUG_TYPE(");
+                f.format(expression);
+                f.write(")");
+            },
+            None => f.write_token(self),
+        }
+    }
+}
+
+impl Format for ParsedBlockExpression {
+    fn format(&self, f: &mut Formatter) {
+        let items : Vec<&ParsedBlockItem> = self.list.iter().collect();
+        let open = f.get_start(&self.location) + 1;
+        let close = f.get_end(&self.location) - 1;
+        let multiline = f.is_multiline(open, items.first().map(|item| &item.location), close);
+
+        f.enter(&self.location);
+        f.write_braces(&items, close, multiline, |f, item, _| item.format(f));
+    }
+}
+
+impl Format for ParsedBlockItem {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.expression);
+
+        if self.semicolon.is_some() {
+            f.write(";");
+        }
+    }
+}
+
+impl Format for ParsedIfBlock {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("if ");
+        f.format(&self.if_branch);
+
+        for branch in &self.else_if_branches {
+            f.write(" else if ");
+            f.format(branch);
+        }
+
+        if let Some(else_branch) = &self.else_branch {
+            f.write(" else ");
+            f.format(else_branch);
+        }
+    }
+}
+
+impl Format for ParsedBranch {
+    fn format(&self, f: &mut Formatter) {
+        if let Some(let_binding) = &self.let_binding {
+            f.write("let ");
+            f.format(&let_binding.var_names);
+            f.write(" = ");
+        }
+
+        f.format(&self.condition);
+
+        if let Some(body) = &self.body {
+            f.write(" ");
+            f.format(body);
+        }
+    }
+}
+
+impl Format for ParsedWhileBlock {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        write_loop_label(f, &self.label);
+        f.write("while ");
+        f.format(&self.while_branch);
+    }
+}
+
+impl Format for ParsedForBlock {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        write_loop_label(f, &self.label);
+        f.write("for");
+
+        if let Some(iterator) = &self.iterator {
+            f.write(" ");
+            f.format(iterator);
+        }
+
+        if self.in_keyword.is_some() {
+            f.write(" in");
+        }
+
+        if let Some(range_start) = &self.range_start {
+            f.write(" ");
+            f.format(range_start);
+        }
+
+        if let Some(range_end) = &self.range_end {
+            f.write("..");
+            f.format(range_end);
+        }
+
+        if let Some(body) = &self.body {
+            f.write(" ");
+            f.format(body);
+        }
+    }
+}
+
+impl Format for ParsedForIterator {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedForIterator::Item(value) => value.format(f),
+            ParsedForIterator::IndexAndItem(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedIndexAndItem {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("[");
+        f.format(&self.index_name);
+
+        if self.comma.is_some() {
+            f.write(", ");
+        }
+
+        f.format(&self.item_names);
+
+        if self.closing_bracket.is_some() {
+            f.write("]");
+        }
+    }
+}
+
+impl Format for ParsedIterFieldsBlock {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("iter_fields ");
+        f.format(&self.block);
+    }
+}
+
+impl Format for ParsedIterVariantsBlock {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("iter_variants ");
+        f.format(&self.block);
+    }
+}
+
+impl Format for ParsedIterAncestorsBlock {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("iter_ancestors ");
+        f.format(&self.block);
+    }
+}
+
+impl Format for ParsedMatchBlock {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("match");
+
+        if let Some(expression) = &self.expression {
+            f.write(" ");
+            f.format(expression);
+        }
+
+        if let Some(body) = &self.body {
+            let branches : Vec<&ParsedMatchBranch> = body.branches.list.iter().collect();
+            let open = f.get_end(&body.opening_bracket.location);
+            let close = f.get_start(&body.closing_bracket.location);
+            let multiline = f.is_multiline(open, branches.first().map(|branch| &branch.location), close);
+            let trailing_comma = multiline && branches.last().is_some_and(|branch| f.find_char(f.get_end(&branch.location), ',') < close);
+
+            f.write(" ");
+            f.enter(&body.location);
+            f.write_braces(&branches, close, multiline, |f, branch, is_last| {
+                branch.format(f);
+
+                if !is_last || trailing_comma {
+                    f.write(",");
+                }
+            });
+        }
+    }
+}
+
+impl Format for ParsedMatchBranch {
+    fn format(&self, f: &mut Formatter) {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                f.write(" | ");
+            }
+
+            f.format(item);
+        }
+
+        if let Some(variable) = &self.variable {
+            f.write("(");
+            f.format(variable);
+            f.write(")");
+        }
+
+        if let Some(guard) = &self.guard {
+            f.write(" if");
+
+            if let Some(condition) = &guard.condition {
+                f.write(" ");
+                f.format(condition);
+            }
+        }
+
+        if let Some(body) = &self.body {
+            f.write(" => ");
+            f.format(&body.expression);
+        }
+    }
+}
+
+impl Format for ParsedMatchBranchItem {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedMatchBranchItem::Wildcard(value) => value.format(f),
+            ParsedMatchBranchItem::Range(value) => value.format(f),
+            ParsedMatchBranchItem::Literal(value) => value.format(f),
+            ParsedMatchBranchItem::TypeOrEnumVariant(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedMatchBranchLiteralItem {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedMatchBranchLiteralItem::None(value) => value.format(f),
+            ParsedMatchBranchLiteralItem::Boolean(value) => value.format(f),
+            ParsedMatchBranchLiteralItem::Number(value) => value.format(f),
+            ParsedMatchBranchLiteralItem::String(value) => value.format(f),
+            ParsedMatchBranchLiteralItem::Character(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedMatchBranchRangeItem {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.start);
+        f.write("..");
+
+        if self.inclusive.is_some() {
+            f.write("=");
+        }
+
+        f.format(&self.end);
+    }
+}
+
+impl Format for ParsedMatchBranchTypeItem {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.ty);
+
+        if let Some(variant) = &self.variant {
+            f.write("::");
+            f.format(&variant.name);
+        }
+
+        if let Some(fields) = &self.fields {
+            f.write(" {");
+
+            for (i, pattern) in fields.list.iter().enumerate() {
+                f.write(match i {
+                    0 => " ",
+                    _ => ", ",
+                });
+                f.format(&pattern.name);
+
+                if let Some(binding) = &pattern.binding {
+                    f.write(": ");
+                    f.format(&binding.name);
+                }
+            }
+
+            f.write(match fields.list.is_empty() {
+                true => "}",
+                false => " }",
+            });
+        }
+
+        if let Some(binding) = &self.binding {
+            f.write(" ");
+            f.format(binding);
+        }
+    }
+}
+
+impl Format for ParsedObjectLiteral {
+    fn format(&self, f: &mut Formatter) {
+        let items : Vec<&ParsedObjectInitializationItem> = self.body.items.iter().collect();
+        let open = f.get_end(&self.opening_bracket.location);
+        let close = f.get_start(&self.closing_bracket.location);
+        let multiline = f.is_multiline(open, items.first().map(|item| item.location()), close);
+
+        f.format(&self.object_type);
+
+        if let Some(variant) = &self.variant {
+            f.write("::");
+            f.format(&variant.name);
+        }
+
+        f.write(" ");
+        f.enter(&self.opening_bracket.location);
+        f.write_braces(&items, close, multiline, |f, item, is_last| {
+            let has_comma = match item {
+                ParsedObjectInitializationItem::FieldInitialization(field) => {
+                    f.format(&field.name);
+
+                    if let Some(value) = &field.value {
+                        f.write(": ");
+                        f.format(&value.expression);
+                    }
+
+                    field.comma.is_some()
+                },
+                ParsedObjectInitializationItem::SpreadOperator(spread) => {
+                    f.enter(&spread.location);
+                    f.write("..");
+                    f.format(&spread.expression);
+
+                    spread.comma.is_some()
+                },
+            };
+
+            // on a single line, the trailing comma is dropped
+            if has_comma && (multiline || !is_last) {
+                f.write(",");
+            }
+        });
+    }
+}
+
+impl Format for ParsedArrayLiteral {
+    fn format(&self, f: &mut Formatter) {
+        let items : Vec<&ParsedExpression> = self.items.iter().collect();
+        let open = f.get_start(&self.location) + 1;
+        let close = f.get_end(&self.location) - 1;
+
+        f.enter(&self.location);
+        f.write("[");
+        f.write_comma_list(&items, open, close);
+        f.write("]");
+    }
+}
+
+impl Format for ParsedParenthesizedExpression {
+    fn format(&self, f: &mut Formatter) {
+        let items : Vec<&ParsedExpression> = self.expr_list.iter().collect();
+        let open = f.get_start(&self.location) + 1;
+        let close = f.get_end(&self.location) - 1;
+
+        f.enter(&self.location);
+        f.write("(");
+        f.write_comma_list(&items, open, close);
+        f.write(")");
+    }
+}
+
+impl Format for ParsedTemplateString {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("`");
+
+        // the literal fragments are written as-is, since their spaces are part of the string
+        for fragment in &self.fragments {
+            match fragment {
+                ParsedTemplateStringFragment::String(literal) => f.write(&literal.content),
+                ParsedTemplateStringFragment::Expression(expression) => {
+                    f.write("${");
+                    f.format(&expression.expression);
+                    f.write("}");
+                },
+            }
+        }
+
+        f.write("`");
+        f.skip_to(f.get_end(&self.location));
+    }
+}
+
+impl Format for ParsedAnonymousFunction {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.arguments);
+        f.write(" => ");
+        f.format(&self.body);
+    }
+}
+
+impl Format for ParsedAnonymousFunctionArguments {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedAnonymousFunctionArguments::Single(name) => name.format(f),
+            ParsedAnonymousFunctionArguments::Multiple(arguments) => {
+                f.write("(");
+                f.write_separated(arguments, ", ");
+                f.write(")");
+            },
+        }
+    }
+}
+
+impl Format for ParsedAnonymousFunctionArgument {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.name);
+
+        if let Some(ty) = &self.ty {
+            f.write(": ");
+            f.format(ty);
+        }
+    }
+}
+
+impl Format for ParsedAnonymousFunctionBody {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedAnonymousFunctionBody::Block(block) => block.format(f),
+            ParsedAnonymousFunctionBody::Expression(expression) => expression.format(f),
+        }
+    }
+}
+
+fn write_loop_label(f: &mut Formatter, label: &Option<ParsedLoopLabel>) {
+    if let Some(label) = label {
+        f.format(label);
+        f.write(": ");
+    }
+}
@@ -0,0 +1,71 @@
+use parsable::{Parsable, ParseError, ParseOptions};
+use crate::{items::ParsedSourceFile, program::COMMENT_START_TOKEN};
+use super::{collect_comments, Formatter};
+
+#[derive(Debug)]
+pub enum FormatError {
+    Parse(ParseError),
+    // The formatted code is not equivalent to the original one; the file is left untouched
+    Mismatch,
+}
+
+pub fn format_source_file(content: &str, file_path: &str) -> Result<String, FormatError> {
+    let parsed_source_file = parse(content, file_path).map_err(FormatError::Parse)?;
+
+    format_parsed_source_file(&parsed_source_file)
+}
+
+pub fn format_parsed_source_file(parsed_source_file: &ParsedSourceFile) -> Result<String, FormatError> {
+    let file = parsed_source_file.location.file.clone();
+    let mut formatter = Formatter::new(&file.content);
+
+    formatter.format(parsed_source_file);
+
+    let output = formatter.into_output();
+
+    match parse(&output, &file.path).is_ok() && is_equivalent(&file.content, &output) {
+        true => Ok(output),
+        false => Err(FormatError::Mismatch),
+    }
+}
+
+fn parse(content: &str, file_path: &str) -> Result<ParsedSourceFile, ParseError> {
+    ParsedSourceFile::parse(content.to_string(), ParseOptions {
+        file_path: Some(file_path.to_string()),
+        package_root_path: None,
+        comment_start: Some(COMMENT_START_TOKEN),
+    })
+}
+
+// Two files are equivalent if they have the same comments, and the same characters once spaces, comments and trailing commas are removed
+fn is_equivalent(original: &str, formatted: &str) -> bool {
+    let original_comments : Vec<&str> = collect_comments(original).into_iter().map(|range| &original[range]).collect();
+    let formatted_comments : Vec<&str> = collect_comments(formatted).into_iter().map(|range| &formatted[range]).collect();
+
+    original_comments == formatted_comments && get_code_signature(original) == get_code_signature(formatted)
+}
+
+fn get_code_signature(content: &str) -> String {
+    let mut code = String::new();
+    let mut index = 0;
+
+    for comment in collect_comments(content) {
+        code.push_str(&content[index..comment.start]);
+        index = comment.end;
+    }
+
+    code.push_str(&content[index..]);
+
+    let characters : Vec<char> = code.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut result = String::new();
+
+    for (i, c) in characters.iter().enumerate() {
+        let is_trailing_comma = *c == ',' && matches!(characters.get(i + 1), Some(')' | ']' | '}'));
+
+        if !is_trailing_comma {
+            result.push(*c);
+        }
+    }
+
+    result
+}
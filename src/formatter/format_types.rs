@@ -0,0 +1,96 @@
+use crate::items::{ParsedType, ParsedTypeWithoutSuffix, ParsedTypeSingle, ParsedTypeTuple, ParsedQualifiedType, ParsedNamespaceSegment, ParsedValueType, ParsedTypeArguments, ParsedFunctionType};
+use super::{Format, Formatter};
+
+impl Format for ParsedType {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.parsed_type);
+
+        for suffix in &self.suffix {
+            f.format(suffix);
+        }
+    }
+}
+
+impl Format for ParsedTypeWithoutSuffix {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedTypeWithoutSuffix::Macro(value) => value.format(f),
+            ParsedTypeWithoutSuffix::Single(value) => value.format(f),
+            ParsedTypeWithoutSuffix::Tuple(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedTypeSingle {
+    fn format(&self, f: &mut Formatter) {
+        match self {
+            ParsedTypeSingle::Function(value) => value.format(f),
+            ParsedTypeSingle::Qualified(value) => value.format(f),
+            ParsedTypeSingle::Value(value) => value.format(f),
+        }
+    }
+}
+
+impl Format for ParsedTypeTuple {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("(");
+        f.write_separated(&self.type_list, ", ");
+        f.write(")");
+    }
+}
+
+impl Format for ParsedQualifiedType {
+    fn format(&self, f: &mut Formatter) {
+        for segment in &self.namespace {
+            f.format(segment);
+        }
+
+        f.format(&self.value);
+    }
+}
+
+impl Format for ParsedNamespaceSegment {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.name);
+        f.write("::");
+    }
+}
+
+impl Format for ParsedValueType {
+    fn format(&self, f: &mut Formatter) {
+        f.format(&self.name);
+        f.format(&self.arguments);
+
+        for associated_type in &self.associated_types {
+            f.write(":");
+            f.format(associated_type);
+        }
+    }
+}
+
+impl Format for ParsedTypeArguments {
+    fn format(&self, f: &mut Formatter) {
+        if !self.list.is_empty() {
+            f.enter(&self.location);
+            f.write("<");
+            f.write_separated(&self.list, ", ");
+            f.write(">");
+        }
+    }
+}
+
+impl Format for ParsedFunctionType {
+    fn format(&self, f: &mut Formatter) {
+        f.enter(&self.location);
+        f.write("fn(");
+        f.write_separated(&self.arguments, ", ");
+        f.write(")");
+
+        if let Some(return_type) = &self.return_type {
+            f.write("(");
+            f.format(return_type);
+            f.write(")");
+        }
+    }
+}
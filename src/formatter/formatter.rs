@@ -0,0 +1,370 @@
+use std::ops::Range;
+use parsable::{ItemLocation, Parsable};
+use super::{collect_comments, Format, INDENT};
+
+pub struct Formatter<'a> {
+    content: &'a str,
+    comments: Vec<Range<usize>>,
+    next_comment_index: usize,
+    cursor: usize,
+    output: String,
+    indent_level: usize,
+    at_line_start: bool,
+}
+
+impl<'a> Formatter<'a> {
+    pub fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            comments: collect_comments(content),
+            next_comment_index: 0,
+            cursor: 0,
+            output: String::new(),
+            indent_level: 0,
+            at_line_start: true,
+        }
+    }
+
+    pub fn into_output(mut self) -> String {
+        self.write_line_trivia(self.content.len(), false, true);
+
+        let mut output = self.output.trim_end().to_string();
+
+        if !output.is_empty() {
+            output.push('\n');
+        }
+
+        output
+    }
+
+    pub fn write(&mut self, string: &str) {
+        if string.is_empty() {
+            return;
+        }
+
+        if self.at_line_start {
+            for _ in 0..self.indent_level {
+                self.output.push_str(INDENT);
+            }
+
+            self.at_line_start = false;
+        }
+
+        self.output.push_str(string);
+    }
+
+    pub fn new_line(&mut self) {
+        while self.output.ends_with(' ') {
+            self.output.pop();
+        }
+
+        self.output.push('\n');
+        self.at_line_start = true;
+    }
+
+    pub fn indent(&mut self) {
+        self.indent_level += 1;
+    }
+
+    pub fn dedent(&mut self) {
+        self.indent_level -= 1;
+    }
+
+    pub fn format<T : Format>(&mut self, item: &T) {
+        item.format(self);
+    }
+
+    // Must be called before writing any item, so comments that precede it are not lost
+    pub fn enter(&mut self, location: &ItemLocation) {
+        let start = self.get_start(location);
+
+        while let Some(comment) = self.get_pending_comment(start) {
+            if !self.at_line_start {
+                self.write(" ");
+            }
+
+            self.write(&self.content[comment]);
+            self.new_line();
+        }
+
+        self.cursor = self.cursor.max(start);
+    }
+
+    // Writes the item exactly as it appears in the source
+    pub fn write_token<T : Parsable>(&mut self, item: &T) {
+        let location = item.location();
+
+        self.enter(location);
+
+        let end = self.get_end(location);
+
+        self.write(&self.content[self.cursor..end]);
+        self.skip_to(end);
+    }
+
+    // Writes the item as it appears in the source, only adjusting the indentation of its lines
+    pub fn write_verbatim(&mut self, location: &ItemLocation) {
+        self.enter(location);
+
+        let start = self.cursor;
+        let end = self.get_end(location);
+        let line_start = self.content[..start].rfind('\n').map(|index| index + 1).unwrap_or(0);
+        let base_indent = self.content[line_start..start].len() - self.content[line_start..start].trim_start().len();
+
+        for (i, line) in self.content[start..end].split('\n').enumerate() {
+            if i > 0 {
+                self.new_line();
+
+                let indent = line.len() - line.trim_start().len();
+
+                if indent > base_indent {
+                    self.write(&line[base_indent..line.len() - line.trim_start().len()]);
+                }
+            }
+
+            self.write(line.trim().trim_end_matches('\r'));
+        }
+
+        self.skip_to(end);
+    }
+
+    // Writes a list with one item per line; `close` is the index of the closing bracket in the source
+    pub fn write_lines<T, F>(&mut self, items: &[&T], close: usize, mut format_item: F)
+        where
+            T : Parsable,
+            F : FnMut(&mut Self, &T, bool)
+    {
+        self.write_grouped_lines(items, close, false, format_item);
+    }
+
+    // Same as `write_lines`, but items that were on the same line in the source can be kept together
+    fn write_grouped_lines<T, F>(&mut self, items: &[&T], close: usize, keep_groups: bool, mut format_item: F)
+        where
+            T : Parsable,
+            F : FnMut(&mut Self, &T, bool)
+    {
+        let mut previous_end = None;
+
+        self.indent();
+
+        for (i, item) in items.iter().enumerate() {
+            let start = self.get_start(item.location());
+
+            match previous_end {
+                Some(end) if keep_groups && !self.has_line_break(end, start) && !self.has_comment(end, start) => self.write(" "),
+                _ => self.write_line_trivia(start, i == 0, false),
+            }
+
+            format_item(self, item, i == items.len() - 1);
+            previous_end = Some(self.get_end(item.location()));
+        }
+
+        self.write_line_trivia(close, items.is_empty(), true);
+        self.dedent();
+    }
+
+    // Writes a list delimited by curly brackets, either on a single line or with one item per line
+    pub fn write_braces<T, F>(&mut self, items: &[&T], close: usize, multiline: bool, mut format_item: F)
+        where
+            T : Parsable,
+            F : FnMut(&mut Self, &T, bool)
+    {
+        if multiline {
+            self.write("{");
+            self.write_lines(items, close, format_item);
+            self.write("}");
+        } else if items.is_empty() {
+            self.write("{}");
+        } else {
+            self.write("{ ");
+
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    self.write(" ");
+                }
+
+                format_item(self, item, i == items.len() - 1);
+            }
+
+            self.write(" }");
+        }
+    }
+
+    // Writes a comma-separated list (without its brackets); `open` is the index right after the opening bracket in the source
+    pub fn write_comma_list<T : Parsable + Format>(&mut self, items: &[&T], open: usize, close: usize) {
+        let first_item = items.first().map(|item| item.location());
+
+        match self.is_multiline(open, first_item, close) {
+            true => {
+                let trailing_comma = items.last().is_some_and(|item| self.find_char(self.get_end(item.location()), ',') < close);
+
+                self.write_grouped_lines(items, close, true, |f, item, is_last| {
+                    item.format(f);
+
+                    if !is_last || trailing_comma {
+                        f.write(",");
+                    }
+                });
+            },
+            false => self.write_separated(items, ", "),
+        }
+    }
+
+    // Writes the items of a file, one per line, preserving the blank lines between them
+    pub fn write_top_level_lines<T : Parsable + Format>(&mut self, items: &[T]) {
+        for (i, item) in items.iter().enumerate() {
+            let start = self.get_start(item.location());
+
+            self.write_line_trivia(start, i == 0, false);
+            item.format(self);
+        }
+    }
+
+    pub fn write_separated<T : Format>(&mut self, items: &[T], separator: &str) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.write(separator);
+            }
+
+            item.format(self);
+        }
+    }
+
+    // Writes a line break if there was one between the two indexes in the source, or the fallback otherwise
+    pub fn write_break_or(&mut self, start: usize, end: usize, fallback: &str) -> bool {
+        let has_line_break = self.has_line_break(start, end);
+
+        match has_line_break {
+            true => self.new_line(),
+            false => self.write(fallback),
+        }
+
+        has_line_break
+    }
+
+    pub fn has_line_break(&self, start: usize, end: usize) -> bool {
+        start < end && self.content[start..end].contains('\n')
+    }
+
+    pub fn has_comment(&self, start: usize, end: usize) -> bool {
+        self.comments[self.next_comment_index..].iter().any(|comment| comment.start >= start && comment.start < end)
+    }
+
+    // A list is written on several lines if it already was, or if it contains comments
+    pub fn is_multiline(&self, open: usize, first_item: Option<&ItemLocation>, close: usize) -> bool {
+        let first_item_start = match first_item {
+            Some(location) => self.get_start(location),
+            None => close,
+        };
+
+        self.has_line_break(open, first_item_start) || self.has_comment(open, close)
+    }
+
+    pub fn get_start(&self, location: &ItemLocation) -> usize {
+        let mut index = location.start;
+
+        loop {
+            let remaining = &self.content[index.min(self.content.len())..];
+            let trimmed = remaining.trim_start();
+
+            index += remaining.len() - trimmed.len();
+
+            match self.comments.iter().find(|comment| comment.start == index) {
+                Some(comment) => index = comment.end,
+                None => return index,
+            }
+        }
+    }
+
+    pub fn get_end(&self, location: &ItemLocation) -> usize {
+        let mut index = location.end.min(self.content.len()).max(location.start);
+
+        loop {
+            index = location.start + self.content[location.start..index].trim_end().len();
+
+            match self.comments.iter().find(|comment| comment.end == index && comment.start >= location.start) {
+                Some(comment) => index = comment.start,
+                None => return index,
+            }
+        }
+    }
+
+    // Returns the index of the first occurrence of `c` that is not in a comment, starting from `start`
+    pub fn find_char(&self, start: usize, c: char) -> usize {
+        let mut index = start;
+
+        while let Some(offset) = self.content[index..].find(c) {
+            let found = index + offset;
+
+            match self.comments.iter().find(|comment| comment.contains(&found)) {
+                Some(comment) => index = comment.end,
+                None => return found,
+            }
+        }
+
+        self.content.len()
+    }
+
+    // Returns the index of the last occurrence of `c` before `end`
+    pub fn rfind_char(&self, end: usize, c: char) -> usize {
+        self.content[..end].rfind(c).unwrap_or(end)
+    }
+
+    pub fn skip_to(&mut self, index: usize) {
+        while self.get_pending_comment(index).is_some() {}
+
+        self.cursor = self.cursor.max(index);
+    }
+
+    fn get_pending_comment(&mut self, before: usize) -> Option<Range<usize>> {
+        match self.comments.get(self.next_comment_index) {
+            Some(comment) if comment.start < before => {
+                self.next_comment_index += 1;
+                self.cursor = self.cursor.max(comment.end);
+
+                Some(comment.clone())
+            },
+            _ => None,
+        }
+    }
+
+    fn write_line_trivia(&mut self, end: usize, is_first: bool, is_last: bool) {
+        let mut allow_blank_line = !is_first;
+
+        loop {
+            let previous_end = self.cursor;
+            let comment = match self.get_pending_comment(end) {
+                Some(comment) => comment,
+                None => break,
+            };
+            let gap = &self.content[previous_end.min(comment.start)..comment.start];
+
+            if gap.contains('\n') || self.at_line_start {
+                if !self.at_line_start {
+                    self.new_line();
+                }
+
+                if allow_blank_line && gap.matches('\n').count() >= 2 {
+                    self.new_line();
+                }
+            } else {
+                self.write(" ");
+            }
+
+            self.write(&self.content[comment]);
+            allow_blank_line = true;
+        }
+
+        let gap = &self.content[self.cursor.min(end)..end];
+
+        if !self.at_line_start {
+            self.new_line();
+        }
+
+        if allow_blank_line && !is_last && gap.matches('\n').count() >= 2 {
+            self.new_line();
+        }
+
+        self.cursor = self.cursor.max(end);
+    }
+}
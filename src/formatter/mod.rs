@@ -0,0 +1,14 @@
+mod constants;
+mod comments;
+mod formatter;
+mod format;
+mod format_declarations;
+mod format_types;
+mod format_expressions;
+mod format_source_file;
+
+pub use constants::*;
+pub use comments::*;
+pub use formatter::*;
+pub use format::*;
+pub use format_source_file::*;
@@ -0,0 +1,381 @@
+use crate::{program::*, utils::{CachedItem, CacheWriter, CacheReader}};
+use super::*;
+
+// The `CachedItem` implementations of all parsed items, so that parsed source files can be written to and read from the on-disk parse cache.
+// Each entry lists all the fields or variants of its item: an entry that is not updated along with its item does not compile.
+// The parse cache is invalidated whenever a file of this directory changes (see `build.rs`).
+
+macro_rules! cached_struct {
+    ($name:ident { $($field:ident),* }) => {
+        impl CachedItem for $name {
+            fn write_to_cache(&self, writer: &mut CacheWriter) {
+                self.location.write_to_cache(writer);
+                $(self.$field.write_to_cache(writer);)*
+            }
+
+            fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+                Some(Self {
+                    location: CachedItem::read_from_cache(reader)?,
+                    $($field: CachedItem::read_from_cache(reader)?,)*
+                })
+            }
+        }
+    };
+}
+
+macro_rules! cached_enum {
+    ($name:ident { $($variant:ident $(($($value:ident),*))?),* }) => {
+        impl CachedItem for $name {
+            fn write_to_cache(&self, writer: &mut CacheWriter) {
+                #[allow(non_camel_case_types, clippy::enum_variant_names)]
+                enum Index { $($variant),* }
+
+                match self {
+                    $(Self::$variant $(($($value),*))? => {
+                        writer.write_usize(Index::$variant as usize);
+                        $($($value.write_to_cache(writer);)*)?
+                    },)*
+                }
+            }
+
+            fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+                #[allow(non_camel_case_types, clippy::enum_variant_names)]
+                enum Index { $($variant),* }
+
+                match reader.read_usize()? {
+                    $(index if index == Index::$variant as usize => {
+                        $($(let $value = CachedItem::read_from_cache(reader)?;)*)?
+
+                        Some(Self::$variant $(($($value),*))?)
+                    },)*
+                    _ => None
+                }
+            }
+        }
+    };
+}
+
+macro_rules! cached_token {
+    ($name:ident, $token:expr) => {
+        impl CachedItem for $name {
+            fn write_to_cache(&self, writer: &mut CacheWriter) {
+                self.location.write_to_cache(writer);
+            }
+
+            fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+                Some(Self {
+                    token: $token,
+                    location: CachedItem::read_from_cache(reader)?,
+                })
+            }
+        }
+    };
+}
+
+macro_rules! cached_flex_keyword {
+    ($name:ident) => {
+        impl CachedItem for $name {
+            fn write_to_cache(&self, writer: &mut CacheWriter) {
+                self.keyword.write_to_cache(writer);
+            }
+
+            fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+                Some(Self {
+                    keyword: CachedItem::read_from_cache(reader)?,
+                })
+            }
+        }
+    };
+}
+
+cached_struct!(Identifier { value });
+cached_struct!(ParsedAction { keyword, label, expression });
+cached_struct!(ParsedActionKeyword { token });
+cached_enum!(ParsedActionKeywordToken { Return, Check, Break, Continue, Intercept, Yield });
+cached_struct!(ParsedAnonymousFunction { arguments, body });
+cached_enum!(ParsedAnonymousFunctionArguments { Single(value), Multiple(value) });
+cached_struct!(ParsedAnonymousFunctionArgument { name, ty });
+cached_enum!(ParsedAnonymousFunctionBody { Block(value), Expression(value) });
+cached_struct!(ParsedArgumentList { opening_bracket, content, closing_bracket });
+cached_struct!(ParsedArgumentListContent { first_argument, other_arguments });
+cached_struct!(ParsedOtherArgument { comma, expression });
+cached_struct!(ParsedArrayLiteral { items });
+cached_struct!(ParsedAsOperation { keyword, ty });
+cached_struct!(ParsedAsKeyword { token });
+cached_struct!(ParsedAssignmentOperator { token });
+cached_enum!(ParsedAssignmentOperatorToken {
+    Equal,
+    PlusEqual,
+    MinusEqual,
+    MultEqual,
+    DivEqual,
+    ModEqual,
+    ShlEqual,
+    ShrEqual,
+    XorEqual,
+    DoubleAndEqual,
+    DoubleOrEqual,
+    SingleAndEqual,
+    SingleOrEqual
+});
+cached_struct!(ParsedAssignmentRvalue { operator, expression });
+cached_struct!(ParsedAssociatedTypeDeclaration { name, value });
+cached_struct!(ParsedBinaryOperation { first, others });
+cached_struct!(ParsedBinaryOperator { token });
+cached_enum!(ParsedBinaryOperatorToken {
+    Plus,
+    Minus,
+    Mult,
+    Div,
+    Mod,
+    Shl,
+    Shr,
+    Xor,
+    DoubleAnd,
+    DoubleOr,
+    SingleAnd,
+    SingleOr,
+    Eq,
+    Ne,
+    Ge,
+    Gt,
+    Le,
+    Lt
+});
+cached_struct!(ParsedBlockExpression { list });
+cached_struct!(ParsedBlockItem { expression, semicolon });
+cached_struct!(ParsedBooleanLiteral { token });
+cached_enum!(ParsedBooleanLiteralToken { True, False });
+cached_struct!(ParsedBracketIndexing { index_expr });
+cached_struct!(ParsedBranch { let_binding, condition, body });
+cached_struct!(ParsedCharLiteral { token });
+cached_struct!(ParsedColorLiteral { token });
+cached_struct!(ParsedDefaultValueAssignment { equal, expression });
+cached_struct!(ParsedEnumVariantDeclaration { name, fields, comma });
+cached_struct!(ParsedEventCallbackDeclaration { event_callback_qualifier, name, step, index, body });
+cached_struct!(ParsedEventCallbackIndex { opening_bracket, expression, closing_bracket });
+cached_struct!(ParsedEventCallbackQualifierKeyword { token });
+cached_enum!(ParsedEventCallbackQualifierKeywordToken { Standard });
+cached_enum!(ParsedEventCallbackEventStep { Start, Progress, End });
+cached_struct!(ParsedExpression { operation });
+cached_struct!(ParsedFieldDeclaration { name, ty, default_value, comma });
+cached_struct!(ParsedFieldOrMethodAccess { dot, name, arguments });
+cached_token!(ForKeyword, FOR_KEYWORD);
+cached_token!(InKeyword, IN_KEYWORD);
+cached_struct!(ParsedForBlock { label, for_keyword, iterator, in_keyword, range_start, range_end, body });
+cached_enum!(ParsedForIterator { Item(value), IndexAndItem(value) });
+cached_struct!(ParsedIndexAndItem { opening_bracket, index_name, comma, item_names, closing_bracket });
+cached_struct!(ParsedFunctionArgument { name, ty, default_value });
+cached_enum!(ParsedFunctionBody { WebAssembly(value), Block(value), Import(value) });
+cached_struct!(ParsedFunctionCall { arguments });
+cached_token!(FnKeyword, FN_KEYWORD);
+cached_struct!(ParsedFunctionDeclaration { visibility, fn_keyword, content });
+cached_struct!(ParsedFunctionImport { opening_bracket, import_keyword, first_namespace, dot, second_namespace, closing_bracket });
+cached_struct!(ParsedFunctionOrMethodContent { meta_qualifier, qualifier, name, parameters, signature, body });
+cached_struct!(ParsedFunctionSignature { arguments, return_type });
+cached_struct!(ParsedFunctionType { fn_token, arguments, return_type });
+cached_struct!(ParsedGlobalVarDeclaration { visibility, var_declaration, semicolon });
+cached_enum!(ParsedIdentifierWrapper { Identifier(value), Macro(value) });
+cached_struct!(ParsedIfBlock { if_branch, else_if_branches, else_branch });
+cached_struct!(ParsedInterfaceAssociatedTypeDeclaration { name });
+cached_struct!(ParsedInterfaceDeclaration { visibility, qualifier, name, body });
+cached_struct!(ParsedInterfaceDeclarationBody { associated_types, methods });
+cached_struct!(ParsedInterfaceMethodDeclaration { qualifier, name, signature, semicolon });
+cached_enum!(ParsedInterfaceQualifier { Interface });
+cached_struct!(ParsedIsOperation { keyword, ty, var_name });
+cached_enum!(ParsedIsBinding { Parenthesized(value), Bare(value) });
+cached_struct!(ParsedIsKeyword { token });
+cached_struct!(ParsedIterAncestorsBlock { block });
+cached_struct!(ParsedIterFieldsBlock { block });
+cached_struct!(ParsedIterVariantsBlock { block });
+cached_token!(LetKeyword, LET_KEYWORD);
+cached_struct!(ParsedLetBinding { let_keyword, var_names, equal });
+cached_token!(LoadKeyword, "#LOAD");
+cached_struct!(ParsedLoadDirective { load_keyword, opening_bracket, type_name, comma, sheet_name, closing_bracket });
+cached_struct!(ParsedLoopLabel { token });
+cached_struct!(ParsedMacroDebug { token, expression });
+cached_enum!(ParsedMacroDebugToken { DebugType });
+cached_struct!(ParsedMacroExpression { token });
+cached_enum!(MacroExpressionToken {
+    Line,
+    TypeId,
+    TypeName,
+    TypeShortName,
+    TypeFullName,
+    ObjectSize,
+    FieldCount,
+    FieldName,
+    FieldDefaultExpression,
+    VariantCount,
+    VariantName,
+    VariantValue,
+    AncestorId,
+    AncestorName
+});
+cached_struct!(ParsedMacroIdentifier { token });
+cached_enum!(MacroIdentifierToken { FieldName, WorldType, UserType, WindowType, LocalDataType });
+cached_struct!(ParsedMacroType { token });
+cached_enum!(MacroTypeToken { FieldType, WorldType, UserType, WindowType, LocalDataType, GameInstanceType });
+cached_struct!(ParsedMainTypeDeclaration { hash, name, equal, ty });
+cached_enum!(ParsedMainTypeName { User, World, Window, LocalData, GameInstance });
+cached_token!(MatchKeyword, MATCH_KEYWORD);
+cached_token!(MatchGuardKeyword, IF_KEYWORD);
+cached_struct!(ParsedMatchBlock { match_keyword, expression, body });
+cached_struct!(ParsedMatchBody { opening_bracket, branches, closing_bracket });
+cached_struct!(ParsedMatchBranchList { list });
+cached_struct!(ParsedMatchBranch { items, variable, guard, body });
+cached_struct!(ParsedMatchBranchGuard { if_keyword, condition });
+cached_struct!(ParsedMatchBranchBody { arrow, expression });
+cached_enum!(ParsedMatchBranchItem { Wildcard(value), Range(value), Literal(value), TypeOrEnumVariant(value) });
+cached_enum!(ParsedMatchBranchLiteralItem { None(value), Boolean(value), Number(value), String(value), Character(value) });
+cached_struct!(ParsedMatchBranchRangeItem { start, double_dot, inclusive, end });
+cached_struct!(ParsedMatchBranchTypeItem { ty, variant, fields, binding });
+cached_struct!(ParsedEnumVariantName { double_colon, name });
+cached_struct!(ParsedMatchBranchFieldPatternList { list });
+cached_struct!(ParsedMatchBranchFieldPattern { name, binding });
+cached_struct!(ParsedMatchBranchFieldBinding { colon, name });
+cached_struct!(ParsedMethodDeclaration { content });
+cached_struct!(ParsedMethodMetaQualifier { token });
+cached_enum!(ParsedMethodMetaQualifierToken { Autogen });
+cached_struct!(ParsedMethodQualifier { token });
+cached_enum!(ParsedMethodQualifierToken { Static, Dyn });
+cached_struct!(ParsedNamespaceSegment { name, double_colon });
+cached_struct!(ParsedNoneLiteral { token });
+cached_struct!(ParsedNumberLiteral { token });
+cached_struct!(ParsedObjectFieldInitialization { name, value, comma });
+cached_struct!(ParsedObjectFieldInitializationValue { colon, expression });
+cached_enum!(ParsedObjectInitializationItem { FieldInitialization(value), SpreadOperator(value) });
+cached_struct!(ParsedObjectLiteral { object_type, variant, opening_bracket, body, closing_bracket });
+cached_struct!(ParsedObjectLiteralInitializationBody { items });
+cached_struct!(ParsedObjectSpreadOperator { double_dot, expression, comma });
+cached_enum!(ParsedOperand { UnaryOperation(value), OperandBody(value) });
+cached_struct!(ParsedOperandBody { lvalue, suffix });
+cached_enum!(ParsedOperandSuffix { Assignment(value), IsOperation(value), AsOperation(value) });
+cached_struct!(ParsedParenthesizedExpression { expr_list });
+cached_struct!(ParsedPrefixedVarRef { prefix, name, arguments });
+cached_struct!(ParsedQualifiedType { namespace, value });
+cached_struct!(ParsedRootTagDeclaration { at, tag_name });
+cached_enum!(ParsedRootTagName { DisableCheckMainType, EnableCheckFieldAccess });
+cached_token!(SelfKeyword, SELF_VAR_NAME);
+cached_struct!(ParsedSuperFieldDefaultValue { self_keyword, dot, name, equal, expression });
+cached_struct!(ParsedSourceFile { blocks });
+cached_struct!(ParsedStackTypeDeclaration { opening_bracket, stack_type, closing_bracket });
+cached_enum!(ParsedStackType { I32, F32, I64, F64, Void });
+cached_struct!(ParsedStaticFieldOrMethod { ty, double_colon, name, arguments });
+cached_struct!(ParsedStringLiteral { token });
+cached_struct!(ParsedTemplateString { fragments });
+cached_struct!(ParsedTemplateStringExpressionFragment { expression });
+cached_enum!(ParsedTemplateStringFragment { String(value), Expression(value) });
+cached_struct!(ParsedTemplateStringLiteralFragment { content });
+cached_struct!(ParsedDotToken { token });
+cached_token!(ParsedCommaToken, ",");
+cached_token!(ParsedSemicolonToken, ";");
+cached_token!(ParsedColonToken, ":");
+cached_token!(ParsedDoubleColonToken, "::");
+cached_token!(ParsedArrowToken, "=>");
+cached_token!(ParsedWildcardToken, "_");
+cached_token!(ParsedDoubleDotToken, "..");
+cached_token!(ParsedEqualToken, "=");
+cached_token!(ParsedHashToken, "#");
+cached_token!(ParsedAtToken, "@");
+cached_token!(ParsedOpeningRoundBracket, "(");
+cached_token!(ParsedClosingRoundBracket, ")");
+cached_token!(ParsedOpeningCurlyBracket, "{");
+cached_token!(ParsedClosingCurlyBracket, "}");
+cached_token!(ParsedOpeningSquareBracket, "[");
+cached_token!(ParsedClosingSquareBracket, "]");
+cached_token!(ParsedOpeningAngleBracket, "<");
+cached_token!(ParsedClosingAngleBracket, ">");
+cached_enum!(ParsedTopLevelBlock {
+    RootTagDeclaration(value),
+    MainTypeDeclaration(value),
+    TypedefDeclaration(value),
+    InterfaceDeclaration(value),
+    TypeDeclaration(value),
+    FunctionDeclaration(value),
+    GlobalDeclaration(value),
+    UseDeclaration(value),
+    InProgress(value)
+});
+cached_struct!(ParsedTopLevelBlockInProgress { visibility_keyword, qualifier_keyword });
+cached_struct!(ParsedType { parsed_type, suffix });
+cached_struct!(ParsedTypeArguments { list });
+cached_struct!(ParsedTypeDeclaration { visibility, qualifier, stack_type, name, parameters, parent, body });
+cached_struct!(ParsedTypeDeclarationBody { items });
+cached_enum!(ParsedTypeDeclarationBodyItem {
+    EventCallbackDeclaration(value),
+    AssociatedTypeDeclaration(value),
+    SuperFieldDefaultValue(value),
+    MethodDeclaration(value),
+    EnumVariantDeclaration(value),
+    FieldDeclaration(value)
+});
+cached_flex_keyword!(ExtendsKeyword);
+cached_struct!(ParsedTypeExtend { extends, ty });
+cached_struct!(ParsedTypeParameters { opening_bracket, list, closing_bracket });
+cached_struct!(ParsedTypeParameter { name, required_interfaces });
+cached_enum!(ParsedTypeQualifier { Type, Enum, Class });
+cached_enum!(ParsedTypeSingle { Function(value), Qualified(value), Value(value) });
+cached_struct!(ParsedTypeSuffix { token });
+cached_enum!(ParsedTypeSuffixToken { Array, Option });
+cached_struct!(ParsedTypeTuple { type_list });
+cached_enum!(ParsedTypeWithoutSuffix { Macro(value), Single(value), Tuple(value) });
+cached_token!(TypeKeyword, TYPE_KEYWORD);
+cached_struct!(ParsedTypedefDeclaration { visibility, qualifier, name, equal, target, semicolon });
+cached_struct!(ParsedUnaryOperation { operator, operand });
+cached_struct!(ParsedUnaryOperator { token });
+cached_enum!(ParsedUnaryOperatorToken { BooleanNot, BinaryNot });
+cached_token!(UseKeyword, USE_KEYWORD);
+cached_struct!(ParsedUseDeclaration { use_keyword, path, semicolon });
+cached_struct!(ParsedUsePath { namespace, segments });
+cached_struct!(ParsedUsePathSegment { double_colon, name });
+cached_struct!(ParsedValueType { name, arguments, associated_types });
+cached_struct!(ParsedVarDeclaration { qualifier, var_names, var_type, equal, init_value });
+cached_struct!(ParsedVarDeclarationNames { content });
+cached_enum!(ParsedVarDeclarationNamesContent { Single(value), Multiple(value) });
+cached_struct!(ParsedVarDeclarationQualifier { token });
+cached_enum!(ParsedVarDeclarationQualifierToken { Let, Const });
+cached_struct!(ParsedVarDeclarationType { colon, var_type });
+cached_struct!(ParsedVarPath { root, path });
+cached_enum!(ParsedVarPathRoot {
+    LoadDirective(value),
+    Macro(value),
+    DebugMacro(value),
+    VarDeclaration(value),
+    Action(value),
+    MatchBlock(value),
+    IfBlock(value),
+    IterFields(value),
+    IterVariants(value),
+    IterAncestors(value),
+    WhileBlock(value),
+    ForBlock(value),
+    Block(value),
+    NoneLiteral(value),
+    BooleanLiteral(value),
+    NumberLiteral(value),
+    CharLiteral(value),
+    StringLiteral(value),
+    TemplateString(value),
+    ArrayLiteral(value),
+    ObjectLiteral(value),
+    StaticFieldOrMethod(value),
+    ColorLiteral(value),
+    FunctionLiteral(value),
+    Parenthesized(value),
+    PrefixedVarRef(value),
+    VarRef(value)
+});
+cached_enum!(ParsedVarPathSegment { FieldOrMethodAccess(value), BracketIndexing(value), FunctionCall(value) });
+cached_struct!(ParsedVarPrefix { token });
+cached_enum!(ParsedVarPrefixToken { System });
+cached_struct!(ParsedVarRef { namespace, name, arguments });
+cached_struct!(ParsedVarTypeDeclaration { colon, ty });
+cached_struct!(ParsedVisibility { token });
+cached_enum!(ParsedVisibilityToken { Public, Export, System });
+cached_enum!(ParsedWatExpression { Leaf(value), Tree(keyword, items) });
+cached_struct!(ParsedWatExpressionList { list });
+cached_struct!(ParsedWatToken { token });
+cached_token!(WhileKeyword, WHILE_KEYWORD);
+cached_struct!(ParsedWhileBlock { label, while_keyword, while_branch });
+cached_struct!(Word { value });
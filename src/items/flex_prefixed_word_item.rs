@@ -1,6 +1,6 @@
 use std::ops::Deref;
 use parsable::{parsable, Parsable, ItemLocation};
-use crate::program::ProgramContext;
+use crate::{program::ProgramContext, utils::{CachedItem, CacheWriter, CacheReader}};
 use super::{FlexWordItem, unwrap_item};
 
 #[derive(Debug)]
@@ -49,4 +49,20 @@ impl<P : Parsable, W : Parsable> Deref for FlexPrefixedWordItem<P, W> {
     fn deref(&self) -> &Self::Target {
         self.location()
     }
+}
+
+impl<P : Parsable + CachedItem, W : Parsable + CachedItem> CachedItem for FlexPrefixedWordItem<P, W> {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        self.location.write_to_cache(writer);
+        self.prefix.write_to_cache(writer);
+        self.word.write_to_cache(writer);
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        let location = ItemLocation::read_from_cache(reader)?;
+        let prefix = P::read_from_cache(reader)?;
+        let word = Option::<FlexWordItem<W>>::read_from_cache(reader)?;
+
+        Some(Self { prefix, word, location })
+    }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 use parsable::{Parsable, ItemLocation};
-use crate::program::ProgramContext;
+use crate::{program::ProgramContext, utils::{CachedItem, CacheWriter, CacheReader}};
 use super::Word;
 
 #[derive(Debug)]
@@ -63,4 +63,32 @@ impl<T : Parsable> Deref for FlexWordItem<T> {
     fn deref(&self) -> &Self::Target {
         self.location()
     }
+}
+
+impl<T : Parsable + CachedItem> CachedItem for FlexWordItem<T> {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        self.location.write_to_cache(writer);
+
+        match &self.content {
+            FlexWordItemContent::Item(item) => {
+                writer.write_usize(0);
+                item.write_to_cache(writer);
+            },
+            FlexWordItemContent::Word(word) => {
+                writer.write_usize(1);
+                word.write_to_cache(writer);
+            },
+        }
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        let location = ItemLocation::read_from_cache(reader)?;
+        let content = match reader.read_usize()? {
+            0 => FlexWordItemContent::Item(T::read_from_cache(reader)?),
+            1 => FlexWordItemContent::Word(Word::read_from_cache(reader)?),
+            _ => return None
+        };
+
+        Some(Self { content, location })
+    }
 }
\ No newline at end of file
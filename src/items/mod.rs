@@ -15,6 +15,7 @@ mod parsed_string_literal;
 mod parsed_binary_operation;
 mod parsed_array_literal;
 mod parsed_field_declaration;
+mod parsed_enum_variant_declaration;
 mod parsed_method_declaration;
 mod parsed_object_literal;
 mod parsed_type;
@@ -33,7 +34,9 @@ mod parsed_action_keyword;
 mod parsed_if_block;
 mod parsed_while_block;
 mod parsed_for_block;
+mod parsed_loop_label;
 mod parsed_branch;
+mod parsed_let_binding;
 mod parsed_var_declaration;
 mod parsed_var_declaration_qualifier;
 mod parsed_var_path_root;
@@ -102,6 +105,7 @@ mod parsed_macro_debug;
 mod parsed_match_branch_item;
 mod parsed_match_branch_type_item;
 mod parsed_match_branch_literal_item;
+mod parsed_match_branch_range_item;
 mod parsed_match_branch_body;
 mod parsed_self_field_default_value;
 mod parsed_var_type_declaration;
@@ -122,6 +126,12 @@ mod parsed_root_tag_name;
 mod parsed_load_directive;
 mod parsed_event_callback_index;
 mod parsed_event_callback_step;
+mod parsed_use_declaration;
+mod parsed_use_path;
+mod parsed_use_path_segment;
+mod parsed_namespace_segment;
+mod parsed_qualified_type;
+mod cached_items;
 
 pub use utils::*;
 pub use word::*;
@@ -140,6 +150,7 @@ pub use parsed_string_literal::*;
 pub use parsed_binary_operation::*;
 pub use parsed_array_literal::*;
 pub use parsed_field_declaration::*;
+pub use parsed_enum_variant_declaration::*;
 pub use parsed_method_declaration::*;
 pub use parsed_object_literal::*;
 pub use parsed_type::*;
@@ -158,7 +169,9 @@ pub use parsed_action_keyword::*;
 pub use parsed_if_block::*;
 pub use parsed_while_block::*;
 pub use parsed_for_block::*;
+pub use parsed_loop_label::*;
 pub use parsed_branch::*;
+pub use parsed_let_binding::*;
 pub use parsed_var_declaration::*;
 pub use parsed_var_declaration_qualifier::*;
 pub use parsed_var_path_root::*;
@@ -228,6 +241,7 @@ pub use parsed_macro_debug::*;
 pub use parsed_match_branch_item::*;
 pub use parsed_match_branch_type_item::*;
 pub use parsed_match_branch_literal_item::*;
+pub use parsed_match_branch_range_item::*;
 pub use parsed_match_branch_body::*;
 pub use parsed_self_field_default_value::*;
 pub use parsed_var_type_declaration::*;
@@ -247,4 +261,10 @@ pub use parsed_root_tag_declaration::*;
 pub use parsed_root_tag_name::*;
 pub use parsed_load_directive::*;
 pub use parsed_event_callback_index::*;
-pub use parsed_event_callback_step::*;
\ No newline at end of file
+pub use parsed_event_callback_step::*;
+pub use parsed_use_declaration::*;
+pub use parsed_use_path::*;
+pub use parsed_use_path_segment::*;
+pub use parsed_namespace_segment::*;
+pub use parsed_qualified_type::*;
+pub use cached_items::*;
\ No newline at end of file
@@ -1,18 +1,29 @@
 use std::{collections::HashMap};
 use parsable::parsable;
-use crate::{items::convert_to_bool, program::{BuiltinType, CompilationError, ProgramContext, ScopeKind, Type, Vasm, EVENT_OPTIONS_VAR_NAME}, wat};
-use super::{ParsedActionKeyword, ParsedActionKeywordToken, ParsedExpression};
+use crate::{items::convert_to_bool, program::{BuiltinType, CompilationError, ProgramContext, Type, Vasm, EVENT_OPTIONS_VAR_NAME}, wat};
+use super::{ParsedActionKeyword, ParsedActionKeywordToken, ParsedExpression, ParsedLoopLabel};
 
 #[parsable]
 pub struct ParsedAction {
     pub keyword: ParsedActionKeyword,
+    pub label: Option<ParsedLoopLabel>,
     pub expression: Option<ParsedExpression>
 }
 
 impl ParsedAction {
+    pub fn is_jump(&self) -> bool {
+        matches!(self.keyword.token, ParsedActionKeywordToken::Return | ParsedActionKeywordToken::Break | ParsedActionKeywordToken::Continue)
+    }
+
     pub fn process(&self, context: &mut ProgramContext) -> Option<Vasm> {
         let keyword = format!("{}", &self.keyword.token);
 
+        if let Some(label) = &self.label {
+            if !matches!(self.keyword.token, ParsedActionKeywordToken::Break | ParsedActionKeywordToken::Continue) {
+                context.errors.generic(label, format!("unexpected label after `{}`", &keyword));
+            }
+        }
+
         match &self.keyword.token {
             ParsedActionKeywordToken::Return => {
                 match context.get_current_function_return_type() {
@@ -46,6 +57,10 @@ impl ParsedAction {
                                     Some(expr) => match expr.process(Some(&return_type), context) {
                                         Some(vasm) => match vasm.ty.is_assignable_to(&return_type) {
                                             true => {
+                                                let is_return_optional = context.is_current_function_return_optional();
+
+                                                context.check_optional_assignment(expr, &vasm, is_return_optional);
+
                                                 Some(
                                                     context.vasm()
                                                         .return_value(vasm)
@@ -76,9 +91,17 @@ impl ParsedAction {
             ParsedActionKeywordToken::Check => {
                 match context.get_current_function_return_type() {
                     Some(return_type) => {
+                        if !return_type.is_void() && !context.is_current_function_return_optional() && context.is_strict_none_enabled(&self.keyword) {
+                            context.errors.generic(&self.keyword, format!("`check` returns `none`, but the function return type `{}` is not optional", &return_type));
+                        }
+
                         match &self.expression {
                             Some(value) => {
-                                match value.process(None, context) {
+                                let mut narrowed_variables = vec![];
+
+                                value.collect_narrowed_variables(true, &mut narrowed_variables, context);
+
+                                let result = match value.process(None, context) {
                                     Some(vasm) => match convert_to_bool(value, vasm, context) {
                                         Some(bool_vasm) => Some(context.vasm()
                                             .if_then_else(None, bool_vasm, context.vasm(), context.vasm()
@@ -89,7 +112,11 @@ impl ParsedAction {
                                         None => None,
                                     },
                                     None => None,
-                                }
+                                };
+
+                                context.narrow_variables(&narrowed_variables);
+
+                                result
                             },
                             None => {
                                 context.errors.expected_expression(&self.keyword.location.get_end());
@@ -101,33 +128,67 @@ impl ParsedAction {
                 }
             },
             ParsedActionKeywordToken::Break | ParsedActionKeywordToken::Continue => {
-                match &self.expression {
-                    Some(expr) => {
-                        expr.process(None, context);
-                        context.errors.unexpected_expression(expr);
-                        None
-                    },
-                    None => {
-                        match context.get_scope_depth(ScopeKind::Loop) {
-                            Some(depth) => {
-                                match &self.keyword.token {
-                                    ParsedActionKeywordToken::Break => Some(context.vasm()
-                                        .jump(depth + 1)
-                                        .set_type(context.void_type())
-                                    ),
-                                    ParsedActionKeywordToken::Continue => Some(context.vasm()
-                                        .jump(depth)
-                                        .set_type(context.void_type())
-                                    ),
-                                    _ => unreachable!()
+                let label_name = self.label.as_ref().map(|label| label.get_name());
+                let depth_opt = match &self.label {
+                    Some(label) => label.process(context),
+                    None => context.get_loop_scope_depth(None),
+                };
+
+                if self.label.is_none() && depth_opt.is_none() {
+                    context.errors.unexpected_keyword(&self.keyword, &keyword);
+                }
+
+                match &self.keyword.token {
+                    ParsedActionKeywordToken::Break => match &self.expression {
+                        Some(expr) => {
+                            let vasm_opt = expr.process(None, context);
+                            let depth = depth_opt?;
+                            let vasm = vasm_opt?;
+
+                            if vasm.ty.is_void() {
+                                context.errors.unexpected_void_expression(expr);
+                                return None;
+                            }
+
+                            let break_variable = match context.get_loop_break_variable(label_name, &vasm.ty) {
+                                Some(break_variable) => break_variable,
+                                None => {
+                                    context.errors.unexpected_expression(expr);
+                                    return None;
                                 }
-                            },
-                            None => {
-                                context.errors.unexpected_keyword(&self.keyword, &keyword);
-                                None
+                            };
+
+                            let break_type = break_variable.ty().clone();
+
+                            if !vasm.ty.is_assignable_to(&break_type) {
+                                context.errors.type_mismatch(expr, &break_type, &vasm.ty);
+                                return None;
                             }
-                        }
-                    }
+
+                            Some(context.vasm()
+                                .append(vasm)
+                                .set_tmp_var(&break_variable)
+                                .jump(depth + 1)
+                                .set_type(context.void_type())
+                            )
+                        },
+                        None => Some(context.vasm()
+                            .jump(depth_opt? + 1)
+                            .set_type(context.void_type())
+                        ),
+                    },
+                    ParsedActionKeywordToken::Continue => match &self.expression {
+                        Some(expr) => {
+                            expr.process(None, context);
+                            context.errors.unexpected_expression(expr);
+                            None
+                        },
+                        None => Some(context.vasm()
+                            .jump(depth_opt?)
+                            .set_type(context.void_type())
+                        ),
+                    },
+                    _ => unreachable!()
                 }
             },
             ParsedActionKeywordToken::Intercept | ParsedActionKeywordToken::Yield => {
@@ -148,7 +209,7 @@ impl ParsedAction {
 
                                         Some(context.vasm()
                                             .get_var(&output_var, None)
-                                            .set_field(&intercepted_field_info.ty, intercepted_field_info.offset, None, context.vasm().int(1i32))
+                                            .set_field(&event_output_type, intercepted_field_info.name.as_str(), &intercepted_field_info.ty, None, context.vasm().int(1i32))
                                             .return_value(context.vasm())
                                             .set_type(context.void_type())
                                         )
@@ -161,7 +222,7 @@ impl ParsedAction {
 
                                             Some(context.vasm()
                                                 .get_var(&output_var, None)
-                                                .get_field(&yielded_field_info.ty, yielded_field_info.offset, None)
+                                                .get_field(&event_output_type, yielded_field_info.name.as_str(), &yielded_field_info.ty, None)
                                                 .call_regular_method(&yielded_field_info.ty, "push", &[], vec![vasm], context)
                                                 .drop(&yielded_field_info.ty)
                                                 .set_type(context.void_type())
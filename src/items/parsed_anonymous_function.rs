@@ -40,7 +40,10 @@ impl ParsedAnonymousFunction {
             let arg_type = match typt_opt {
                 Some(ty) => ty,
                 None => match expected_arg_types.get(i) {
-                    Some(ty) => ty.clone(),
+                    Some(ty) => {
+                        context.inlay_hints_provider.add_type_hint(name, ty);
+                        ty.clone()
+                    },
                     None => {
                         context.errors.generic(name, format!("cannot infer type of `{}`", name.as_str().bold()));
                         Type::undefined()
@@ -51,6 +54,7 @@ impl ParsedAnonymousFunction {
             arguments.push(ArgumentInfo {
                 name: name.clone(),
                 ty: arg_type,
+                has_default_value: false,
                 is_optional: false,
                 default_value: context.vasm(),
             });
@@ -61,7 +65,8 @@ impl ParsedAnonymousFunction {
                 arguments.push(ArgumentInfo {
                     name: Identifier::unlocated(&format!("__unused_arg_{}", i + provided_argument_count)),
                     ty: ty.clone(),
-                    is_optional: true,
+                    has_default_value: true,
+                    is_optional: false,
                     default_value: context.vasm(),
                 });
             }
@@ -85,6 +90,8 @@ impl ParsedAnonymousFunction {
             parameters: parameters.clone(),
             arguments,
             signature: signature.clone(),
+            // the return type of an anonymous function is inferred, so it cannot be declared as optional
+            is_return_optional: true,
             argument_variables: vec![],
             owner_type: context.get_current_type(),
             owner_interface: context.get_current_interface(),
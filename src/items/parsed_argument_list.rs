@@ -1,23 +1,25 @@
 use parsable::{parsable, ItemLocation};
+use crate::program::{ProgramContext, VariableInfo};
 use super::{ParsedExpression, ParsedOpeningRoundBracket, ParsedClosingRoundBracket, ParsedCommaToken};
 
 #[parsable]
 pub struct ParsedArgumentList {
-    opening_bracket: ParsedOpeningRoundBracket,
-    content: Option<ParsedArgumentListContent>,
-    closing_bracket: ParsedClosingRoundBracket,
+    pub opening_bracket: ParsedOpeningRoundBracket,
+    #[parsable(unset_marker = "no-anonymous-function")]
+    pub content: Option<ParsedArgumentListContent>,
+    pub closing_bracket: ParsedClosingRoundBracket,
 }
 
 #[parsable]
-struct ParsedArgumentListContent {
-    first_argument: ParsedExpression,
-    other_arguments: Vec<ParsedOtherArgument>
+pub struct ParsedArgumentListContent {
+    pub first_argument: ParsedExpression,
+    pub other_arguments: Vec<ParsedOtherArgument>
 }
 
 #[parsable]
-struct ParsedOtherArgument {
-    comma: ParsedCommaToken,
-    expression: Option<ParsedExpression>
+pub struct ParsedOtherArgument {
+    pub comma: ParsedCommaToken,
+    pub expression: Option<ParsedExpression>
 }
 
 pub struct ArgumentListIterator<'a> {
@@ -60,6 +62,12 @@ impl<'a> IntoIterator for &'a ParsedArgumentList {
 }
 
 impl ParsedArgumentList {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        for argument in self.into_iter().flatten() {
+            argument.collect_assigned_variables(list, context);
+        }
+    }
+
     pub fn len(&self) -> usize {
         match &self.content {
             Some(content) => 1 + content.other_arguments.len(),
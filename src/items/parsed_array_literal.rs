@@ -10,6 +10,12 @@ pub struct ParsedArrayLiteral {
 }
 
 impl ParsedArrayLiteral {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        for item in &self.items {
+            item.collect_assigned_variables(list, context);
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         for item in &self.items {
             item.collect_instancied_type_names(list, context);
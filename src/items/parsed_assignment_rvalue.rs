@@ -3,7 +3,7 @@ use super::{ParsedExpression, ParsedAssignmentOperator};
 
 #[parsable]
 pub struct ParsedAssignmentRvalue {
-    #[parsable(not_followed_by="=")]
+    #[parsable(not_followed_by="[=>]")] // to avoid the confusion with `==` and `=>`
     pub operator: ParsedAssignmentOperator,
     pub expression: Option<ParsedExpression>
 }
\ No newline at end of file
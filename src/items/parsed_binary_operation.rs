@@ -1,6 +1,6 @@
 use parsable::{ItemLocation, parsable};
-use crate::{program::{BuiltinType, IS_NONE_METHOD_NAME, ProgramContext, Type, Vasm}, wat};
-use super::{ParsedBinaryOperator, ParsedType, Identifier, ParsedOperand};
+use crate::{program::{BuiltinType, IS_NONE_METHOD_NAME, ProgramContext, Type, VariableInfo, Vasm}, wat};
+use super::{ParsedBinaryOperator, ParsedBinaryOperatorToken, ParsedType, Identifier, ParsedOperand};
 
 #[parsable]
 pub struct ParsedBinaryOperation {
@@ -9,6 +9,14 @@ pub struct ParsedBinaryOperation {
 }
 
 impl ParsedBinaryOperation {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.first.collect_assigned_variables(list, context);
+
+        for (_, operand) in &self.others {
+            operand.collect_assigned_variables(list, context);
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         self.first.collect_instancied_type_names(list, context);
         
@@ -17,6 +25,13 @@ impl ParsedBinaryOperation {
         }
     }
 
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        match self.others.is_empty() {
+            true => self.first.collect_narrowed_variables(is_truthy, list, context),
+            false => OperationTree::from_operation(self).collect_narrowed_variables(is_truthy, list, context),
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         match self.others.is_empty() {
             true => self.first.process(type_hint, context),
@@ -37,7 +52,18 @@ impl<'a> OperationTree<'a> {
             OperationTree::Operation(left, operator, right) => {
                 let left_vasm_result = left.process(None, context);
                 let right_type_hint = left_vasm_result.as_ref().and_then(|vasm| operator.get_type_hint(&vasm.ty, context));
+                let mut narrowed_variables = vec![];
+
+                // the right operand of `&&` (resp. `||`) is only evaluated if the left operand is truthy (resp. falsy)
+                match &operator.token {
+                    ParsedBinaryOperatorToken::DoubleAnd => left.collect_narrowed_variables(true, &mut narrowed_variables, context),
+                    ParsedBinaryOperatorToken::DoubleOr => left.collect_narrowed_variables(false, &mut narrowed_variables, context),
+                    _ => {}
+                }
+
+                context.narrow_variables(&narrowed_variables);
                 let right_vasm_result = right.process(right_type_hint.as_ref().map(|ty| ty.as_ref()), context);
+                context.unnarrow_variables(&narrowed_variables);
 
                 match (left_vasm_result, right_vasm_result) {
                     (Some(left_vasm), Some(right_vasm)) => operator.process(left_vasm, right_vasm, right.get_location(), context),
@@ -48,6 +74,19 @@ impl<'a> OperationTree<'a> {
         }
     }
 
+    fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        match self {
+            OperationTree::Operation(left, operator, right) => match (&operator.token, is_truthy) {
+                (ParsedBinaryOperatorToken::DoubleAnd, true) | (ParsedBinaryOperatorToken::DoubleOr, false) => {
+                    left.collect_narrowed_variables(is_truthy, list, context);
+                    right.collect_narrowed_variables(is_truthy, list, context);
+                },
+                _ => {}
+            },
+            OperationTree::Value(operand) => operand.collect_narrowed_variables(is_truthy, list, context),
+        }
+    }
+
     fn from_operation(operation: &'a ParsedBinaryOperation) -> Self {
         let mut list : Vec<(ParsedBinaryOperator, &'a ParsedOperand, usize)> = operation.others.iter().enumerate().map(|(i, (operator, operand))| {
             let priority = operator.get_priority() * 256 + i;
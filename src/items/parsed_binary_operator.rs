@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use parsable::{ItemLocation, parsable};
 use colored::*;
-use crate::{items::Identifier, program::{BuiltinInterface, CompilationError, IS_NONE_METHOD_NAME, NONE_METHOD_NAME, ProgramContext, Type, VariableInfo, VariableKind, Vasm}, wat};
+use crate::{items::Identifier, program::{BuiltinInterface, CompilationError, EQ_METHOD_NAME, IS_NONE_METHOD_NAME, NONE_METHOD_NAME, ProgramContext, Type, VariableInfo, VariableKind, Vasm}, wat};
 
 #[parsable]
 #[derive(Default, Clone)]
@@ -137,16 +137,17 @@ impl ParsedBinaryOperator {
 
         match operator_kind {
             OperatorKind::Equality(kind) => {
-                let method_name = match kind {
-                    EqualityOperator::Equal => "__eq",
-                    EqualityOperator::NotEqual => "__ne",
-                };
-
                 match right_vasm.ty.is_assignable_to(&left_vasm.ty) || left_vasm.ty.is_assignable_to(&right_vasm.ty) {
                     true => {
-                        let operator_vasm = context.vasm()
-                            .call_regular_method(&left_vasm.ty, method_name, &[], vec![], context)
-                            .set_type(context.bool_type());
+                        // functions cannot implement interfaces, so they are compared directly
+                        let eq_vasm = match left_vasm.ty.is_function() {
+                            true => context.vasm().call_regular_method(&left_vasm.ty, EQ_METHOD_NAME, &[], vec![], context),
+                            false => left_vasm.ty.call_builtin_interface_no_arg(self, BuiltinInterface::Eq, context)?,
+                        };
+                        let operator_vasm = match kind {
+                            EqualityOperator::Equal => eq_vasm,
+                            EqualityOperator::NotEqual => eq_vasm.eqz(),
+                        }.set_type(context.bool_type());
 
                         let result = context.vasm()
                             .append(left_vasm)
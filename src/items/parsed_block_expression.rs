@@ -1,5 +1,5 @@
-use parsable::parsable;
-use crate::{program::{ProgramContext, ScopeKind, Type, Vasm}};
+use parsable::{parsable, ItemLocation};
+use crate::{program::{ProgramContext, ScopeKind, Type, VariableInfo, Vasm, LintKind}};
 use super::{ParsedExpression, ParsedSemicolonToken};
 
 #[parsable(name="block")]
@@ -15,23 +15,52 @@ pub struct ParsedBlockItem {
 }
 
 impl ParsedBlockExpression {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        for item in &self.list {
+            item.expression.collect_assigned_variables(list, context);
+        }
+    }
+
+    pub fn is_jump(&self) -> bool {
+        self.list.iter().any(|item| item.expression.is_jump())
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let mut result = context.vasm().set_void(context);
         let is_result_void = type_hint.map(|ty| ty.is_void()).unwrap_or(false);
+        let void_type = context.void_type();
 
         context.push_scope(ScopeKind::Block);
 
+        if let Some(index) = self.list.iter().position(|item| item.expression.is_jump()) {
+            if let (Some(first), Some(last)) = (self.list.get(index + 1), self.list.last()) {
+                let location = ItemLocation {
+                    file: first.location.file.clone(),
+                    start: first.location.start,
+                    end: last.location.end,
+                };
+
+                context.lint(&location, LintKind::UnreachableCode, "");
+            }
+        }
+
         for (i, item) in self.list.iter().enumerate() {
             let is_last = i == self.list.len() - 1;
+            let is_discarded = !is_last || item.semicolon.is_some() || is_result_void;
             let hint = match is_last {
                 true => type_hint,
                 false => None,
             };
+            // a loop whose value is discarded does not accept `break <value>`
+            let hint = match is_discarded && item.expression.is_loop() {
+                true => Some(&void_type),
+                false => hint,
+            };
 
             if let Some(item_vasm) = item.expression.process(hint, context) {
                 result = result.append(item_vasm);
 
-                if !is_last || item.semicolon.is_some() || is_result_void {
+                if is_discarded {
                     let ty = result.ty.clone();
 
                     result = result
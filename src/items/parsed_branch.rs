@@ -1,17 +1,45 @@
 use colored::Colorize;
 use parsable::{ItemLocation, parsable};
-use crate::{program::{BuiltinInterface, BuiltinType, CompilationError, IS_NONE_METHOD_NAME, ProgramContext, Type, Vasm}, wat};
-use super::{ParsedExpression, ParsedBlockExpression};
+use crate::{program::{BuiltinInterface, BuiltinType, CompilationError, IS_NONE_METHOD_NAME, ProgramContext, Type, VariableInfo, Vasm}, wat};
+use super::{ParsedExpression, ParsedBlockExpression, ParsedLetBinding};
 
 #[parsable]
 pub struct ParsedBranch {
+    pub let_binding: Option<ParsedLetBinding>,
     #[parsable(declare_marker = "no-object")]
     pub condition: ParsedExpression,
     pub body: Option<ParsedBlockExpression>
 }
 
 impl ParsedBranch {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.condition.collect_assigned_variables(list, context);
+
+        if let Some(body) = &self.body {
+            body.collect_assigned_variables(list, context);
+        }
+    }
+
+    pub fn is_jump(&self) -> bool {
+        match &self.body {
+            Some(body) => body.is_jump(),
+            None => false,
+        }
+    }
+
+    pub fn get_narrowed_variables(&self, is_truthy: bool, context: &ProgramContext) -> Vec<VariableInfo> {
+        let mut result = vec![];
+
+        self.condition.collect_narrowed_variables(is_truthy, &mut result, context);
+
+        result
+    }
+
     pub fn process_condition(&self, context: &mut ProgramContext) -> Option<Vasm> {
+        if let Some(let_binding) = &self.let_binding {
+            return let_binding.process(&self.condition, context);
+        }
+
         match self.condition.process(None, context) {
             Some(condition_vasm) => convert_to_bool(&self.condition, condition_vasm, context),
             None => None,
@@ -0,0 +1,10 @@
+use parsable::parsable;
+use super::{Identifier, ParsedFieldDeclaration, ParsedCommaToken};
+
+#[parsable]
+pub struct ParsedEnumVariantDeclaration {
+    pub name: Identifier,
+    #[parsable(brackets="{}")]
+    pub fields: Vec<ParsedFieldDeclaration>,
+    pub comma: Option<ParsedCommaToken>,
+}
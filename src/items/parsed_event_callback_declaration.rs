@@ -1,7 +1,7 @@
 use colored::Colorize;
 use indexmap::IndexMap;
 use parsable::{parsable, ItemLocation};
-use crate::{program::{FunctionBlueprint, ProgramContext, EVENT_VAR_NAME, EVENT_OPTIONS_VAR_NAME, Signature, BuiltinType, MethodDetails, Vasm, ScopeKind, SELF_VAR_NAME, Visibility, MethodQualifier, FunctionBody, FieldVisibility, ArgumentInfo, SELF_TYPE_NAME, EventCallbackStep, FunctionKind, EventCallback}, utils::Link, wat};
+use crate::{program::{FunctionBlueprint, ProgramContext, EVENT_VAR_NAME, EVENT_OPTIONS_VAR_NAME, Signature, BuiltinType, MethodDetails, Vasm, ScopeKind, SELF_VAR_NAME, Visibility, MethodQualifier, FunctionBody, FieldVisibility, ArgumentInfo, SELF_TYPE_NAME, EventCallbackStep, FunctionKind, EventCallback}, utils::Link, wat, language_server::SemanticTokenKind};
 use super::{ParsedEventCallbackQualifierKeyword, Identifier, ParsedExpression, ParsedBlockExpression, ParsedVisibilityToken, ParsedEventCallbackIndex, FlexPrefixedWordItem, ParsedColonToken, ParsedEventCallbackEventStep};
 
 #[parsable]
@@ -35,6 +35,8 @@ impl ParsedEventCallbackDeclaration {
 
                 match type_wrapped {
                     Some(event_type) => {
+                        context.mark_type_as_used(&event_type);
+
                         // let event_class_name = BuiltinType::Event.get_name();
                         // let is_valid_event = event_type.borrow().self_type.inherits_from(event_class_name);
 
@@ -64,6 +66,8 @@ impl ParsedEventCallbackDeclaration {
         if name.as_str() != SELF_TYPE_NAME {
             context.rename_provider.add_occurence(name, &event_type.borrow().name);
         }
+
+        context.semantic_tokens_provider.add_token(&ItemLocation { end: name.location.end, ..self.event_callback_qualifier.location.clone() }, SemanticTokenKind::Event, &[]);
         
         context.definition_provider.set_definition(name, &event_type.borrow().name);
 
@@ -74,12 +78,14 @@ impl ParsedEventCallbackDeclaration {
         let event_argument = ArgumentInfo {
             name: Identifier::new(EVENT_VAR_NAME, None),
             ty: event_type.borrow().self_type.clone(),
+            has_default_value: false,
             is_optional: false,
             default_value: context.vasm()
         };
         let output_argument = ArgumentInfo {
             name: Identifier::new(EVENT_OPTIONS_VAR_NAME, None),
             ty: context.get_builtin_type(BuiltinType::EventOptions, vec![]),
+            has_default_value: false,
             is_optional: false,
             default_value: context.vasm()
         };
@@ -96,6 +102,7 @@ impl ParsedEventCallbackDeclaration {
             parameters: IndexMap::new(),
             arguments,
             signature,
+            is_return_optional: false,
             argument_variables: vec![],
             owner_type: Some(this_type.clone()),
             owner_interface: None,
@@ -132,7 +139,7 @@ impl ParsedEventCallbackDeclaration {
                                             .if_then_else(None,
                                                 context.vasm()
                                                     .get_var(&event_var_info, current_function_level)
-                                                    .get_field(&field_info.ty, field_info.offset, None)
+                                                    .get_field(&event_var_info.ty(), event_field_name, &field_info.ty, None)
                                                     .get_var(&self_var_info, current_function_level)
                                                     .raw(wat!["i32.eq"]),
                                                 context.vasm(),
@@ -2,7 +2,7 @@ use std::{fmt::format, rc::Rc};
 use colored::Colorize;
 use parsable::{ItemLocation, parsable};
 use crate::{program::{IS_METHOD_NAME, ProgramContext, Type, VariableInfo, VariableKind, Vasm}};
-use super::{ParsedBinaryOperation, Identifier, ParsedType, ParsedIsOperation, ParsedAsOperation};
+use super::{ParsedBinaryOperation, Identifier, ParsedType, ParsedIsOperation, ParsedAsOperation, ParsedOperand, ParsedOperandSuffix, ParsedVarPathRoot};
 
 #[parsable(name="expression")]
 pub struct ParsedExpression {
@@ -10,10 +10,59 @@ pub struct ParsedExpression {
 }
 
 impl ParsedExpression {
+    // Collects the variables assigned by the expression (assignments inside anonymous functions are not included)
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.operation.collect_assigned_variables(list, context);
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         self.operation.collect_instancied_type_names(list, context);
     }
 
+    // Collects the variables that cannot be `none` when the expression evaluates to `is_truthy`
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.operation.collect_narrowed_variables(is_truthy, list, context);
+    }
+
+    pub fn is_jump(&self) -> bool {
+        if !self.operation.others.is_empty() {
+            return false;
+        }
+
+        match &self.operation.first {
+            ParsedOperand::OperandBody(body) => match body.lvalue.root.as_ref() {
+                ParsedVarPathRoot::Action(action) => body.lvalue.path.is_empty() && body.suffix.is_none() && action.is_jump(),
+                _ => false
+            },
+            ParsedOperand::UnaryOperation(_) => false,
+        }
+    }
+
+    pub fn is_loop(&self) -> bool {
+        if !self.operation.others.is_empty() {
+            return false;
+        }
+
+        match &self.operation.first {
+            ParsedOperand::OperandBody(body) => match body.lvalue.root.as_ref() {
+                ParsedVarPathRoot::WhileBlock(_) | ParsedVarPathRoot::ForBlock(_) => body.lvalue.path.is_empty() && body.suffix.is_none(),
+                _ => false
+            },
+            ParsedOperand::UnaryOperation(_) => false,
+        }
+    }
+
+    pub fn is_cast(&self) -> bool {
+        if !self.operation.others.is_empty() {
+            return false;
+        }
+
+        match &self.operation.first {
+            ParsedOperand::OperandBody(body) => matches!(body.suffix, Some(ParsedOperandSuffix::AsOperation(_))),
+            ParsedOperand::UnaryOperation(_) => false,
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let mut result = None;
 
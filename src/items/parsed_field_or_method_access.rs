@@ -2,8 +2,8 @@ use std::{cell::Ref, collections::{HashMap, HashSet}, rc::Rc};
 use indexmap::IndexMap;
 use parsable::parsable;
 use colored::*;
-use crate::{program::{AccessType, AnonymousFunctionCallDetails, DUPLICATE_INT_WASM_FUNC_NAME, FieldKind, FunctionBlueprint, FunctionCall, GET_AT_INDEX_FUNC_NAME, NONE_LITERAL, NONE_METHOD_NAME, NamedFunctionCallDetails, ParameterTypeInfo, ProgramContext, Type, VariableInfo, VariableKind, Vasm, Wat, print_type_list, print_type_ref_list, TypeContent}, utils::Link, wat, language_server::FieldCompletionOptions};
-use super::{ParsedArgumentList, Identifier, ParsedIdentifierWrapper, ParsedVarPrefixToken, ParsedDotToken};
+use crate::{program::{AccessType, AnonymousFunctionCallDetails, DUPLICATE_INT_WASM_FUNC_NAME, FieldKind, FunctionBlueprint, FunctionCall, GET_AT_INDEX_FUNC_NAME, NONE_LITERAL, NONE_METHOD_NAME, NamedFunctionCallDetails, ParameterTypeInfo, ProgramContext, Type, VariableInfo, VariableKind, Vasm, Wat, print_type_list, print_type_ref_list, TypeContent}, utils::Link, wat, language_server::{FieldCompletionOptions, SemanticTokenKind}};
+use super::{ParsedArgumentList, Identifier, ParsedIdentifierWrapper, ParsedVarPrefixToken, ParsedDotToken, instanciate_type};
 
 #[parsable]
 pub struct ParsedFieldOrMethodAccess {
@@ -64,19 +64,26 @@ pub fn process_field_access(parent_type: &Type, field_kind: FieldKind, field_nam
                     true => Some(&field_name.location),
                     false => None,
                 };
+                let is_optional = match access_type {
+                    AccessType::Get => field_info.is_optional,
+                    // a type parameter may stand for an optional type
+                    AccessType::Set(_) => field_info.is_optional || field_info.ty.is_parameter(),
+                };
                 let mut vasm = context.vasm()
-                    .set_type(&field_type);
+                    .set_type(&field_type)
+                    .set_optional(is_optional);
 
                 match access_type {
                     AccessType::Get => {
-                        vasm = vasm.get_field(&field_type, field_info.offset, check_location);
+                        vasm = vasm.get_field(parent_type, field_name.as_str(), &field_type, check_location);
                     },
                     AccessType::Set(location) => {
-                        vasm = vasm.set_field(&field_type, field_info.offset, check_location, context.vasm().placeholder(location));
+                        vasm = vasm.set_field(parent_type, field_name.as_str(), &field_type, check_location, context.vasm().placeholder(location));
                     },
                 };
 
                 context.rename_provider.add_occurence(field_name, &field_info.name);
+                context.semantic_tokens_provider.add_occurence(field_name, &field_info.name, SemanticTokenKind::Property, &[]);
                 context.definition_provider.set_definition(field_name, &field_info.name);
                 context.hover_provider.set_type(field_name, &field_type);
 
@@ -91,21 +98,30 @@ pub fn process_field_access(parent_type: &Type, field_kind: FieldKind, field_nam
                     result = Some(context.vasm()
                         .call_static_method(parent_type, NONE_METHOD_NAME, &[], vec![], context)
                         .set_type(parent_type)
+                        .set_optional(true)
                     );
                 },
                 false => match parent_type.content() {
                     TypeContent::Actual(info) => info.type_blueprint.with_ref(|type_unwrapped| {
                         if let Some(variant_info) = type_unwrapped.enum_variants.get(field_name.as_str()) {
                             context.rename_provider.add_occurence(field_name, &variant_info.name);
+                            context.semantic_tokens_provider.add_occurence(field_name, &variant_info.name, SemanticTokenKind::EnumMember, &[]);
                             context.definition_provider.set_definition(field_name, &variant_info.name);
                             context.hover_provider.set_type(field_name, parent_type);
 
                             match access_type {
-                                AccessType::Get => {
-                                    result = Some(context.vasm()
-                                        .int(variant_info.value)
-                                        .set_type(parent_type)
-                                    );
+                                AccessType::Get => match parent_type.get_variant_type(variant_info) {
+                                    Some(variant_type) => {
+                                        result = Some(instanciate_type(&variant_type, field_name, &[], context)
+                                            .set_type(parent_type)
+                                        );
+                                    },
+                                    None => {
+                                        result = Some(context.vasm()
+                                            .int(variant_info.value)
+                                            .set_type(parent_type)
+                                        );
+                                    }
                                 },
                                 AccessType::Set(location) => {
                                     context.errors.generic(location, format!("cannot set value of enum variant"));
@@ -135,6 +151,7 @@ pub fn process_method_call(caller_type: &Type, field_kind: FieldKind, method_nam
             match function_unwrapped.get_dynamic_index() {
                 Some(dynamic_index) => {
                     context.rename_provider.add_occurence(method_name, &function_unwrapped.name);
+                    context.semantic_tokens_provider.add_function_occurence(method_name, &function_unwrapped);
                     context.definition_provider.set_definition(method_name, &function_unwrapped.name);
                     context.hover_provider.set_type(method_name, &function_unwrapped.get_self_type());
 
@@ -151,7 +168,10 @@ pub fn process_method_call(caller_type: &Type, field_kind: FieldKind, method_nam
             }
         });
 
-        result = process_function_call(Some(method_name), function_call, arguments, type_hint, access_type, context);
+        let is_return_optional = func_ref.function.borrow().is_return_optional;
+
+        result = process_function_call(Some(method_name), function_call, arguments, type_hint, access_type, context)
+            .map(|vasm| vasm.set_optional(is_return_optional));
     } else if !caller_type.is_undefined() {
         context.errors.generic(method_name, format!("type `{}` has no {}method `{}`", caller_type, field_kind.get_qualifier(), method_name.as_str().bold()));
     }
@@ -168,6 +188,7 @@ pub fn process_function_call(function_identifier: Option<&Identifier>, mut funct
     if let Some(identifier) = function_identifier {
         if let FunctionCall::Named(details) = &function_call {
             context.rename_provider.add_occurence(identifier, &details.function.borrow().name);
+            context.semantic_tokens_provider.add_function_occurence(identifier, &details.function.borrow());
             context.definition_provider.set_definition(identifier, &details.function.borrow().name);
             context.hover_provider.set_type(identifier, &details.function.borrow().get_self_type());
         }
@@ -191,6 +212,11 @@ pub fn process_function_call(function_identifier: Option<&Identifier>, mut funct
         FunctionCall::Named(details) => details.function.borrow().parameters.values().map(|info| Type::function_parameter(&info)).collect(),
         FunctionCall::Anonymous(_) => vec![],
     };
+    // the signature of a function value does not hold the names of its arguments
+    let argument_names : Vec<String> = match &function_call {
+        FunctionCall::Named(details) => details.function.borrow().arguments.iter().map(|arg| arg.name.to_string()).collect(),
+        FunctionCall::Anonymous(_) => vec![],
+    };
 
     let mut remaining_param_indexes_to_infer : HashSet<usize> = HashSet::new();
 
@@ -219,6 +245,10 @@ pub fn process_function_call(function_identifier: Option<&Identifier>, mut funct
         let mut arg_vasm = context.vasm();
 
         if let Some(arg_expression) = arg {
+            if let Some(argument_name) = argument_names.get(i) {
+                context.inlay_hints_provider.add_parameter_hint(&arg_expression.location, argument_name);
+            }
+
             if let Some(vasm) = arg_expression.process(hint.as_ref(), context) {
                 if let Some(expected_type) = signature.argument_types.get(i) {
                     function_parameters = infer_function_parameters(&function_parameters, &mut remaining_param_indexes_to_infer, &vasm.ty, expected_type);
@@ -238,7 +268,7 @@ pub fn process_function_call(function_identifier: Option<&Identifier>, mut funct
             for i in arg_vasms.len()..function_unwrapped.arguments.len() {
                 let arg = &function_unwrapped.arguments[i];
 
-                if arg.is_optional {
+                if arg.has_default_value {
                     arg_vasms.push(arg.default_value.clone());
                 }
             }
@@ -278,6 +308,11 @@ pub fn process_function_call(function_identifier: Option<&Identifier>, mut funct
                             context.errors.generic(location, format!("cannot infer type"));
                         } else if !arg_vasm.ty.is_assignable_to(&expected_type) {
                             context.errors.type_mismatch(location, &expected_type, &arg_vasm.ty);
+                        } else if let FunctionCall::Named(details) = &function_call {
+                            // a type parameter may stand for an optional type
+                            let is_arg_optional = details.function.borrow().arguments.get(i).map(|arg| arg.is_optional || arg.ty.is_parameter()).unwrap_or(true);
+
+                            context.check_optional_assignment(location, arg_vasm, is_arg_optional);
                         }
                     }
                 }
@@ -307,9 +342,12 @@ pub fn process_function_call(function_identifier: Option<&Identifier>, mut funct
 
     let result = match function_call {
         FunctionCall::Named(details) => {
+            let is_return_optional = details.function.borrow().is_return_optional;
+
             context.vasm()
                 .call_function_named(check_location, details.caller_type.as_ref(), &details.function, &function_parameters, arg_vasms)
                 .set_type(return_type)
+                .set_optional(is_return_optional)
         },
         FunctionCall::Anonymous(details) => {
             context.vasm()
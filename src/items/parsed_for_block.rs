@@ -1,13 +1,15 @@
 use std::borrow::Cow;
 use parsable::{create_token_struct, parsable};
-use crate::{program::{BuiltinInterface, BuiltinType, GET_AT_INDEX_FUNC_NAME, GET_ITERABLE_LEN_FUNC_NAME, GET_ITERABLE_PTR_FUNC_NAME, ITERABLE_ASSOCIATED_TYPE_NAME, ProgramContext, ScopeKind, Type, TypeIndex, VariableInfo, VariableKind, Vasm, Wat, FOR_KEYWORD, IN_KEYWORD}, wat};
-use super::{ParsedExpression, Identifier, ParsedBlockExpression, ParsedVarDeclarationNames, ParsedOpeningSquareBracket, ParsedForIterator, unwrap_item};
+use crate::{program::{BuiltinInterface, BuiltinType, FieldKind, GET_AT_INDEX_FUNC_NAME, GET_ITERABLE_LEN_FUNC_NAME, GET_ITERABLE_PTR_FUNC_NAME, ITERABLE_ASSOCIATED_TYPE_NAME, ITER_METHOD_NAME, NEXT_METHOD_NAME, IS_NONE_METHOD_NAME, ProgramContext, ScopeKind, Type, TypeIndex, VariableInfo, VariableKind, Vasm, Wat, FOR_KEYWORD, IN_KEYWORD}, wat};
+use super::{ParsedExpression, Identifier, ParsedBlockExpression, ParsedVarDeclarationNames, ParsedOpeningSquareBracket, ParsedForIterator, ParsedLoopLabel, unwrap_item, wrap_loop_result};
 
 create_token_struct!(ForKeyword, FOR_KEYWORD);
 create_token_struct!(InKeyword, IN_KEYWORD);
 
 #[parsable(cascade=true)]
 pub struct ParsedForBlock {
+    #[parsable(suffix=":", cascade=false)]
+    pub label: Option<ParsedLoopLabel>,
     pub for_keyword: ForKeyword,
     pub iterator: Option<ParsedForIterator>,
     pub in_keyword: Option<InKeyword>,
@@ -20,19 +22,41 @@ pub struct ParsedForBlock {
 }
 
 impl ParsedForBlock {
-    pub fn process(&self, context: &mut ProgramContext) -> Option<Vasm> {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        for expression in self.range_start.iter().chain(self.range_end.iter()) {
+            expression.collect_assigned_variables(list, context);
+        }
+
+        if let Some(body) = &self.body {
+            body.collect_assigned_variables(list, context);
+        }
+    }
+
+    pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let iterator = unwrap_item(&self.iterator, &self.for_keyword, context)?;
         let (index_var_name, item_var_names) = iterator.process(context)?;
         let in_keyword = unwrap_item(&self.in_keyword, iterator.location(), context)?;
         let range_start = unwrap_item(&self.range_start, in_keyword, context)?;
 
-        context.push_scope(ScopeKind::Loop);
+        let is_value_discarded = type_hint.map(|ty| ty.is_void()).unwrap_or(false);
+
+        context.push_loop_scope(self.label.as_ref().map(|label| label.get_name()), is_value_discarded);
 
         let mut result = None;
         let void_type = context.void_type();
         let range_start_vasm_opt = range_start.process(None, context);
         let range_end_vasm_opt = self.range_end.as_ref().and_then(|expr| expr.process(None, context));
+        let (range_start_vasm_opt, iterator_vasm_opt) = match range_start_vasm_opt {
+            Some(vasm) if self.range_end.is_none() && is_iterator_source(&vasm.ty, context) => (None, Some(vasm)),
+            vasm_opt => (vasm_opt, None),
+        };
         let current_function_level = Some(context.get_function_level());
+        let mut assigned_variables = vec![];
+
+        if let Some(body) = &self.body {
+            body.collect_assigned_variables(&mut assigned_variables, context);
+            context.suspend_narrowings(&assigned_variables);
+        }
 
         if let Some(range_end) = &self.range_end {
             if let (Some(range_start_vasm), Some(range_end_vasm)) = (range_start_vasm_opt, range_end_vasm_opt) {
@@ -159,6 +183,75 @@ impl ParsedForBlock {
                                 )
                                 .set_type(context.void_type());
 
+                            result = Some(content);
+                        }
+                    }
+                }
+            }
+        } else if let Some(mut iterator_vasm) = iterator_vasm_opt {
+            if let Some(iterator_type) = get_iter_method_return_type(&iterator_vasm.ty, context) {
+                let iterable_type = iterator_vasm.ty.clone();
+
+                iterator_vasm = context.vasm()
+                    .append(iterator_vasm)
+                    .call_regular_method(&iterable_type, ITER_METHOD_NAME, &[], vec![], context)
+                    .set_type(iterator_type);
+            }
+
+            let required_interface_wrapped = context.get_builtin_interface(BuiltinInterface::Iterator);
+            let iterator_type = iterator_vasm.ty.clone();
+            let item_type = iterator_type.get_associated_type(ITERABLE_ASSOCIATED_TYPE_NAME).unwrap_or(Type::undefined());
+
+            let iterator_var = VariableInfo::tmp("iterator", iterator_type.clone());
+            let index_var = VariableInfo::tmp("index", context.int_type());
+            let item_var = VariableInfo::tmp("item", item_type.clone());
+            let declared_index_var = context.declare_local_variable(index_var_name.as_ref().clone(), context.int_type());
+            let iteration_vasm = context.vasm()
+                .raw(Wat::get_local(&item_var.wasm_name()))
+                .set_type(&item_type);
+
+            if let Some((item_variables, init_vasm)) = item_var_names.process(None, iteration_vasm, Some(range_start), context) {
+                if iterator_type.check_match_interface(&required_interface_wrapped, range_start, context) {
+                    // `none` and `false` are the same `bool` value: the iteration would stop at the first `false` item
+                    if item_type.is_bool() {
+                        context.errors.generic(range_start, format!("cannot iterate over `{}`: its items are `{}`, whose `none` value cannot be told apart from `false`", &iterator_type, &item_type));
+                    }
+
+                    if let Some(body) = unwrap_item(&self.body, range_start, context) {
+                        if let Some(block_vasm) = body.process(Some(&void_type), context) {
+                            if !block_vasm.ty.is_void() {
+                                context.errors.type_mismatch(body, &context.void_type(), &block_vasm.ty);
+                            }
+
+                            let index_var_wasm_name = index_var.get_wasm_name();
+                            let content = context.vasm()
+                                .declare_variable(&declared_index_var)
+                                .declare_variable(&iterator_var)
+                                .declare_variable(&index_var)
+                                .declare_variable(&item_var)
+                                .append(iterator_vasm)
+                                .set_tmp_var(&iterator_var)
+                                .int(-1i32)
+                                .set_tmp_var(&index_var)
+                                .block(context.vasm()
+                                    .loop_(context.vasm()
+                                        .raw(Wat::increment_local_i32(&index_var_wasm_name, 1i32))
+                                        // the iteration stops as soon as `next()` returns `none`
+                                        .call_regular_method(&iterator_type, NEXT_METHOD_NAME, &[], vec![context.vasm().get_tmp_var(&iterator_var)], context)
+                                        .set_tmp_var(&item_var)
+                                        .call_regular_method(&item_type, IS_NONE_METHOD_NAME, &[], vec![context.vasm().get_tmp_var(&item_var)], context)
+                                        .jump_if_from_stack(1)
+                                        .init_var(&declared_index_var)
+                                        .set_var(&declared_index_var, current_function_level,
+                                            context.vasm().raw(Wat::get_local(&index_var_wasm_name))
+                                        )
+                                        .append(init_vasm)
+                                        .append(block_vasm)
+                                        .jump(0)
+                                    )
+                                )
+                                .set_type(context.void_type());
+
                             result = Some(content);
                         }
                     }
@@ -166,8 +259,29 @@ impl ParsedForBlock {
             }
         }
 
+        let result = result.map(|vasm| wrap_loop_result(vasm, context));
+
         context.pop_scope();
 
         result
     }
+}
+
+// Values that are not indexable but have an `iter()` method or are iterators themselves go through the iterator protocol
+fn is_iterator_source(ty: &Type, context: &ProgramContext) -> bool {
+    if ty.get_method(FieldKind::Regular, GET_ITERABLE_PTR_FUNC_NAME, context).is_some() {
+        return false;
+    }
+
+    get_iter_method_return_type(ty, context).is_some() || ty.get_method(FieldKind::Regular, NEXT_METHOD_NAME, context).is_some()
+}
+
+fn get_iter_method_return_type(ty: &Type, context: &ProgramContext) -> Option<Type> {
+    let func_ref = ty.get_method(FieldKind::Regular, ITER_METHOD_NAME, context)?;
+    let function_unwrapped = func_ref.function.borrow();
+
+    match function_unwrapped.signature.argument_types.is_empty() {
+        true => Some(function_unwrapped.signature.return_type.replace_parameters(Some(ty), &[])),
+        false => None,
+    }
 }
\ No newline at end of file
@@ -17,7 +17,8 @@ impl ParsedFunctionArgument {
         Some(ArgumentInfo {
             name: self.name.clone(),
             ty,
-            is_optional: self.default_value.is_some(),
+            has_default_value: self.default_value.is_some(),
+            is_optional: parsed_type.is_option(),
             default_value: context.vasm(),
         })
     }
@@ -1,5 +1,5 @@
 use parsable::{create_token_struct, parsable};
-use crate::{items::ParsedVisibilityToken, program::{ProgramContext, ScopeKind, VariableKind, Visibility, FN_KEYWORD}};
+use crate::{items::ParsedVisibilityToken, program::{ProgramContext, ScopeKind, VariableKind, Visibility, FN_KEYWORD, get_location_string}};
 use super::{ParsedType, ParsedFunctionOrMethodContent, ParsedFunctionSignature, Identifier, ParsedBlockExpression, ParsedVisibility};
 
 create_token_struct!(FnKeyword, FN_KEYWORD);
@@ -13,8 +13,8 @@ pub struct ParsedFunctionDeclaration {
 
 impl ParsedFunctionDeclaration {
     pub fn process_signature(&self, context: &mut ProgramContext) {
-        if context.functions.get_by_identifier(&self.content.name).is_some() {
-            context.errors.generic(&self.content.name, format!("duplicate function declaration `{}`", &self.content.name));
+        if let Some(existing_function) = context.functions.get_conflicting_item(&self.content.name) {
+            context.errors.generic(&self.content.name, format!("duplicate function declaration `{}` (first declared at {})", &self.content.name, get_location_string(&existing_function.borrow().name.location)));
         }
 
         let mut function_wrapped = self.content.process_signature(context);
@@ -2,7 +2,7 @@ use std::{collections::HashSet, rc::Rc};
 use indexmap::{IndexMap, IndexSet};
 use colored::*;
 use parsable::parsable;
-use crate::{items::ParsedTypeQualifier, program::{BuiltinType, FunctionBlueprint, MethodDetails, ProgramContext, ScopeKind, Signature, SELF_VAR_NAME, Type, VariableInfo, VariableKind, Vasm, HAS_TARGET_METHOD_NAME, EVENT_OPTIONS_VAR_NAME, EVENT_VAR_NAME, CompilationError, SignatureContent, MethodMetaQualifier, MethodQualifier, Visibility, FunctionBody, FieldVisibility, FunctionKind}, utils::Link, wat};
+use crate::{items::ParsedTypeQualifier, program::{BuiltinType, FunctionBlueprint, MethodDetails, ProgramContext, ScopeKind, Signature, SELF_VAR_NAME, Type, VariableInfo, VariableKind, Vasm, HAS_TARGET_METHOD_NAME, EVENT_OPTIONS_VAR_NAME, EVENT_VAR_NAME, CompilationError, SignatureContent, MethodMetaQualifier, MethodQualifier, Visibility, FunctionBody, FieldVisibility, FunctionKind}, utils::Link, wat, language_server::SemanticTokenKind};
 use super::{ParsedEventCallbackQualifierKeyword, ParsedFunctionBody, ParsedFunctionSignature, Identifier, ParsedMethodMetaQualifier, ParsedMethodQualifier, ParsedBlockExpression, ParsedTypeParameters, ParsedVisibilityToken, ParsedExpression};
 
 #[parsable]
@@ -48,6 +48,7 @@ impl ParsedFunctionOrMethodContent {
             parameters,
             arguments: vec![],
             signature: Signature::undefined(),
+            is_return_optional: false,
             argument_variables: vec![],
             owner_type: current_type.clone(),
             owner_interface: None,
@@ -59,6 +60,7 @@ impl ParsedFunctionOrMethodContent {
 
         for details in function_blueprint.parameters.values() {
             context.rename_provider.add_occurence(&details.name, &details.name);
+            context.semantic_tokens_provider.add_occurence(&details.name, &details.name, SemanticTokenKind::TypeParameter, &[]);
         }
 
         if let Some(type_wrapped) = &current_type {
@@ -116,8 +118,10 @@ impl ParsedFunctionOrMethodContent {
         function_wrapped.with_mut(|mut function_unwrapped| {
             function_unwrapped.arguments = arguments;
             function_unwrapped.signature = signature;
+            function_unwrapped.is_return_optional = self.signature.is_return_optional();
 
             context.rename_provider.add_occurence(&self.name, &self.name);
+            context.semantic_tokens_provider.add_function_occurence(&self.name, &function_unwrapped);
         });
 
         context.pop_scope();
@@ -148,9 +152,12 @@ impl ParsedFunctionOrMethodContent {
         if let Some(body) = body.process(Some(&return_type), context) {
             function_wrapped.with_mut(|mut function_unwrapped| {
                 if let FunctionBody::Vasm(vasm) = &body {
+                    let location = self.signature.return_type.as_ref().map(|t| t.location.clone()).unwrap_or(self.signature.location.get_end());
+
                     if !vasm.ty.is_assignable_to(&return_type) {
-                        let location = self.signature.return_type.as_ref().map(|t| t.location.clone()).unwrap_or(self.signature.location.get_end());
                         context.errors.type_mismatch(&location, &return_type, &vasm.ty);
+                    } else if !return_type.is_void() {
+                        context.check_optional_assignment(&location, vasm, function_unwrapped.is_return_optional);
                     }
                 }
 
@@ -163,15 +170,19 @@ impl ParsedFunctionOrMethodContent {
 }
 
 pub fn set_function_argument_default_values(function_wrapped: &Link<FunctionBlueprint>, parsed_signature: &ParsedFunctionSignature, context: &mut ProgramContext) {
-    let arg_types : Vec<Type> = function_wrapped.borrow().arguments.iter().map(|arg| arg.ty.clone()).collect();
+    let arg_types : Vec<(Type, bool)> = function_wrapped.borrow().arguments.iter().map(|arg| (arg.ty.clone(), arg.is_optional)).collect();
     let mut arg_default_values = vec![];
 
-    for (parsed_arg, arg_type) in parsed_signature.arguments.iter().zip(arg_types.iter()) {
+    for (parsed_arg, (arg_type, is_arg_optional)) in parsed_signature.arguments.iter().zip(arg_types.iter()) {
         let mut vasm = None;
 
         if let Some(assignment) = &parsed_arg.default_value {
             if let Some(expression) = &assignment.expression {
                 vasm = expression.process(arg_type.to_type_hint(), context);
+
+                if let Some(vasm) = &vasm {
+                    context.check_optional_assignment(expression, vasm, *is_arg_optional || arg_type.is_parameter());
+                }
             }
         }
 
@@ -12,6 +12,13 @@ pub struct ParsedFunctionSignature {
 }
 
 impl ParsedFunctionSignature {
+    pub fn is_return_optional(&self) -> bool {
+        match &self.return_type {
+            Some(ty) => ty.is_option(),
+            None => false,
+        }
+    }
+
     pub fn process(&self, context: &mut ProgramContext) -> (Vec<ArgumentInfo>, Option<Type>) {
         let mut arg_names = HashSet::new();
         let mut arguments = vec![];
@@ -1,5 +1,5 @@
 use parsable::parsable;
-use crate::program::{GlobalVarBlueprint, GlobalVarInstance, ProgramContext, VariableInfo, VariableKind, Visibility};
+use crate::program::{GlobalVarBlueprint, GlobalVarInstance, ProgramContext, VariableInfo, VariableKind, Visibility, get_location_string};
 use super::{ParsedVarDeclaration, ParsedVisibilityToken, ParsedVisibility, ParsedSemicolonToken, unwrap_item};
 
 #[parsable]
@@ -32,8 +32,8 @@ impl ParsedGlobalVarDeclaration {
                 var_info.with_mut(|mut var_content| var_content.wasm_name = var_name.to_string());
             }
 
-            if context.global_vars.get_by_identifier(&var_name).is_some() {
-                context.errors.generic(&var_name, format!("duplicate global variable declaration: `{}`", var_name));
+            if let Some(existing_global_var) = context.global_vars.get_conflicting_item(&var_name) {
+                context.errors.generic(&var_name, format!("duplicate global variable declaration: `{}` (first declared at {})", var_name, get_location_string(&existing_global_var.borrow().name.location)));
             }
 
             context.global_vars.insert(global_var_blueprint, None);
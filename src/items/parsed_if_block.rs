@@ -13,13 +13,39 @@ pub struct ParsedIfBlock {
 }
 
 impl ParsedIfBlock {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.if_branch.collect_assigned_variables(list, context);
+
+        for branch in &self.else_if_branches {
+            branch.collect_assigned_variables(list, context);
+        }
+
+        if let Some(else_branch) = &self.else_branch {
+            else_branch.collect_assigned_variables(list, context);
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let mut result = context.vasm().set_void(context);
         let mut required_branch_type = context.void_type();
         let result_var = VariableInfo::tmp("tmp_result", context.void_type());
+        // variables that cannot be `none` because all previous conditions were falsy
+        let mut falsy_narrowed_variables = vec![];
+        // variables that cannot be `none` after the block, whichever branch has been taken
+        let mut remaining_narrowed_variables = None;
 
         context.push_scope(ScopeKind::Branch);
-        if let (Some(condition_vasm), Some(block_vasm)) = (self.if_branch.process_condition(context), self.if_branch.process_body(type_hint, context)) {
+        let condition_vasm = self.if_branch.process_condition(context);
+        context.narrow_variables(&self.if_branch.get_narrowed_variables(true, context));
+        let block_vasm = self.if_branch.process_body(type_hint, context);
+
+        if !self.if_branch.is_jump() {
+            intersect_narrowed_variables(&mut remaining_narrowed_variables, context.get_current_scope_narrowed_variables());
+        }
+
+        falsy_narrowed_variables.extend(self.if_branch.get_narrowed_variables(false, context));
+
+        if let (Some(condition_vasm), Some(block_vasm)) = (condition_vasm, block_vasm) {
             required_branch_type = block_vasm.ty.clone();
 
             result = result.block(context.vasm()
@@ -34,8 +60,19 @@ impl ParsedIfBlock {
 
         for else_if_branch in &self.else_if_branches {
             context.push_scope(ScopeKind::Branch);
+            context.narrow_variables(&falsy_narrowed_variables);
 
-            if let (Some(condition_vasm), Some(block_vasm)) = (else_if_branch.process_condition(context), else_if_branch.process_body(Some(&required_branch_type), context)) {
+            let condition_vasm = else_if_branch.process_condition(context);
+            context.narrow_variables(&else_if_branch.get_narrowed_variables(true, context));
+            let block_vasm = else_if_branch.process_body(Some(&required_branch_type), context);
+
+            if !else_if_branch.is_jump() {
+                intersect_narrowed_variables(&mut remaining_narrowed_variables, context.get_current_scope_narrowed_variables());
+            }
+
+            falsy_narrowed_variables.extend(else_if_branch.get_narrowed_variables(false, context));
+
+            if let (Some(condition_vasm), Some(block_vasm)) = (condition_vasm, block_vasm) {
                 match block_vasm.ty.get_common_type(&required_branch_type) {
                     Some(ty) => required_branch_type = ty.clone(),
                     None => context.errors.type_mismatch(&else_if_branch, &required_branch_type, &block_vasm.ty).void(),
@@ -52,8 +89,12 @@ impl ParsedIfBlock {
             context.pop_scope();
         }
 
+        let is_else_branch_jump = self.else_branch.as_ref().map(|else_branch| else_branch.is_jump()).unwrap_or(false);
+        let mut else_narrowed_variables = falsy_narrowed_variables.clone();
+
         if let Some(else_branch) = &self.else_branch {
             context.push_scope(ScopeKind::Branch);
+            context.narrow_variables(&falsy_narrowed_variables);
 
             if let Some(block_vasm) = else_branch.process(Some(&required_branch_type), context) {
                 match block_vasm.ty.get_common_type(&required_branch_type) {
@@ -68,6 +109,7 @@ impl ParsedIfBlock {
                 );
             }
 
+            else_narrowed_variables = context.get_current_scope_narrowed_variables();
             context.pop_scope();
         } else if !required_branch_type.is_void() {
             result = result.set_void(context);
@@ -75,7 +117,12 @@ impl ParsedIfBlock {
             // context.errors.generic(self, format!("missing `else` branch (because the `if` branch returns a non-void type)"));
         }
 
+        if !is_else_branch_jump {
+            intersect_narrowed_variables(&mut remaining_narrowed_variables, else_narrowed_variables);
+        }
+
         result_var.set_type(required_branch_type.clone());
+        context.narrow_variables(&remaining_narrowed_variables.unwrap_or_default());
 
         Some(context.vasm()
             .declare_variable(&result_var)
@@ -84,4 +131,11 @@ impl ParsedIfBlock {
             .set_type(required_branch_type)
         )
     }
-}
\ No newline at end of file
+}
+
+fn intersect_narrowed_variables(narrowed_variables: &mut Option<Vec<VariableInfo>>, branch_narrowed_variables: Vec<VariableInfo>) {
+    match narrowed_variables {
+        Some(list) => list.retain(|var_info| branch_narrowed_variables.contains(var_info)),
+        None => *narrowed_variables = Some(branch_narrowed_variables),
+    }
+}
@@ -1,7 +1,7 @@
 use std::{collections::HashSet, rc::Rc};
 use indexmap::IndexMap;
 use parsable::parsable;
-use crate::{program::{AssociatedTypeContent, FieldKind, FuncRef, FunctionBlueprint, InterfaceAssociatedTypeInfo, InterfaceBlueprint, InterfaceList, MethodDetails, ParameterTypeInfo, ProgramContext, ScopeKind, Signature, SELF_TYPE_NAME, SELF_VAR_NAME, Type, VariableInfo, VariableKind, Vasm, SignatureContent, Visibility}, utils::Link};
+use crate::{program::{AssociatedTypeContent, FieldKind, FuncRef, FunctionBlueprint, InterfaceAssociatedTypeInfo, InterfaceBlueprint, InterfaceList, MethodDetails, ParameterTypeInfo, ProgramContext, ScopeKind, Signature, SELF_TYPE_NAME, SELF_VAR_NAME, Type, VariableInfo, VariableKind, Vasm, SignatureContent, Visibility, get_location_string}, utils::Link, language_server::SemanticTokenKind};
 use super::{ParsedEventCallbackQualifierKeyword, Identifier, ParsedInterfaceAssociatedTypeDeclaration, ParsedInterfaceMethodDeclaration, ParsedInterfaceQualifier, ParsedVisibilityToken, ParsedVisibility};
 
 #[parsable]
@@ -44,11 +44,12 @@ impl ParsedInterfaceDeclaration {
             static_methods: IndexMap::new(),
         };
 
-        if context.interfaces.get_by_identifier(&self.name).is_some() {
-            context.errors.generic(self, format!("interface `{}` already exists", &self.name));
+        if let Some(existing_interface) = context.interfaces.get_conflicting_item(&self.name) {
+            context.errors.generic(self, format!("interface `{}` already exists (first declared at {})", &self.name, get_location_string(&existing_interface.borrow().name.location)));
         }
 
         context.rename_provider.add_occurence(&self.name, &self.name);
+        context.semantic_tokens_provider.add_occurence(&self.name, &self.name, SemanticTokenKind::Interface, &[]);
 
         context.interfaces.insert(interface_blueprint, None);
     }
@@ -73,6 +74,7 @@ impl ParsedInterfaceDeclaration {
                 });
 
                 context.rename_provider.add_occurence(&name, &name);
+                context.semantic_tokens_provider.add_occurence(&name, &name, SemanticTokenKind::TypeParameter, &[]);
 
 
                 if associated_types.insert(name.to_string(), item).is_some() {
@@ -116,6 +118,7 @@ impl ParsedInterfaceDeclaration {
                     }
 
                     context.rename_provider.add_occurence(&method.name, &method.name);
+                    context.semantic_tokens_provider.add_function_occurence(&method.name, &function_unwrapped);
                 });
 
                 context.push_scope(ScopeKind::Function(function_wrapped.clone()));
@@ -34,6 +34,7 @@ impl ParsedInterfaceMethodDeclaration {
             parameters: IndexMap::new(),
             arguments,
             signature,
+            is_return_optional: self.signature.is_return_optional(),
             argument_variables: vec![],
             owner_type: None,
             owner_interface: Some(interface.clone()),
@@ -6,8 +6,14 @@ use super::{ParsedType, Identifier, ParsedVarDeclarationNames, unwrap_item};
 pub struct ParsedIsOperation {
     pub keyword: ParsedIsKeyword,
     pub ty: Option<ParsedType>,
+    pub var_name: Option<ParsedIsBinding>
+}
+
+#[parsable]
+pub enum ParsedIsBinding {
     #[parsable(brackets="()")]
-    pub var_name: Option<ParsedVarDeclarationNames>
+    Parenthesized(ParsedVarDeclarationNames),
+    Bare(ParsedVarDeclarationNames)
 }
 
 #[parsable]
@@ -31,7 +37,7 @@ impl ParsedIsOperation {
             },
         };
 
-        let var_name = unwrap_item(&self.var_name, self, context)?;
+        let var_name = unwrap_item(&self.var_name, self, context)?.get_names();
         let is_source_object = source_type.is_object();
         let is_target_object = target_type.is_object();
         let both_object = is_source_object && is_target_object;
@@ -66,4 +72,13 @@ impl ParsedIsOperation {
             None => None,
         }
     }
-}
\ No newline at end of file
+}
+
+impl ParsedIsBinding {
+    pub fn get_names(&self) -> &ParsedVarDeclarationNames {
+        match self {
+            ParsedIsBinding::Parenthesized(names) => names,
+            ParsedIsBinding::Bare(names) => names,
+        }
+    }
+}
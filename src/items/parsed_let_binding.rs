@@ -0,0 +1,47 @@
+use parsable::{create_token_struct, parsable};
+use crate::program::{ProgramContext, VariableInfo, Vasm, IS_METHOD_NAME, LET_KEYWORD};
+use super::{ParsedExpression, ParsedEqualToken, ParsedVarDeclarationNames, convert_to_bool};
+
+create_token_struct!(LetKeyword, LET_KEYWORD);
+
+#[parsable]
+pub struct ParsedLetBinding {
+    pub let_keyword: LetKeyword,
+    pub var_names: ParsedVarDeclarationNames,
+    pub equal: ParsedEqualToken,
+}
+
+impl ParsedLetBinding {
+    // The condition is true if the value is not `none` (or, for a cast to a class, if the value is an instance of this class),
+    // and the variables are bound to the value in the current scope
+    pub fn process(&self, value: &ParsedExpression, context: &mut ProgramContext) -> Option<Vasm> {
+        let value_vasm = value.process(None, context)?;
+
+        if value_vasm.ty.is_void() {
+            context.errors.unexpected_void_expression(value);
+            return None;
+        }
+
+        let value_type = value_vasm.ty.clone();
+        let tmp_var_info = VariableInfo::tmp("let_tmp", value_type.clone());
+        let condition_vasm = match value.is_cast() && value_type.is_object() {
+            true => context.vasm()
+                .call_static_method(&value_type, IS_METHOD_NAME, &[], vec![context.vasm().get_tmp_var(&tmp_var_info)], context)
+                .set_type(context.bool_type()),
+            false => convert_to_bool(value, context.vasm().get_tmp_var(&tmp_var_info).set_type(&value_type), context)?,
+        };
+        let assigned_vasm = context.vasm()
+            .get_tmp_var(&tmp_var_info)
+            .set_type(&value_type);
+        let (_, init_vasm) = self.var_names.process(None, assigned_vasm, None, context)?;
+
+        Some(context.vasm()
+            .declare_variable(&tmp_var_info)
+            .append(value_vasm)
+            .set_tmp_var(&tmp_var_info)
+            .append(init_vasm)
+            .append(condition_vasm)
+            .set_type(context.bool_type())
+        )
+    }
+}
@@ -18,6 +18,8 @@ pub struct ParsedLoadDirective {
 
 const DEFAULT_INT_VALUE : i32 = 0;
 const DEFAULT_FLOAT_VALUE : f32 = 0.;
+const DEFAULT_LONG_VALUE : i64 = 0;
+const DEFAULT_DOUBLE_VALUE : f64 = 0.;
 
 impl ParsedLoadDirective {
     pub fn process(&self, context: &mut ProgramContext) -> Option<Vasm> {
@@ -89,6 +91,22 @@ impl ParsedLoadDirective {
                                     false => None,
                                 },
                             }
+                        } else if field.ty.is_long() {
+                            match i64::from_str_radix(string, 10) {
+                                Ok(value) => Some(context.vasm().long(value)),
+                                Err(_) => match string.is_empty() {
+                                    true => Some(context.vasm().long(DEFAULT_LONG_VALUE)),
+                                    false => None,
+                                },
+                            }
+                        } else if field.ty.is_double() {
+                            match string.parse::<f64>() {
+                                Ok(value) => Some(context.vasm().double(value)),
+                                Err(_) => match string.is_empty() {
+                                    true => Some(context.vasm().double(DEFAULT_DOUBLE_VALUE)),
+                                    false => None,
+                                },
+                            }
                         } else if field.ty.is_bool() {
                             let s = string.to_ascii_lowercase();
 
@@ -116,7 +134,7 @@ impl ParsedLoadDirective {
                 if let Some(vasm) = init_vasm {
                     object_creation_vasm = object_creation_vasm
                         .get_tmp_var(&object_var)
-                        .set_field(&field.ty, field.offset, None, vasm);
+                        .set_field(&object_type, field.name.as_str(), &field.ty, None, vasm);
                 } else if let Some(string) = init_values.get(field.name.as_str()) {
                     context.errors.generic(self, format!("cannot convert \"{}\" to `{}`", string, &field.ty));
                 }
@@ -0,0 +1,48 @@
+use colored::Colorize;
+use parsable::parsable;
+use crate::program::{ProgramContext, Vasm};
+
+#[parsable(name="label")]
+pub struct ParsedLoopLabel {
+    #[parsable(regex = r"'[a-zA-Z_][a-zA-Z0-9_]*", not_followed_by = "'")] // to avoid the confusion with char literals
+    pub token: String,
+}
+
+impl ParsedLoopLabel {
+    pub fn get_name(&self) -> &str {
+        &self.token[1..]
+    }
+
+    pub fn process(&self, context: &mut ProgramContext) -> Option<u32> {
+        let available_labels = context.get_loop_labels();
+
+        context.completion_provider.add_label_completion(self, available_labels);
+
+        match context.get_loop_scope_depth(Some(self.get_name())) {
+            Some(depth) => Some(depth),
+            None => {
+                context.errors.generic(self, format!("undefined label `{}`", self.token.as_str().bold()));
+                None
+            },
+        }
+    }
+}
+
+// A loop exited by `break <value>` evaluates to that value, or to `none` if it ends normally
+pub fn wrap_loop_result(loop_vasm: Vasm, context: &mut ProgramContext) -> Vasm {
+    match context.get_current_scope_break_variable() {
+        Some(break_variable) => {
+            let result_type = break_variable.ty().clone();
+
+            context.vasm()
+                .declare_variable(&break_variable)
+                .none(&result_type, context)
+                .set_tmp_var(&break_variable)
+                .append(loop_vasm)
+                .get_tmp_var(&break_variable)
+                .set_type(result_type)
+                .set_optional(true)
+        },
+        None => loop_vasm,
+    }
+}
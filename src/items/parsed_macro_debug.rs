@@ -21,7 +21,7 @@ impl ParsedMacroDebug {
             ParsedMacroDebugToken::DebugType => match &self.expression {
                 Some(expression) => match expression.process(None, context) {
                     Some(vasm) => {
-                        println!("{}", vasm.ty.to_string());
+                        context.debug_output.push(vasm.ty.to_string());
                     },
                     None => {},
                 },
@@ -1,5 +1,5 @@
 use parsable::{ItemLocation, parsable};
-use crate::program::{ProgramContext, Vasm, MacroContext, BuiltinType, SELF_VAR_NAME};
+use crate::{program::{ProgramContext, Vasm, MacroContext, BuiltinType, SELF_VAR_NAME}, language_server::SemanticTokenKind};
 use super::{make_string_value_from_literal_unchecked, Identifier};
 
 #[parsable]
@@ -15,6 +15,7 @@ pub enum MacroExpressionToken {
     TypeName = "TYPE_NAME",
     TypeShortName = "TYPE_SHORT_NAME",
     TypeFullName = "TYPE_FULL_NAME",
+    ObjectSize = "OBJECT_SIZE",
     FieldCount = "FIELD_COUNT",
     FieldName = "FIELD_NAME",
     FieldDefaultExpression = "FIELD_DEFAULT_EXPRESSION",
@@ -27,6 +28,8 @@ pub enum MacroExpressionToken {
 
 impl ParsedMacroExpression {
     pub fn process(&self, context: &mut ProgramContext) -> Option<Vasm> {
+        context.semantic_tokens_provider.add_token(&self.location, SemanticTokenKind::Macro, &[]);
+
         let mut m = MacroContext::new(self, context);
 
         match &self.token {
@@ -54,6 +57,11 @@ impl ParsedMacroExpression {
                     .type_name(&type_unwrapped.self_type, true)
                     .set_type(context.get_builtin_type(BuiltinType::String, vec![]))
             }, context),
+            MacroExpressionToken::ObjectSize => m.access_current_type(|type_unwrapped, context| {
+                context.vasm()
+                    .object_size(&type_unwrapped.self_type)
+                    .set_type(context.int_type())
+            }, context),
             MacroExpressionToken::FieldCount => m.access_current_type(|type_unwrapped, context| {
                 context.vasm()
                     .int(type_unwrapped.fields.len())
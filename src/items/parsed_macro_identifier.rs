@@ -1,5 +1,5 @@
 use parsable::{ItemLocation, parsable};
-use crate::program::{ProgramContext, Vasm, MacroContext, BuiltinType, MainType};
+use crate::{program::{ProgramContext, Vasm, MacroContext, BuiltinType, MainType}, language_server::SemanticTokenKind};
 use super::{make_string_value_from_literal_unchecked, Identifier};
 
 #[parsable]
@@ -19,6 +19,8 @@ pub enum MacroIdentifierToken {
 
 impl ParsedMacroIdentifier {
     pub fn process(&self, context: &mut ProgramContext) -> Option<Identifier> {
+        context.semantic_tokens_provider.add_token(&self.location, SemanticTokenKind::Macro, &[]);
+
         let mut m = MacroContext::new(self, context);
 
         match &self.token {
@@ -1,5 +1,5 @@
 use parsable::{ItemLocation, parsable};
-use crate::program::{ProgramContext, Vasm, MacroContext, BuiltinType, Type, MainType};
+use crate::{program::{ProgramContext, Vasm, MacroContext, BuiltinType, Type, MainType}, language_server::SemanticTokenKind};
 use super::{make_string_value_from_literal_unchecked, Identifier};
 
 #[parsable]
@@ -20,6 +20,8 @@ pub enum MacroTypeToken {
 
 impl ParsedMacroType {
     pub fn process(&self, context: &mut ProgramContext) -> Option<Type> {
+        context.semantic_tokens_provider.add_token(&self.location, SemanticTokenKind::Macro, &[]);
+
         let mut m = MacroContext::new(self, context);
 
         match &self.token {
@@ -1,13 +1,14 @@
-use std::iter::FromIterator;
+use std::{collections::HashSet, iter::FromIterator};
 
 use colored::Colorize;
 use enum_iterator::all;
 use indexmap::IndexSet;
 use parsable::{create_token_struct, parsable};
-use crate::{program::{BuiltinInterface, INT_NONE_VALUE, IS_METHOD_NAME, IS_NONE_METHOD_NAME, NONE_LITERAL, NONE_METHOD_NAME, ProgramContext, ScopeKind, Type, TypeCategory, VariableInfo, VariableKind, Vasm, TypeContent, MATCH_KEYWORD}, wat};
-use super::{ParsedExpression, Identifier, ParsedType, ParsedTypeQualifier, ParsedDoubleColonToken, ParsedOpeningRoundBracket, ParsedClosingRoundBracket, ParsedArrowToken, ParsedNoneLiteral, ParsedNumberLiteral, ParsedStringLiteral, ParsedCharLiteral, ParsedMatchBranchItem, ParsedMatchBranchBody, ParsedVarDeclarationNames, ParsedOpeningCurlyBracket, ParsedClosingCurlyBracket, ParsedBooleanLiteralToken, unwrap_item};
+use crate::{program::{BuiltinInterface, INT_NONE_VALUE, IS_METHOD_NAME, IS_NONE_METHOD_NAME, NONE_LITERAL, NONE_METHOD_NAME, ProgramContext, ScopeKind, Type, TypeCategory, VariableInfo, VariableKind, Vasm, TypeContent, TypeBlueprint, Visibility, LintKind, MATCH_KEYWORD, IF_KEYWORD}, utils::Link, wat};
+use super::{convert_to_bool, MatchItemCoverage, ParsedExpression, Identifier, ParsedType, ParsedTypeQualifier, ParsedDoubleColonToken, ParsedOpeningRoundBracket, ParsedClosingRoundBracket, ParsedArrowToken, ParsedNoneLiteral, ParsedNumberLiteral, ParsedStringLiteral, ParsedCharLiteral, ParsedMatchBranchItem, ParsedMatchBranchBody, ParsedVarDeclarationNames, ParsedOpeningCurlyBracket, ParsedClosingCurlyBracket, ParsedBooleanLiteralToken, unwrap_item};
 
 create_token_struct!(MatchKeyword, MATCH_KEYWORD);
+create_token_struct!(MatchGuardKeyword, IF_KEYWORD);
 
 #[parsable]
 pub struct ParsedMatchBlock {
@@ -32,13 +33,47 @@ pub struct ParsedMatchBranchList {
 
 #[parsable]
 pub struct ParsedMatchBranch {
-    pub item: ParsedMatchBranchItem,
+    #[parsable(separator="|", min=1)]
+    pub items: Vec<ParsedMatchBranchItem>,
     #[parsable(brackets="()")]
     pub variable: Option<ParsedVarDeclarationNames>,
+    pub guard: Option<ParsedMatchBranchGuard>,
     pub body: Option<ParsedMatchBranchBody>,
 }
 
+#[parsable]
+pub struct ParsedMatchBranchGuard {
+    pub if_keyword: MatchGuardKeyword,
+    #[parsable(declare_marker="no-anonymous-function")]
+    pub condition: Option<Box<ParsedExpression>>,
+}
+
+impl ParsedMatchBranchGuard {
+    pub fn process(&self, context: &mut ProgramContext) -> Option<Vasm> {
+        let condition = unwrap_item(&self.condition, &self.if_keyword, context)?;
+        let condition_vasm = condition.process(None, context)?;
+
+        convert_to_bool(condition, condition_vasm, context)
+    }
+}
+
 impl ParsedMatchBlock {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        if let Some(expression) = &self.expression {
+            expression.collect_assigned_variables(list, context);
+        }
+
+        for branch in self.body.iter().flat_map(|body| &body.branches.list) {
+            if let Some(condition) = branch.guard.as_ref().and_then(|guard| guard.condition.as_ref()) {
+                condition.collect_assigned_variables(list, context);
+            }
+
+            if let Some(expression) = branch.body.as_ref().and_then(|body| body.expression.as_ref()) {
+                expression.collect_assigned_variables(list, context);
+            }
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let expression = unwrap_item(&self.expression, &self.match_keyword, context)?;
         let matched_vasm = expression.process(None, context)?;
@@ -71,21 +106,75 @@ impl ParsedMatchBlock {
         context.add_match_item_completion_area(&first_half_location, &matched_type);
         context.add_match_item_completion_area(&second_half_location, &matched_type);
 
+        let mut coverage = MatchCoverage::new(&matched_type);
+        let mut has_invalid_branch = false;
+
         for branch in &body.branches.list {
             let mut branch_vasm = context.vasm();
+            let mut item_types = vec![];
+            let mut condition_vasm = context.vasm();
+
+            for (i, item) in branch.items.iter().enumerate() {
+                if branch.items.len() > 1 && item.has_bindings(context) {
+                    context.errors.generic(item, "cannot bind variables in an or-pattern".to_string());
+                }
+
+                if let Some((item_type, item_vasm)) = item.process(tested_vasm.clone(), context) {
+                    condition_vasm = match i {
+                        0 => item_vasm,
+                        _ => condition_vasm.append(item_vasm).raw(wat!["i32.or"]).set_type(context.bool_type()),
+                    };
+                    item_types.push(item_type);
+                }
+
+                if !item.is_enum_variant() {
+                    context.add_match_item_completion_area(item, &matched_type);
+                }
+            }
+
+            if item_types.len() == branch.items.len() {
+                let item_coverages : Vec<MatchItemCoverage> = branch.items.iter().zip(&item_types)
+                    .map(|(item, item_type)| item.get_coverage(item_type, context))
+                    .collect();
+
+                if item_coverages.iter().all(|item_coverage| coverage.is_covered(item_coverage)) {
+                    context.lint(branch, LintKind::UnreachableMatchArm, "");
+                }
+
+                if branch.guard.is_none() {
+                    for item_coverage in item_coverages {
+                        coverage.add(item_coverage);
+                    }
+                }
+
+                let item_type = item_types.iter().skip(1)
+                    .try_fold(item_types[0].clone(), |common_type, item_type| common_type.get_common_type(item_type))
+                    .unwrap_or_else(|| matched_type.clone());
 
-            if let Some((item_type, item_vasm)) = branch.item.process(tested_vasm.clone(), context) {
                 context.push_scope(ScopeKind::Branch);
 
                 branch_vasm = branch_vasm
-                    .jump_if(0, item_vasm.eqz());
+                    .jump_if(0, condition_vasm.eqz());
+
+                if let [item] = branch.items.as_slice() {
+                    if let Some(bindings_vasm) = item.process_bindings(&item_type, tested_vasm.clone().set_type(&item_type), context) {
+                        branch_vasm = branch_vasm.append(bindings_vasm);
+                    }
+                }
 
                 if let Some(variable) = &branch.variable {
-                    if let Some((_, var_init_vasm)) = variable.process(None, tested_vasm.clone().set_type(item_type), None, context) {
+                    if let Some((_, var_init_vasm)) = variable.process(None, tested_vasm.clone().set_type(&item_type), None, context) {
                         branch_vasm = branch_vasm.append(var_init_vasm);
                     }
                 }
 
+                if let Some(guard) = &branch.guard {
+                    if let Some(guard_vasm) = guard.process(context) {
+                        branch_vasm = branch_vasm
+                            .jump_if(0, guard_vasm.eqz());
+                    }
+                }
+
                 if let Some(body) = &branch.body {
                     let hint = type_hint.or(returned_type.as_ref());
 
@@ -111,15 +200,27 @@ impl ParsedMatchBlock {
                 }
 
                 context.pop_scope();
-            }
-
-            if !branch.item.is_enum_variant() {
-                context.add_match_item_completion_area(&branch.item, &matched_type);
+            } else {
+                has_invalid_branch = true;
             }
 
             vasm = vasm.block(branch_vasm);
         }
 
+        if !has_invalid_branch {
+            let missing_cases = coverage.get_missing_cases();
+
+            if !missing_cases.is_empty() {
+                let mut case_list : Vec<String> = missing_cases.iter().take(MAX_DISPLAYED_MISSING_CASES).map(|case| format!("`{}`", case)).collect();
+
+                if missing_cases.len() > MAX_DISPLAYED_MISSING_CASES {
+                    case_list.push("...".to_string());
+                }
+
+                context.errors.generic(&self.match_keyword, format!("non-exhaustive match, missing {}", case_list.join(", ")));
+            }
+        }
+
         let generate_fill_match_arms = || {
             let mut result = None;
             let all_variants = if matched_type.is_enum() {
@@ -137,8 +238,8 @@ impl ParsedMatchBlock {
             };
             let mut variant_set : IndexSet<String> = IndexSet::from_iter(all_variants);
 
-            for branch in &body.branches.list {
-                if let Some(variant) = branch.item.get_variant_name() {
+            for item in body.branches.list.iter().flat_map(|branch| branch.items.iter()) {
+                if let Some(variant) = item.get_variant_name() {
                     variant_set.shift_remove(&variant);
                 }
             }
@@ -173,4 +274,82 @@ impl ParsedMatchBlock {
             .set_type(&final_type)
         )
     }
-}
\ No newline at end of file
+}
+
+const MAX_DISPLAYED_MISSING_CASES : usize = 5;
+
+struct MatchCoverage {
+    matched_type: Type,
+    all: bool,
+    values: HashSet<String>,
+    types: Vec<Link<TypeBlueprint>>,
+}
+
+impl MatchCoverage {
+    fn new(matched_type: &Type) -> Self {
+        Self {
+            matched_type: matched_type.clone(),
+            all: false,
+            values: HashSet::new(),
+            types: vec![],
+        }
+    }
+
+    fn is_covered(&self, item_coverage: &MatchItemCoverage) -> bool {
+        self.all || match item_coverage {
+            MatchItemCoverage::All => false,
+            MatchItemCoverage::Value(value) => self.values.contains(value),
+            MatchItemCoverage::Type(type_wrapped) => self.types.iter().any(|covered_type| covered_type.borrow().descendants.contains(type_wrapped)),
+            MatchItemCoverage::Partial => false,
+        }
+    }
+
+    fn add(&mut self, item_coverage: MatchItemCoverage) {
+        match item_coverage {
+            MatchItemCoverage::All => self.all = true,
+            MatchItemCoverage::Value(value) => {
+                self.values.insert(value);
+            },
+            MatchItemCoverage::Type(type_wrapped) => {
+                // a type that is an ancestor of the matched type matches all the values
+                if let TypeContent::Actual(info) = self.matched_type.content() {
+                    if type_wrapped.borrow().descendants.contains(&info.type_blueprint) {
+                        self.all = true;
+                    }
+                }
+
+                self.types.push(type_wrapped);
+            },
+            MatchItemCoverage::Partial => {},
+        }
+    }
+
+    fn get_missing_cases(&self) -> Vec<String> {
+        if self.all {
+            return vec![];
+        }
+
+        if self.matched_type.is_enum() {
+            self.matched_type.get_all_variants().iter()
+                .filter(|variant_info| !self.values.contains(variant_info.name.as_str()))
+                .map(|variant_info| format!("{}::{}", &self.matched_type, variant_info.name.as_str()))
+                .collect()
+        } else if self.matched_type.is_bool() {
+            all::<ParsedBooleanLiteralToken>()
+                .map(|value| value.as_str().to_string())
+                .filter(|value| !self.values.contains(value))
+                .collect()
+        } else if self.matched_type.is_object() {
+            match self.matched_type.content() {
+                TypeContent::Actual(info) => info.type_blueprint.borrow().descendants.iter()
+                    .filter(|type_wrapped| type_wrapped.borrow().visibility != Visibility::None)
+                    .filter(|type_wrapped| !self.is_covered(&MatchItemCoverage::Type((*type_wrapped).clone())))
+                    .map(|type_wrapped| type_wrapped.borrow().name.to_string())
+                    .collect(),
+                _ => vec![],
+            }
+        } else {
+            vec![]
+        }
+    }
+}
@@ -1,18 +1,28 @@
 use parsable::{parsable, ItemLocation};
-use crate::program::{Type, ProgramContext, Vasm};
-use super::{ParsedNoneLiteral, ParsedNumberLiteral, ParsedType, ParsedDoubleColonToken, Identifier, ParsedMatchBranchTypeItem, ParsedMatchBranchLiteralItem, ParsedWildcardToken};
+use crate::{program::{Type, ProgramContext, TypeBlueprint, TypeContent, Vasm}, utils::Link};
+use super::{ParsedNoneLiteral, ParsedNumberLiteral, ParsedType, ParsedDoubleColonToken, Identifier, ParsedMatchBranchTypeItem, ParsedMatchBranchLiteralItem, ParsedMatchBranchRangeItem, ParsedWildcardToken};
 
 #[parsable]
 pub enum ParsedMatchBranchItem {
     Wildcard(ParsedWildcardToken),
+    Range(ParsedMatchBranchRangeItem),
     Literal(ParsedMatchBranchLiteralItem),
     TypeOrEnumVariant(ParsedMatchBranchTypeItem),
 }
 
+// Values of the tested type that are matched by an item, used to check that a match is exhaustive
+pub enum MatchItemCoverage {
+    All,
+    Value(String),
+    Type(Link<TypeBlueprint>),
+    Partial,
+}
+
 impl ParsedMatchBranchItem {
     pub fn is_enum_variant(&self) -> bool {
         match self {
             ParsedMatchBranchItem::Wildcard(_) => false,
+            ParsedMatchBranchItem::Range(_) => false,
             ParsedMatchBranchItem::Literal(_) => false,
             ParsedMatchBranchItem::TypeOrEnumVariant(item) => item.variant.is_some(),
         }
@@ -21,6 +31,7 @@ impl ParsedMatchBranchItem {
     pub fn get_variant_name(&self) -> Option<String> {
         match self {
             ParsedMatchBranchItem::Wildcard(_) => None,
+            ParsedMatchBranchItem::Range(_) => None,
             ParsedMatchBranchItem::Literal(literal) => match literal {
                 ParsedMatchBranchLiteralItem::None(_) => None,
                 ParsedMatchBranchLiteralItem::Boolean(bool_literal) => Some(bool_literal.token.as_str().to_string()),
@@ -40,9 +51,48 @@ impl ParsedMatchBranchItem {
         }
     }
 
+    pub fn get_coverage(&self, item_type: &Type, context: &ProgramContext) -> MatchItemCoverage {
+        match self {
+            ParsedMatchBranchItem::Wildcard(_) => MatchItemCoverage::All,
+            ParsedMatchBranchItem::Range(_) => MatchItemCoverage::Partial,
+            ParsedMatchBranchItem::Literal(literal) => match literal {
+                ParsedMatchBranchLiteralItem::Boolean(bool_literal) => MatchItemCoverage::Value(bool_literal.token.as_str().to_string()),
+                _ => MatchItemCoverage::Partial,
+            },
+            ParsedMatchBranchItem::TypeOrEnumVariant(item) => match &item.variant {
+                Some(variant) => match &variant.name {
+                    Some(name) => MatchItemCoverage::Value(name.to_string()),
+                    None => MatchItemCoverage::Partial,
+                },
+                None => match item.has_value_binding(context) {
+                    true => MatchItemCoverage::All,
+                    false => match item_type.content() {
+                        TypeContent::Actual(info) => MatchItemCoverage::Type(info.type_blueprint.clone()),
+                        _ => MatchItemCoverage::Partial,
+                    },
+                },
+            },
+        }
+    }
+
+    pub fn has_bindings(&self, context: &ProgramContext) -> bool {
+        match self {
+            ParsedMatchBranchItem::TypeOrEnumVariant(type_or_enum_variant) => type_or_enum_variant.has_bindings(context),
+            _ => false,
+        }
+    }
+
+    pub fn process_bindings(&self, item_type: &Type, tested_value: Vasm, context: &mut ProgramContext) -> Option<Vasm> {
+        match self {
+            ParsedMatchBranchItem::TypeOrEnumVariant(type_or_enum_variant) => Some(type_or_enum_variant.process_bindings(item_type, tested_value, context)),
+            _ => None,
+        }
+    }
+
     pub fn process(&self, tested_value: Vasm, context: &mut ProgramContext) -> Option<(Type, Vasm)> {
         match self {
             ParsedMatchBranchItem::Wildcard(_) => Some((tested_value.ty.clone(), context.vasm().int(1i32).set_type(context.bool_type()))),
+            ParsedMatchBranchItem::Range(range) => range.process(tested_value, context),
             ParsedMatchBranchItem::Literal(literal) => literal.process(tested_value, context),
             ParsedMatchBranchItem::TypeOrEnumVariant(type_or_enum_variant) => type_or_enum_variant.process(tested_value, context),
         }
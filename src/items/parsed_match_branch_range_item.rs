@@ -0,0 +1,43 @@
+use parsable::parsable;
+use crate::{program::{Vasm, ProgramContext, GE_METHOD_NAME, LE_METHOD_NAME, LT_METHOD_NAME, Type}, wat};
+use super::{ParsedNumberLiteral, ParsedDoubleDotToken, ParsedEqualToken};
+
+#[parsable]
+pub struct ParsedMatchBranchRangeItem {
+    pub start: ParsedNumberLiteral,
+    pub double_dot: ParsedDoubleDotToken,
+    pub inclusive: Option<ParsedEqualToken>,
+    pub end: ParsedNumberLiteral,
+}
+
+impl ParsedMatchBranchRangeItem {
+    pub fn process(&self, tested_value: Vasm, context: &mut ProgramContext) -> Option<(Type, Vasm)> {
+        let type_hint = Some(&tested_value.ty);
+        let start_vasm = self.start.process(type_hint, context)?;
+        let end_vasm = self.end.process(type_hint, context)?;
+
+        for (bound, bound_vasm) in [(&self.start, &start_vasm), (&self.end, &end_vasm)] {
+            if !bound_vasm.ty.is_assignable_to(&tested_value.ty) {
+                context.errors.type_mismatch(bound, &tested_value.ty, &bound_vasm.ty);
+                return None;
+            }
+        }
+
+        let item_type = start_vasm.ty.clone();
+        let end_method_name = match &self.inclusive {
+            Some(_) => LE_METHOD_NAME,
+            None => LT_METHOD_NAME,
+        };
+
+        Some((
+            item_type.clone(),
+            context.vasm()
+                .append(tested_value.clone())
+                .call_regular_method(&item_type, GE_METHOD_NAME, &[], vec![start_vasm], context)
+                .append(tested_value)
+                .call_regular_method(&item_type, end_method_name, &[], vec![end_vasm], context)
+                .raw(wat!["i32.and"])
+                .set_type(context.bool_type())
+        ))
+    }
+}
@@ -1,11 +1,14 @@
 use parsable::parsable;
-use crate::program::{ProgramContext, Vasm, IS_METHOD_NAME, EQ_METHOD_NAME, Type};
-use super::{ParsedType, Identifier, ParsedDoubleColonToken};
+use colored::Colorize;
+use crate::{program::{ProgramContext, Vasm, IS_METHOD_NAME, EQ_METHOD_NAME, SELF_TYPE_NAME, Type}, language_server::SemanticTokenKind};
+use super::{ParsedType, Identifier, ParsedDoubleColonToken, ParsedColonToken};
 
-#[parsable]
+#[parsable(declare_marker = "no-qualified-type")]
 pub struct ParsedMatchBranchTypeItem {
     pub ty: ParsedType,
     pub variant: Option<ParsedEnumVariantName>,
+    pub fields: Option<ParsedMatchBranchFieldPatternList>,
+    pub binding: Option<Identifier>,
 }
 
 #[parsable]
@@ -14,8 +17,61 @@ pub struct ParsedEnumVariantName {
     pub name: Option<Identifier>
 }
 
+#[parsable]
+pub struct ParsedMatchBranchFieldPatternList {
+    #[parsable(brackets="{}", separator=",")]
+    pub list: Vec<ParsedMatchBranchFieldPattern>
+}
+
+#[parsable]
+pub struct ParsedMatchBranchFieldPattern {
+    pub name: Identifier,
+    pub binding: Option<ParsedMatchBranchFieldBinding>,
+}
+
+#[parsable]
+pub struct ParsedMatchBranchFieldBinding {
+    pub colon: ParsedColonToken,
+    pub name: Option<Identifier>,
+}
+
 impl ParsedMatchBranchTypeItem {
+    // A single name that does not refer to a type binds the whole tested value (e.g `x if x > 3 => ...`)
+    fn get_value_binding(&self, context: &ProgramContext) -> Option<&Identifier> {
+        if self.variant.is_some() || self.fields.is_some() || self.binding.is_some() {
+            return None;
+        }
+
+        let name = self.ty.as_single_identifier()?;
+        let is_type_name = name.as_str() == SELF_TYPE_NAME
+            || context.types.get_by_identifier(name).is_some()
+            || context.typedefs.get_by_identifier(name).is_some()
+            || context.get_type_parameter(name.as_str()).is_some();
+
+        match is_type_name {
+            true => None,
+            false => Some(name),
+        }
+    }
+
+    pub fn has_value_binding(&self, context: &ProgramContext) -> bool {
+        self.get_value_binding(context).is_some()
+    }
+
+    pub fn has_bindings(&self, context: &ProgramContext) -> bool {
+        self.fields.is_some() || self.binding.is_some() || self.get_value_binding(context).is_some()
+    }
+
     pub fn process(&self, tested_value: Vasm, context: &mut ProgramContext) -> Option<(Type, Vasm)> {
+        if self.get_value_binding(context).is_some() {
+            return Some((
+                tested_value.ty.clone(),
+                context.vasm()
+                    .int(1i32)
+                    .set_type(context.bool_type())
+            ));
+        }
+
         let ty = self.ty.process(true, None, context)?;
 
         if tested_value.ty.is_object() {
@@ -52,6 +108,16 @@ impl ParsedMatchBranchTypeItem {
                         match ty.get_variant(name.as_str()) {
                             Some(variant_info) => {
                                 context.rename_provider.add_occurence(name, &variant_info.name);
+                                context.semantic_tokens_provider.add_occurence(name, &variant_info.name, SemanticTokenKind::EnumMember, &[]);
+
+                                if let Some(variant_type) = ty.get_variant_type(&variant_info) {
+                                    return Some((
+                                        variant_type.clone(),
+                                        context.vasm()
+                                            .call_static_method(&variant_type, IS_METHOD_NAME, &[], vec![tested_value], context)
+                                            .set_type(context.bool_type())
+                                    ));
+                                }
 
                                 Some((
                                     ty.clone(),
@@ -85,4 +151,65 @@ impl ParsedMatchBranchTypeItem {
             },
         }
     }
-}
\ No newline at end of file
+
+    // Binds the name (e.g `Magician m`) and the fields (e.g `Event::Hit { damage }`) of the pattern to local variables
+    pub fn process_bindings(&self, item_type: &Type, tested_value: Vasm, context: &mut ProgramContext) -> Vasm {
+        let mut result = context.vasm();
+        let current_function_level = Some(context.get_function_level());
+
+        if let Some(name) = self.binding.as_ref().or(self.get_value_binding(context)) {
+            let var_info = context.declare_local_variable(name.clone(), item_type.clone());
+
+            context.hover_provider.set_type(name, item_type);
+
+            result = result
+                .declare_variable(&var_info)
+                .init_var(&var_info)
+                .set_var(&var_info, current_function_level, tested_value.clone());
+        }
+
+        let fields = match &self.fields {
+            Some(fields) => fields,
+            None => return result.set_type(context.void_type()),
+        };
+
+        for pattern in &fields.list {
+            let field_info = match item_type.get_field(pattern.name.as_str()) {
+                Some(field_info) => field_info,
+                None => {
+                    context.errors.generic(&pattern.name, format!("type `{}` has no field `{}`", item_type, pattern.name.as_str().bold()));
+                    continue;
+                },
+            };
+
+            context.rename_provider.add_occurence(&pattern.name, &field_info.name);
+            context.semantic_tokens_provider.add_occurence(&pattern.name, &field_info.name, SemanticTokenKind::Property, &[]);
+            context.definition_provider.set_definition(&pattern.name, &field_info.name);
+
+            let var_name = match &pattern.binding {
+                Some(binding) => match &binding.name {
+                    Some(name) => name,
+                    None => {
+                        context.errors.expected_identifier(&binding.colon);
+                        continue;
+                    },
+                },
+                None => &pattern.name,
+            };
+            let field_type = field_info.ty.replace_parameters(Some(item_type), &[]);
+            let var_info = context.declare_local_variable(var_name.clone(), field_type.clone());
+
+            context.hover_provider.set_type(var_name, &field_type);
+
+            result = result
+                .declare_variable(&var_info)
+                .init_var(&var_info)
+                .set_var(&var_info, current_function_level, context.vasm()
+                    .append(tested_value.clone())
+                    .get_field(item_type, field_info.name.as_str(), &field_type, None)
+                );
+        }
+
+        result.set_type(context.void_type())
+    }
+}
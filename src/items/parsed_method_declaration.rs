@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use parsable::parsable;
 use colored::*;
-use crate::{items::ParsedVisibilityToken, program::{FuncRef, FunctionBlueprint, ProgramContext, ScopeKind, SELF_VAR_NAME, VariableKind, display_join, hashmap_get_or_insert_with, insert_in_vec_hashmap, Visibility, TO_STRING_METHOD_NAME}, utils::Link};
+use crate::{items::ParsedVisibilityToken, program::{FuncRef, FunctionBlueprint, ProgramContext, ScopeKind, SELF_VAR_NAME, VariableKind, display_join, hashmap_get_or_insert_with, insert_in_vec_hashmap, Visibility, TO_STRING_METHOD_NAME, EQ_METHOD_NAME, NE_METHOD_NAME}, utils::Link};
 use super::{ParsedEventCallbackQualifierKeyword, ParsedFunctionOrMethodContent, ParsedFunctionDeclaration, ParsedFunctionSignature, Identifier, ParsedBlockExpression, ParsedTypeDeclaration, ParsedTypeQualifier, ParsedVarPath};
 
 #[parsable]
@@ -15,6 +15,11 @@ impl ParsedMethodDeclaration {
     }
 
     pub fn process_signature(&self, context: &mut ProgramContext) {
+        // `!=` is always compiled as the negation of `__eq`
+        if self.content.name.as_str() == NE_METHOD_NAME {
+            context.errors.generic(&self.content.name, format!("method `{}` is never called: `!=` is the negation of `{}`", NE_METHOD_NAME.bold(), EQ_METHOD_NAME.bold()));
+        }
+
         let function_wrapped = self.content.process_signature(context);
         let mut type_wrapped = context.get_current_type().unwrap();
 
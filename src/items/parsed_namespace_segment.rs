@@ -0,0 +1,9 @@
+use parsable::parsable;
+use super::{Identifier, ParsedDoubleColonToken};
+
+#[parsable]
+pub struct ParsedNamespaceSegment {
+    pub name: Identifier,
+    #[parsable(followed_by = "[a-zA-Z_]")]
+    pub double_colon: ParsedDoubleColonToken,
+}
\ No newline at end of file
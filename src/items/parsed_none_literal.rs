@@ -17,6 +17,7 @@ impl ParsedNoneLiteral {
                 result = Some(context.vasm()
                     .call_static_method(ty, NONE_METHOD_NAME, &[], vec![], context)
                     .set_type(ty)
+                    .set_optional(true)
                 );
             },
             None => {
@@ -24,11 +24,17 @@ impl ParsedNumberLiteral {
             false => {
                 let (number, suffix) = split_number_suffix(s);
                 let mut prefer_float = false;
+                let mut prefer_long = false;
+                let mut prefer_double = false;
                 let mut prefer_display_size = false;
 
                 if let Some(ty) = type_hint {
                     if ty.is_builtin_type(BuiltinType::Float) {
                         prefer_float = true;
+                    } else if ty.is_builtin_type(BuiltinType::Long) {
+                        prefer_long = true;
+                    } else if ty.is_builtin_type(BuiltinType::Double) {
+                        prefer_double = true;
                     } else if ty.is_builtin_type(BuiltinType::DisplaySize) {
                         prefer_display_size = true;
                     }
@@ -37,6 +43,10 @@ impl ParsedNumberLiteral {
                 if suffix.is_empty() {
                     if prefer_float {
                         Number::Float(number.parse().unwrap())
+                    } else if prefer_double || (prefer_long && number.contains(".")) {
+                        Number::Double(number.parse().unwrap())
+                    } else if prefer_long {
+                        parse_long(number, self, context)
                     } else if prefer_display_size {
                         Number::VirtualSize(number.parse().unwrap())
                     } else if number.contains(".") {
@@ -51,6 +61,16 @@ impl ParsedNumberLiteral {
                             },
                         }
                     }
+                } else if suffix == "l" {
+                    match number.contains(".") {
+                        true => {
+                            context.errors.generic(self, format!("invalid long literal"));
+                            return None;
+                        },
+                        false => parse_long(number, self, context),
+                    }
+                } else if suffix == "d" {
+                    Number::Double(number.parse().unwrap())
                 } else {
                     let value : f32 = number.parse().unwrap();
 
@@ -77,6 +97,8 @@ impl ParsedNumberLiteral {
         let vasm = match value {
             Number::Int(value) => context.vasm().int(value).set_type(context.int_type()),
             Number::Float(value) => context.vasm().float(value).set_type(context.float_type()),
+            Number::Long(value) => context.vasm().long(value).set_type(context.long_type()),
+            Number::Double(value) => context.vasm().double(value).set_type(context.double_type()),
             Number::RealSize(value) => create_display_size(REAL_SIZE_VARIANT_VALUE, value, context),
             Number::VirtualSize(value) => create_display_size(VIRTUAL_SIZE_VARIANT_VALUE, value, context),
             Number::ScaledFromContainerWidthSize(value) => create_display_size(SCALED_FROM_WIDTH_SIZE_VARIANT_VALUE, value, context),
@@ -99,6 +121,8 @@ const SCALED_FROM_MIN_SIZE_VARIANT_VALUE : i32 = 4;
 enum Number {
     Int(i32),
     Float(f32),
+    Long(i64),
+    Double(f64),
     RealSize(f32),
     VirtualSize(f32),
     ScaledFromContainerWidthSize(f32),
@@ -111,6 +135,8 @@ impl Number {
         match self {
             Number::Int(value) => Number::Int(value.neg()),
             Number::Float(value) => Number::Float(value.neg()),
+            Number::Long(value) => Number::Long(value.neg()),
+            Number::Double(value) => Number::Double(value.neg()),
             Number::RealSize(value) => Number::RealSize(value.neg()),
             Number::VirtualSize(value) => Number::VirtualSize(value.neg()),
             Number::ScaledFromContainerWidthSize(value) => Number::ScaledFromContainerWidthSize(value.neg()),
@@ -127,6 +153,17 @@ fn maybe_neg<T : Neg<Output = T>>(value: T, should_neg: bool) -> T {
     }
 }
 
+fn parse_long(number: &str, literal: &ParsedNumberLiteral, context: &mut ProgramContext) -> Number {
+    match i64::from_str_radix(number, 10) {
+        Ok(n) => Number::Long(n),
+        Err(_) => {
+            context.errors.generic(literal, format!("number too big"));
+
+            Number::Long(0)
+        },
+    }
+}
+
 fn split_number_suffix(s: &str) -> (&str, &str) {
     for (i, b) in s.as_bytes().iter().enumerate() {
         let c = *b as char;
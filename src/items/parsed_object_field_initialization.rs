@@ -1,6 +1,6 @@
 use colored::Colorize;
 use parsable::parsable;
-use crate::{items::ObjectInitResult, program::{CompilationError, ProgramContext, Type, Vasm}};
+use crate::{items::ObjectInitResult, program::{CompilationError, ProgramContext, Type, Vasm}, language_server::SemanticTokenKind};
 use super::{ParsedExpression, Identifier, ParsedColonToken, ParsedCommaToken, unwrap_item};
 
 #[parsable]
@@ -23,8 +23,10 @@ impl ParsedObjectFieldInitialization {
         let result = match object_type.get_field(self.name.as_str()) {
             Some(field_info) => {
                 let field_type = field_info.ty.replace_parameters(Some(&object_type), &[]);
+                let is_field_optional = field_info.is_optional || field_info.ty.is_parameter();
 
                 context.rename_provider.add_occurence(&self.name, &field_info.name);
+                context.semantic_tokens_provider.add_occurence(&self.name, &field_info.name, SemanticTokenKind::Property, &[]);
                 context.definition_provider.set_definition(&self.name, &field_info.name);
                 context.hover_provider.set_type(&self.name, &field_type);
 
@@ -35,6 +37,7 @@ impl ParsedObjectFieldInitialization {
                                 match expr.process(Some(&field_type), context) {
                                     Some(vasm) => match vasm.ty.is_assignable_to(&field_type) {
                                         true => {
+                                            context.check_optional_assignment(expr, &vasm, is_field_optional);
                                             Some(vasm)
                                         },
                                         false => {
@@ -54,10 +57,14 @@ impl ParsedObjectFieldInitialization {
                     None => match context.access_var(&self.name) {
                         Some(var_info) => match var_info.ty().is_assignable_to(&field_type) {
                             true => {
-                                Some(context.vasm()
+                                let vasm = context.vasm()
                                     .get_var(&var_info, Some(context.get_function_level()))
                                     .set_type(field_type)
-                                )
+                                    .set_optional(var_info.is_optional() && !context.is_variable_narrowed(&var_info));
+
+                                context.check_optional_assignment(&self.name, &vasm, is_field_optional);
+
+                                Some(vasm)
                             },
                             false => {
                                 context.errors.type_mismatch(&self.name, &field_type, &var_info.ty());
@@ -2,50 +2,108 @@ use std::{collections::{HashMap, HashSet}, rc::Rc, iter::FromIterator};
 use colored::Colorize;
 use indexmap::{IndexMap, IndexSet};
 use parsable::{parsable, ItemLocation};
-use crate::{items::ParsedTypeQualifier, program::{OBJECT_CREATE_METHOD_NAME, ProgramContext, Type, VariableInfo, VariableKind, Vasm, TypeContent, NONE_METHOD_NAME}};
-use super::{ParsedExpression, Identifier, ParsedObjectInitializationItem, ParsedType, ParsedOpeningCurlyBracket, ParsedClosingCurlyBracket};
+use crate::{items::ParsedTypeQualifier, program::{OBJECT_CREATE_METHOD_NAME, ProgramContext, Type, VariableInfo, VariableKind, Vasm, TypeContent, NONE_METHOD_NAME}, language_server::SemanticTokenKind};
+use super::{ParsedExpression, Identifier, ParsedObjectInitializationItem, ParsedType, ParsedOpeningCurlyBracket, ParsedClosingCurlyBracket, ParsedEnumVariantName, process_qualified_type_name};
 
-#[parsable]
+#[parsable(declare_marker = "no-qualified-type")]
 pub struct ParsedObjectLiteral {
     pub object_type: ParsedType,
+    pub variant: Option<ParsedEnumVariantName>,
     pub opening_bracket: ParsedOpeningCurlyBracket,
     pub body: ParsedObjectLiteralInitializationBody,
     pub closing_bracket: ParsedClosingCurlyBracket,
 }
 
-#[parsable]
+#[parsable(unset_marker = "no-qualified-type")]
 pub struct ParsedObjectLiteralInitializationBody {
     pub items: Vec<ParsedObjectInitializationItem>,
 }
 
 impl ParsedObjectLiteral {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        for item in &self.body.items {
+            let expression = match item {
+                ParsedObjectInitializationItem::FieldInitialization(field_initialization) => field_initialization.value.as_ref().and_then(|value| value.expression.as_ref()),
+                ParsedObjectInitializationItem::SpreadOperator(spread_operator) => spread_operator.expression.as_ref(),
+            };
+
+            if let Some(expression) = expression {
+                expression.collect_assigned_variables(list, context);
+            }
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         self.object_type.collect_instancied_type_names(list, context);
     }
 
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
-        let mut result = None;
+        let qualified_name = self.variant.as_ref().and_then(|variant| variant.name.as_ref());
 
-        if let Some(object_type) = self.object_type.process(true, type_hint, context) {
-            let first_half_location = self.opening_bracket.location.until(&self.body);
-            let second_half_location = self.body.location.until(&self.closing_bracket);
+        if let (Some(namespace), Some(name)) = (self.object_type.as_namespace(context), qualified_name) {
+            let object_type = process_qualified_type_name(namespace, name, type_hint, context)?;
 
-            let fill_required_fields = || fill_class_fields(&object_type, &self.body.items, true);
-            let fill_all_fields = || fill_class_fields(&object_type, &self.body.items, false);
+            return Some(self.process_object_type(&object_type, &object_type, name, context));
+        }
 
-            for location in &[first_half_location, second_half_location] {
-                context.completion_provider.add_field_completion(&location, &object_type, None, None);
-                context.code_actions_provider.add_replace_action(&location, "Fill required fields", None, fill_required_fields);
-                context.code_actions_provider.add_replace_action(&location, "Fill all fields", None, fill_all_fields);
-            }
+        let parsed_type = self.object_type.process(true, type_hint, context)?;
+        let (object_type, location) = match &self.variant {
+            Some(variant) => (process_variant_type(&parsed_type, variant, context)?, &variant.location),
+            None => (parsed_type.clone(), &self.object_type.location),
+        };
 
-            result = instanciate_object(&self.object_type, &self.body.items, type_hint, context);
+        Some(self.process_object_type(&parsed_type, &object_type, location, context))
+    }
+
+    fn process_object_type(&self, parsed_type: &Type, object_type: &Type, location: &ItemLocation, context: &mut ProgramContext) -> Vasm {
+        let first_half_location = self.opening_bracket.location.until(&self.body);
+        let second_half_location = self.body.location.until(&self.closing_bracket);
+
+        let fill_required_fields = || fill_class_fields(object_type, &self.body.items, true);
+        let fill_all_fields = || fill_class_fields(object_type, &self.body.items, false);
+
+        for location in &[first_half_location, second_half_location] {
+            context.completion_provider.add_field_completion(&location, object_type, None, None);
+            context.code_actions_provider.add_replace_action(&location, "Fill required fields", None, fill_required_fields);
+            context.code_actions_provider.add_replace_action(&location, "Fill all fields", None, fill_all_fields);
         }
 
-        result
+        instanciate_type(object_type, location, &self.body.items, context)
+            .set_type(parsed_type)
     }
 }
 
+fn process_variant_type(enum_type: &Type, variant: &ParsedEnumVariantName, context: &mut ProgramContext) -> Option<Type> {
+    let variant_name = match &variant.name {
+        Some(name) => name,
+        None => {
+            context.errors.expected_identifier(&variant.double_colon);
+            return None;
+        },
+    };
+
+    let variant_info = match enum_type.get_variant(variant_name.as_str()) {
+        Some(variant_info) => variant_info,
+        None => {
+            context.errors.generic(variant_name, format!("type `{}` has no enum variant `{}`", enum_type, variant_name.as_str().bold()));
+            return None;
+        },
+    };
+
+    context.rename_provider.add_occurence(variant_name, &variant_info.name);
+    context.semantic_tokens_provider.add_occurence(variant_name, &variant_info.name, SemanticTokenKind::EnumMember, &[]);
+    context.definition_provider.set_definition(variant_name, &variant_info.name);
+    context.hover_provider.set_type(variant_name, enum_type);
+
+    let variant_type = enum_type.get_variant_type(&variant_info);
+
+    if variant_type.is_none() {
+        context.errors.generic(variant_name, format!("enum variant `{}` does not carry fields", variant_name.as_str().bold()));
+    }
+
+    variant_type
+}
+
 fn fill_class_fields(ty: &Type, initialization_items: &[ParsedObjectInitializationItem], exclude_non_required: bool) -> Option<String> {
     let mut fields = ty.get_all_fields();
     let mut field_names : IndexSet<&str> = match exclude_non_required {
@@ -74,8 +132,12 @@ fn fill_class_fields(ty: &Type, initialization_items: &[ParsedObjectInitializati
 pub fn instanciate_object(parsed_object_type: &ParsedType, initialization_items: &[ParsedObjectInitializationItem], type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
     let object_type = parsed_object_type.process(true, type_hint, context)?;
 
+    Some(instanciate_type(&object_type, &parsed_object_type.location, initialization_items, context))
+}
+
+pub fn instanciate_type(object_type: &Type, location: &ItemLocation, initialization_items: &[ParsedObjectInitializationItem], context: &mut ProgramContext) -> Vasm {
     let mut result = context.vasm()
-        .set_type(&object_type);
+        .set_type(object_type);
 
     if let TypeContent::Actual(info) = object_type.content() {
         let object_var = VariableInfo::tmp("object", object_type.clone());
@@ -86,12 +148,12 @@ pub fn instanciate_object(parsed_object_type: &ParsedType, initialization_items:
 
             result = result
                 .declare_variable(&object_var)
-                .call_static_method(&object_type, OBJECT_CREATE_METHOD_NAME, &[], vec![], context)
+                .call_static_method(object_type, OBJECT_CREATE_METHOD_NAME, &[], vec![], context)
                 .set_tmp_var(&object_var);
 
             for (i, item) in initialization_items.iter().enumerate() {
                 let is_last = i == initialization_items.len() - 1;
-                let init_info = item.process(&object_type, is_last, context);
+                let init_info = item.process(object_type, is_last, context);
 
                 for (field_name, vasm) in init_info.fields {
                     fields_init.insert(field_name, vasm);
@@ -104,7 +166,7 @@ pub fn instanciate_object(parsed_object_type: &ParsedType, initialization_items:
 
             for (i, field_info) in type_unwrapped.fields.values().enumerate() {
                 let field_name = field_info.name.as_str();
-                let field_type = field_info.ty.replace_parameters(Some(&object_type), &[]);
+                let field_type = field_info.ty.replace_parameters(Some(object_type), &[]);
                 let specified_value = fields_init.remove(field_name);
                 let is_value_specified = specified_value.is_some();
                 let init_vasm = match specified_value {
@@ -113,23 +175,23 @@ pub fn instanciate_object(parsed_object_type: &ParsedType, initialization_items:
                 };
 
                 if field_info.is_required && !is_value_specified {
-                    context.errors.generic(parsed_object_type, format!("missing field `{}`", field_name));
+                    context.errors.generic(location, format!("missing field `{}`", field_name));
                 }
 
                 result = result
                     .get_tmp_var(&object_var)
-                    .set_field(&field_type, field_info.offset, None, init_vasm);
+                    .set_field(object_type, field_name, &field_type, None, init_vasm);
             }
 
             result = result
                 .get_tmp_var(&object_var)
-                .set_type(&object_type);
+                .set_type(object_type);
         } else {
-            context.errors.generic(parsed_object_type, format!("type `{}` is not a class", &object_type));
+            context.errors.generic(location, format!("type `{}` is not a class", object_type));
         }
     } else {
-        context.errors.generic(parsed_object_type, format!("cannot instanciate type `{}` this way", &object_type));
+        context.errors.generic(location, format!("cannot instanciate type `{}` this way", object_type));
     }
 
-    Some(result)
+    result
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 use parsable::{ItemLocation, parsable};
-use crate::program::{AccessType, ProgramContext, Type, Vasm};
+use crate::program::{AccessType, ProgramContext, Type, VariableInfo, Vasm};
 use super::{ParsedAction, ParsedArrayLiteral, ParsedOperandBody, ParsedBooleanLiteral, ParsedExpression, ParsedForBlock, Identifier, ParsedIfBlock, ParsedIterAncestorsBlock, ParsedIterFieldsBlock, ParsedIterVariantsBlock, ParsedObjectLiteral, ParsedType, ParsedStringLiteral, ParsedUnaryOperation, ParsedVarDeclaration, ParsedVarPath, ParsedWhileBlock};
 
 #[parsable]
@@ -9,6 +9,13 @@ pub enum ParsedOperand {
 }
 
 impl ParsedOperand {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        match self {
+            ParsedOperand::UnaryOperation(unary_operation) => unary_operation.collect_assigned_variables(list, context),
+            ParsedOperand::OperandBody(operand_body) => operand_body.collect_assigned_variables(list, context),
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         match self {
             ParsedOperand::UnaryOperation(unary_operation) => unary_operation.collect_instancied_type_names(list, context),
@@ -16,6 +23,13 @@ impl ParsedOperand {
         }
     }
 
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        match self {
+            ParsedOperand::UnaryOperation(unary_operation) => unary_operation.collect_narrowed_variables(is_truthy, list, context),
+            ParsedOperand::OperandBody(operand_body) => operand_body.collect_narrowed_variables(is_truthy, list, context),
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         match self {
             ParsedOperand::UnaryOperation(unary_operation) => unary_operation.process(type_hint, context),
@@ -1,6 +1,6 @@
 use std::{collections::HashMap, rc::Rc};
 use parsable::parsable;
-use crate::{items::{ParsedAssignmentOperatorToken, ParsedBinaryOperatorToken, ParsedBinaryOperator}, program::{AccessType, CompilationError, ProgramContext, Type, Vasm}, wat};
+use crate::{items::{ParsedAssignmentOperatorToken, ParsedBinaryOperatorToken, ParsedBinaryOperator}, program::{AccessType, CompilationError, ProgramContext, Type, VariableInfo, Vasm}, wat};
 use super::{ParsedAssignmentOperator, ParsedExpression, Identifier, ParsedVarPath, ParsedOperandSuffix, unwrap_item};
 
 #[parsable]
@@ -10,6 +10,20 @@ pub struct ParsedOperandBody {
 }
 
 impl ParsedOperandBody {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.lvalue.collect_assigned_variables(list, context);
+
+        if let Some(ParsedOperandSuffix::Assignment(assignment)) = &self.suffix {
+            if let Some(var_info) = self.lvalue.get_var_info(context) {
+                list.push(var_info);
+            }
+
+            if let Some(expression) = &assignment.expression {
+                expression.collect_assigned_variables(list, context);
+            }
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         match &self.suffix {
             Some(_) => {},
@@ -17,6 +31,14 @@ impl ParsedOperandBody {
         }
     }
 
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        match &self.suffix {
+            None => self.lvalue.collect_narrowed_variables(is_truthy, list, context),
+            Some(ParsedOperandSuffix::IsOperation(_)) if is_truthy => self.lvalue.collect_narrowed_variables(true, list, context),
+            _ => {}
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let mut result = None;
 
@@ -27,6 +49,17 @@ impl ParsedOperandBody {
             if let Some(mut left_vasm) = self.lvalue.process(None, AccessType::Set(&equal_token), context) {
                 if let Some(right_vasm) = rvalue.process(Some(&left_vasm.ty), context) {
                     if right_vasm.ty.is_assignable_to(&left_vasm.ty) {
+                        if let ParsedAssignmentOperatorToken::Equal = &equal_token.token {
+                            context.check_optional_assignment(rvalue, &right_vasm, left_vasm.is_optional);
+
+                            if let Some(var_info) = self.lvalue.get_var_info(context) {
+                                match right_vasm.is_optional {
+                                    true => context.forget_narrowing(&var_info),
+                                    false => context.narrow_variables(&[var_info]),
+                                }
+                            }
+                        }
+
                         let assigned_vasm = match &equal_token.token {
                             ParsedAssignmentOperatorToken::Equal => right_vasm,
                             _ => {
@@ -59,6 +92,7 @@ impl ParsedOperandBody {
 
                         left_vasm.replace_placeholder(&equal_token, &Rc::new(assigned_vasm));
                         left_vasm.ty = context.void_type();
+                        left_vasm.is_optional = false;
                         
                         result = Some(left_vasm);
                     } else {
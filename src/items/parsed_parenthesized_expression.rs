@@ -1,5 +1,5 @@
 use parsable::parsable;
-use crate::{program::{BuiltinType, NEW_METHOD_NAME, ProgramContext, Type, Vasm}};
+use crate::{program::{BuiltinType, NEW_METHOD_NAME, ProgramContext, Type, VariableInfo, Vasm}};
 use super::{ParsedExpression, Identifier};
 
 #[parsable]
@@ -9,12 +9,24 @@ pub struct ParsedParenthesizedExpression {
 }
 
 impl ParsedParenthesizedExpression {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        for expression in &self.expr_list {
+            expression.collect_assigned_variables(list, context);
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         for expr in &self.expr_list {
             expr.collect_instancied_type_names(list, context);
         }
     }
 
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        if let [expr] = self.expr_list.as_slice() {
+            expr.collect_narrowed_variables(is_truthy, list, context);
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         match self.expr_list.len() {
             0 => {
@@ -1,5 +1,5 @@
 use parsable::{ItemLocation, parsable};
-use crate::{program::{ProgramContext, Vasm, FieldKind, Type, AccessType}, language_server::FieldCompletionOptions};
+use crate::{program::{ProgramContext, Vasm, FieldKind, Type, AccessType}, language_server::{FieldCompletionOptions, SemanticTokenKind, SemanticTokenModifier}};
 use super::{ParsedVarPrefix, Identifier, ParsedArgumentList, process_method_call, process_field_access, unwrap_item};
 
 #[parsable]
@@ -28,6 +28,12 @@ impl ParsedPrefixedVarRef {
         }));
 
         let name = unwrap_item(&self.name, &self.prefix, context)?;
+        let token_kind = match &self.arguments {
+            Some(_) => SemanticTokenKind::Function,
+            None => SemanticTokenKind::Property,
+        };
+
+        context.semantic_tokens_provider.add_token(&ItemLocation { start: self.prefix.location.start, ..name.location.clone() }, token_kind, &[SemanticTokenModifier::DefaultLibrary]);
         
         context.completion_provider.add_field_completion(name, &vasm.ty, type_hint, Some(&FieldCompletionOptions {
             show_methods: true,
@@ -0,0 +1,43 @@
+use parsable::parsable;
+use crate::program::{ProgramContext, Type};
+use super::{Identifier, ParsedNamespaceSegment, ParsedValueType};
+
+#[parsable]
+pub struct ParsedQualifiedType {
+    #[parsable(min = 1)]
+    pub namespace: Vec<ParsedNamespaceSegment>,
+    pub value: ParsedValueType,
+}
+
+impl ParsedQualifiedType {
+    pub fn collect_instancied_type_names(&self, list: &mut Vec<String>) {
+        self.value.collect_instancied_type_names(list);
+    }
+
+    pub fn process(&self, check_interfaces: bool, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Type> {
+        let namespace : Vec<&Identifier> = self.namespace.iter().map(|segment| &segment.name).collect();
+
+        process_qualified_type(&namespace, &self.value, check_interfaces, type_hint, context)
+    }
+}
+
+pub fn process_qualified_type(namespace: &[&Identifier], value_type: &ParsedValueType, check_interfaces: bool, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Type> {
+    context.add_qualified_name_completion_areas(namespace, &value_type.name);
+
+    match context.get_qualified_import(namespace, &value_type.name) {
+        Some(import) => value_type.process_in_namespace(Some(&import), check_interfaces, type_hint, context),
+        None => {
+            context.errors.generic(namespace[0], format!("undefined namespace `{}`", namespace[0]));
+            None
+        },
+    }
+}
+
+pub fn process_qualified_type_name(namespace: &Identifier, name: &Identifier, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Type> {
+    let mut value_type = ParsedValueType::default();
+
+    value_type.name = name.clone();
+    value_type.location = name.location.clone();
+
+    process_qualified_type(&[namespace], &value_type, true, type_hint, context)
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use parsable::{create_token_struct, parsable};
-use crate::program::{ProgramContext, Type, Vasm, SELF_VAR_NAME};
+use crate::{program::{ProgramContext, Type, Vasm, SELF_VAR_NAME}, language_server::SemanticTokenKind};
 use super::{ParsedDotToken, Identifier, ParsedEqualToken, ParsedExpression, unwrap_item, ParsedCommaToken, ParsedSemicolonToken};
 
 create_token_struct!(SelfKeyword, SELF_VAR_NAME);
@@ -28,6 +28,7 @@ impl ParsedSuperFieldDefaultValue {
         let field_type = match self_type.get_field(name.as_str()) {
             Some(field_info) => {
                 context.rename_provider.add_occurence(name, &field_info.name);
+                context.semantic_tokens_provider.add_occurence(name, &field_info.name, SemanticTokenKind::Property, &[]);
                 field_info.ty.clone()
             },
             None => {
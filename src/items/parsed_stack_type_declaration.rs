@@ -14,6 +14,8 @@ pub struct ParsedStackTypeDeclaration {
 pub enum ParsedStackType {
     I32 = "i32",
     F32 = "f32",
+    I64 = "i64",
+    F64 = "f64",
     Void = "void"
 }
 
@@ -25,6 +27,8 @@ impl ParsedStackTypeDeclaration {
         match keyword {
             ParsedStackType::I32 => Some(WasmStackType::I32),
             ParsedStackType::F32 => Some(WasmStackType::F32),
+            ParsedStackType::I64 => Some(WasmStackType::I64),
+            ParsedStackType::F64 => Some(WasmStackType::F64),
             ParsedStackType::Void => Some(WasmStackType::Void),
         }
     }
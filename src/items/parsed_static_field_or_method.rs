@@ -1,17 +1,23 @@
 use parsable::parsable;
 use crate::{program::{AccessType, FieldKind, ProgramContext, Type, Vasm}, language_server::FieldCompletionOptions};
-use super::{ParsedArgumentList, Identifier, ParsedType, process_method_call, process_field_access, ParsedDoubleColonToken};
+use super::{ParsedArgumentList, Identifier, ParsedType, process_method_call, process_field_access, process_qualified_item, ParsedDoubleColonToken};
 
-#[parsable]
+#[parsable(declare_marker = "no-qualified-type")]
 pub struct ParsedStaticFieldOrMethod {
     pub ty: ParsedType,
+    #[parsable(not_followed_by = r"[a-zA-Z_]\w*\s*::")] // `dir::Type::method()` is parsed as a qualified var ref
     pub double_colon: ParsedDoubleColonToken,
     pub name: Option<Identifier>,
+    #[parsable(unset_marker = "no-qualified-type")]
     pub arguments: Option<ParsedArgumentList>
 }
 
 impl ParsedStaticFieldOrMethod {
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
+        if let (Some(namespace), Some(name)) = (self.ty.as_namespace(context), &self.name) {
+            return process_qualified_item(&[namespace], name, self.arguments.as_ref(), type_hint, AccessType::Get, context);
+        }
+
         match self.ty.process(true, type_hint, context) {
             Some(ty) => {
                 context.completion_provider.add_static_field_completion(&self.double_colon, &ty, type_hint, Some(&FieldCompletionOptions {
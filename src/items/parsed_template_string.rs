@@ -1,5 +1,5 @@
 use parsable::parsable;
-use crate::{program::{BuiltinType, FunctionCall, NamedFunctionCallDetails, ProgramContext, Vasm}};
+use crate::{program::{BuiltinType, FunctionCall, NamedFunctionCallDetails, ProgramContext, VariableInfo, Vasm}};
 use super::{ParsedTemplateStringFragment, make_string_value_from_literal_unchecked};
 
 #[parsable]
@@ -9,6 +9,14 @@ pub struct ParsedTemplateString {
 }
 
 impl ParsedTemplateString {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        for fragment in &self.fragments {
+            if let ParsedTemplateStringFragment::Expression(expression_fragment) = fragment {
+                expression_fragment.expression.collect_assigned_variables(list, context);
+            }
+        }
+    }
+
     pub fn process(&self, context: &mut ProgramContext) -> Option<Vasm> {
         match self.fragments.len() {
             0 => Some(make_string_value_from_literal_unchecked("", context)),
@@ -1,5 +1,5 @@
 use parsable::parsable;
-use super::{ParsedFunctionDeclaration, ParsedGlobalVarDeclaration, ParsedInterfaceDeclaration, ParsedTypeDeclaration, ParsedTypedefDeclaration, ParsedVarDeclaration, ParsedTopLevelBlockInProgress, ParsedMainTypeDeclaration, ParsedRootTagDeclaration};
+use super::{ParsedFunctionDeclaration, ParsedGlobalVarDeclaration, ParsedInterfaceDeclaration, ParsedTypeDeclaration, ParsedTypedefDeclaration, ParsedVarDeclaration, ParsedTopLevelBlockInProgress, ParsedMainTypeDeclaration, ParsedRootTagDeclaration, ParsedUseDeclaration};
 
 #[parsable]
 pub enum ParsedTopLevelBlock {
@@ -10,5 +10,6 @@ pub enum ParsedTopLevelBlock {
     TypeDeclaration(ParsedTypeDeclaration),
     FunctionDeclaration(ParsedFunctionDeclaration),
     GlobalDeclaration(ParsedGlobalVarDeclaration),
+    UseDeclaration(ParsedUseDeclaration),
     InProgress(ParsedTopLevelBlockInProgress)
 }
\ No newline at end of file
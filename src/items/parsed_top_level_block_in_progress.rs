@@ -1,5 +1,5 @@
 use parsable::parsable;
-use crate::program::{ProgramContext, VISIBILITY_KEYWORDS, VAR_DECLARATION_KEYWORDS, TYPE_DECLARATION_KEYWORDS, FUNCTION_DECLARATION_KEYWORDS};
+use crate::program::{ProgramContext, VISIBILITY_KEYWORDS, VAR_DECLARATION_KEYWORDS, TYPE_DECLARATION_KEYWORDS, FUNCTION_DECLARATION_KEYWORDS, USE_KEYWORD};
 use super::{Identifier, Word};
 
 thread_local! {
@@ -15,6 +15,7 @@ thread_local! {
         keywords.extend_from_slice(VAR_DECLARATION_KEYWORDS);
         keywords.extend_from_slice(FUNCTION_DECLARATION_KEYWORDS);
         keywords.extend_from_slice(VISIBILITY_KEYWORDS);
+        keywords.push(USE_KEYWORD);
         keywords
     };
 }
@@ -1,5 +1,5 @@
 use parsable::parsable;
-use crate::program::{BuiltinType, ProgramContext, Type};
+use crate::program::{BuiltinType, ProgramContext, SELF_TYPE_NAME, Type};
 use super::{Identifier, ParsedTypeSingle, ParsedTypeWithoutSuffix, ParsedTypeSuffixToken, ParsedTypeSuffix};
 
 #[parsable(name = "type")]
@@ -17,6 +17,20 @@ impl ParsedType {
         }
     }
 
+    // Where qualified types are not allowed, `dir::Type` is parsed as a type followed by a name
+    pub fn as_namespace(&self, context: &ProgramContext) -> Option<&Identifier> {
+        let name = self.as_single_identifier()?;
+        let is_type_name = name.as_str() == SELF_TYPE_NAME
+            || context.types.find_by_identifier(name, false).is_some()
+            || context.typedefs.find_by_identifier(name, false).is_some()
+            || context.get_type_parameter(name.as_str()).is_some();
+
+        match !is_type_name && context.is_namespace(name) {
+            true => Some(name),
+            false => None,
+        }
+    }
+
     pub fn is_option(&self) -> bool {
         match self.suffix.last() {
             Some(s) => s.token == ParsedTypeSuffixToken::Option,
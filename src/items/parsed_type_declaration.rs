@@ -2,8 +2,8 @@ use std::{collections::{HashMap, HashSet}, fmt::format, hash::Hash, rc::Rc, slic
 use colored::Colorize;
 use indexmap::{IndexMap, IndexSet};
 use parsable::{ItemLocation, parsable};
-use crate::{program::{ActualTypeContent, AssociatedTypeInfo, DEFAULT_METHOD_NAME, BuiltinType, DESERIALIZE_DYN_METHOD_NAME, DynamicMethodInfo, ENUM_TYPE_NAME, EVENT_CALLBACKS_GLOBAL_NAME, EnumVariantInfo, FieldInfo, FuncRef, FunctionBlueprint, FunctionCall, NONE_METHOD_NAME, NamedFunctionCallDetails, OBJECT_HEADER_SIZE, OBJECT_TYPE_NAME, ParentInfo, ProgramContext, ScopeKind, Signature, SELF_TYPE_NAME, Type, TypeBlueprint, TypeCategory, WasmStackType, hashmap_get_or_insert_with, MainType, TypeContent, Visibility, FunctionBody, SELF_VAR_NAME, FieldVisibility, ANY_TYPE_NAME, ArgumentInfo, FunctionKind}, utils::Link};
-use super::{ParsedAssociatedTypeDeclaration, ParsedEventCallbackQualifierKeyword, ParsedFieldDeclaration, ParsedType, Identifier, ParsedMethodDeclaration, ParsedTypeParameters, ParsedTypeQualifier, ParsedVisibilityToken, ParsedVisibility, ParsedEventCallbackDeclaration, ParsedSuperFieldDefaultValue, ParsedTypeExtend, ParsedStackTypeDeclaration};
+use crate::{program::{EQ_METHOD_NAME, HASH_METHOD_NAME, ActualTypeContent, AssociatedTypeInfo, DEFAULT_METHOD_NAME, BuiltinType, DESERIALIZE_DYN_METHOD_NAME, DynamicMethodInfo, ENUM_TYPE_NAME, EVENT_CALLBACKS_GLOBAL_NAME, EnumVariantInfo, FieldInfo, FuncRef, FunctionBlueprint, FunctionCall, NONE_METHOD_NAME, NamedFunctionCallDetails, OBJECT_TYPE_NAME, ParentInfo, ProgramContext, ScopeKind, Signature, SELF_TYPE_NAME, Type, TypeBlueprint, TypeCategory, WasmStackType, hashmap_get_or_insert_with, MainType, TypeContent, Visibility, FunctionBody, SELF_VAR_NAME, FieldVisibility, ANY_TYPE_NAME, ArgumentInfo, FunctionKind, get_location_string}, utils::Link, language_server::SemanticTokenKind};
+use super::{ParsedAssociatedTypeDeclaration, ParsedEventCallbackQualifierKeyword, ParsedFieldDeclaration, ParsedType, Identifier, ParsedMethodDeclaration, ParsedTypeParameters, ParsedTypeQualifier, ParsedVisibilityToken, ParsedVisibility, ParsedEventCallbackDeclaration, ParsedSuperFieldDefaultValue, ParsedTypeExtend, ParsedStackTypeDeclaration, ParsedEnumVariantDeclaration};
 
 #[parsable]
 pub struct ParsedTypeDeclaration {
@@ -28,6 +28,7 @@ pub enum ParsedTypeDeclarationBodyItem {
     AssociatedTypeDeclaration(ParsedAssociatedTypeDeclaration),
     SuperFieldDefaultValue(ParsedSuperFieldDefaultValue),
     MethodDeclaration(ParsedMethodDeclaration),
+    EnumVariantDeclaration(ParsedEnumVariantDeclaration),
     FieldDeclaration(ParsedFieldDeclaration),
 }
 
@@ -67,6 +68,52 @@ impl ParsedTypeDeclaration {
         }).collect()
     }
 
+    // `Map` and `Set` compare their keys with `__eq` and hash them with `__hash`: overriding only one of them breaks them
+    fn check_eq_and_hash_methods(&self, context: &mut ProgramContext) {
+        let methods = self.get_methods();
+        let find_method = |name: &str| methods.iter()
+            .filter(|method| !method.is_autogen())
+            .map(|method| &method.content.name)
+            .find(|method_name| method_name.as_str() == name);
+
+        match (find_method(EQ_METHOD_NAME), find_method(HASH_METHOD_NAME)) {
+            (Some(name), None) => {
+                context.errors.generic(name, format!("type `{}` declares `{}` without `{}`: equal keys of a `Map` or a `Set` would be hashed differently", self.name.as_str().bold(), EQ_METHOD_NAME.bold(), HASH_METHOD_NAME.bold()));
+            },
+            (None, Some(name)) => {
+                context.errors.generic(name, format!("type `{}` declares `{}` without `{}`: the keys of a `Map` or a `Set` would still be compared by reference", self.name.as_str().bold(), HASH_METHOD_NAME.bold(), EQ_METHOD_NAME.bold()));
+            },
+            _ => {}
+        }
+    }
+
+    fn get_enum_variants(&self) -> Vec<(&Identifier, &[ParsedFieldDeclaration])> {
+        self.get_body_items().iter().filter_map(|item| match item {
+            ParsedTypeDeclarationBodyItem::EnumVariantDeclaration(value) => Some((&value.name, value.fields.as_slice())),
+            ParsedTypeDeclarationBodyItem::FieldDeclaration(value) if value.ty.is_none() => Some((&value.name, &[][..])),
+            _ => None,
+        }).collect()
+    }
+
+    // An enum whose variants carry fields is an object, each variant being a class that extends it
+    fn has_variant_payloads(&self) -> bool {
+        self.qualifier == ParsedTypeQualifier::Enum && self.get_body_items().iter().any(|item| matches!(item, ParsedTypeDeclarationBodyItem::EnumVariantDeclaration(_)))
+    }
+
+    fn get_inherited_type(&self) -> Option<BuiltinType> {
+        match self.has_variant_payloads() {
+            true => Some(BuiltinType::EnumObject),
+            false => self.qualifier.get_inherited_type(),
+        }
+    }
+
+    fn get_variant_types(&self, context: &ProgramContext) -> Vec<Link<TypeBlueprint>> {
+        match self.has_variant_payloads() {
+            true => self.get_enum_variants().into_iter().map(|(name, _)| context.types.get_by_location(name, None)).collect(),
+            false => vec![],
+        }
+    }
+
     fn get_event_callbacks(&self) -> Vec<&ParsedEventCallbackDeclaration> {
         self.get_body_items().iter().filter_map(|item| match item {
             ParsedTypeDeclarationBodyItem::EventCallbackDeclaration(value) => Some(value),
@@ -98,8 +145,8 @@ impl ParsedTypeDeclaration {
             event_callbacks: HashMap::new(),
         };
         
-        if context.types.get_by_identifier(&self.name).is_some() {
-            context.errors.generic(&self.name, format!("duplicate type declaration: `{}`", &self.name));
+        if let Some(existing_type) = context.types.get_conflicting_item(&self.name) {
+            context.errors.generic(&self.name, format!("duplicate type declaration: `{}` (first declared at {})", &self.name, get_location_string(&existing_type.borrow().name.location)));
         }
 
         let type_wrapped = context.types.insert(type_unwrapped, None);
@@ -109,10 +156,38 @@ impl ParsedTypeDeclaration {
             .unwrap_or(category.get_default_wasm_stack_type());
         
         context.rename_provider.add_occurence(&self.name, &self.name);
+        context.semantic_tokens_provider.add_occurence(&self.name, &self.name, SemanticTokenKind::from_type_category(category), &[]);
 
         type_wrapped.with_mut(|mut type_unwrapped| {
             type_unwrapped.stack_type = stack_type;
         });
+
+        if self.has_variant_payloads() {
+            for (variant_name, _) in self.get_enum_variants() {
+                let variant_type_unwrapped = TypeBlueprint {
+                    declaration_index: index,
+                    type_id: variant_name.location.get_hash(),
+                    name: Identifier::new(&format!("{}::{}", self.name.as_str(), variant_name.as_str()), Some(&variant_name.location)),
+                    visibility: Visibility::None,
+                    category: TypeCategory::Class,
+                    stack_type: TypeCategory::Class.get_default_wasm_stack_type(),
+                    descendants: vec![],
+                    ancestors: vec![],
+                    parameters: IndexMap::new(),
+                    associated_types: IndexMap::new(),
+                    self_type: Type::undefined(),
+                    parent: None,
+                    enum_variants: IndexMap::new(),
+                    fields: IndexMap::new(),
+                    regular_methods: IndexMap::new(),
+                    static_methods: IndexMap::new(),
+                    dynamic_methods: vec![],
+                    event_callbacks: HashMap::new(),
+                };
+
+                context.types.insert(variant_type_unwrapped, None);
+            }
+        }
     }
 
     pub fn process_parameters(&self, context: &mut ProgramContext) {
@@ -124,6 +199,7 @@ impl ParsedTypeDeclaration {
 
             for details in parameters.values() {
                 context.rename_provider.add_occurence(&details.name, &details.name);
+                context.semantic_tokens_provider.add_occurence(&details.name, &details.name, SemanticTokenKind::TypeParameter, &[]);
             }
 
             type_wrapped.with_mut(|mut type_unwrapped| {
@@ -135,6 +211,19 @@ impl ParsedTypeDeclaration {
             type_wrapped.with_mut(|mut type_unwrapped| {
                 type_unwrapped.self_type = self_type;
             });
+
+            // variant types have the same parameters as their enum
+            for variant_type in self.get_variant_types(context) {
+                variant_type.with_mut(|mut variant_type_unwrapped| {
+                    variant_type_unwrapped.parameters = parameters.clone();
+                });
+
+                let variant_self_type = Type::actual(&variant_type, parameters.values().map(Type::type_parameter).collect(), &variant_type.borrow().name.location);
+
+                variant_type.with_mut(|mut variant_type_unwrapped| {
+                    variant_type_unwrapped.self_type = variant_self_type;
+                });
+            }
         });
     }
 
@@ -142,7 +231,7 @@ impl ParsedTypeDeclaration {
         let mut type_names = vec![];
         let mut builtin_types = vec![];
 
-        if let Some(builtin_type) = self.qualifier.get_inherited_type() {
+        if let Some(builtin_type) = self.get_inherited_type() {
             let builtin_type_name = builtin_type.get_name();
 
             if self.name.as_str() != builtin_type_name {
@@ -177,7 +266,7 @@ impl ParsedTypeDeclaration {
         for name in type_names {
             let identifier = Identifier::new(&name, Some(&ItemLocation { file: self.location.file.clone(), start: 0, end: 0 }));
 
-            if let Some(type_blueprint) = context.types.get_by_identifier(&identifier) {
+            if let Some(type_blueprint) = context.types.find_by_identifier(&identifier, false) {
                 dependancies.insert(type_blueprint);
             }
         }
@@ -189,7 +278,7 @@ impl ParsedTypeDeclaration {
         self.process(context, |type_wrapped, context| {
             let mut result = None;
 
-            if let Some(inherited_type) = self.qualifier.get_inherited_type() {
+            if let Some(inherited_type) = self.get_inherited_type() {
                 let parent_type_wrapped = context.types.get_by_name(inherited_type.get_name()).unwrap();
 
                 if parent_type_wrapped != type_wrapped {
@@ -230,11 +319,32 @@ impl ParsedTypeDeclaration {
             type_wrapped.with_mut(|mut type_unwrapped| {
                 type_unwrapped.parent = result;
             });
+
+            let self_type = type_wrapped.borrow().self_type.clone();
+
+            for variant_type in self.get_variant_types(context) {
+                variant_type.with_mut(|mut variant_type_unwrapped| {
+                    variant_type_unwrapped.parent = Some(ParentInfo {
+                        location: ItemLocation::default(),
+                        ty: self_type.clone(),
+                    });
+                });
+            }
         });
     }
 
     pub fn compute_descendants(&self, context: &mut ProgramContext) {
         self.process(context, |type_wrapped, context| {
+            for variant_type in self.get_variant_types(context) {
+                variant_type.with_mut(|mut variant_type_unwrapped| {
+                    variant_type_unwrapped.descendants.insert(0, variant_type.clone());
+                });
+
+                type_wrapped.with_mut(|mut type_unwrapped| {
+                    type_unwrapped.descendants.push(variant_type.clone());
+                });
+            }
+
             type_wrapped.with_mut(|mut type_unwrapped| {
                 type_unwrapped.descendants.insert(0, type_wrapped.clone());
             });
@@ -273,6 +383,16 @@ impl ParsedTypeDeclaration {
                 }
             });
 
+            for variant_type in self.get_variant_types(context) {
+                let mut variant_ancestors = vec![variant_type.borrow().self_type.clone()];
+
+                variant_ancestors.extend(ancestors.iter().cloned());
+
+                variant_type.with_mut(|mut variant_type_unwrapped| {
+                    variant_type_unwrapped.ancestors = variant_ancestors;
+                });
+            }
+
             type_wrapped.with_mut(|mut type_unwrapped| {
                 type_unwrapped.ancestors = ancestors;
             });
@@ -310,6 +430,7 @@ impl ParsedTypeDeclaration {
                     });
 
                     context.rename_provider.add_occurence(&name, &name);
+                    context.semantic_tokens_provider.add_occurence(&name, &name, SemanticTokenKind::TypeParameter, &[]);
 
                     if associated_types.insert(associatd_type_info.name.to_string(), associatd_type_info).is_some() {
                         context.errors.generic(&associated_type.name, format!("duplicate associated type `{}`", &name));
@@ -321,6 +442,12 @@ impl ParsedTypeDeclaration {
                 }
             });
 
+            for variant_type in self.get_variant_types(context) {
+                variant_type.with_mut(|mut variant_type_unwrapped| {
+                    variant_type_unwrapped.associated_types = associated_types.clone();
+                });
+            }
+
             type_wrapped.with_mut(|mut type_unwrapped| {
                 type_unwrapped.associated_types = associated_types;
             });
@@ -331,7 +458,6 @@ impl ParsedTypeDeclaration {
         self.process(context, |type_wrapped, context| {
             let mut fields = IndexMap::new();
             let mut variants = IndexMap::new();
-            let mut offset = OBJECT_HEADER_SIZE;
 
             type_wrapped.with_ref(|type_unwrapped| {
                 if let Some(parent) = &type_unwrapped.parent {
@@ -342,73 +468,93 @@ impl ParsedTypeDeclaration {
                                 ty: field_info.ty.replace_parameters(Some(&parent.ty), &[]),
                                 name: field_info.name.clone(),
                                 visibility: field_info.visibility.clone(),
-                                offset,
                                 default_value: None,
-                                is_required: field_info.is_required
+                                is_required: field_info.is_required,
+                                is_optional: field_info.is_optional
                             });
 
-                            offset += 1;
                             fields.insert(field_info.name.to_string(), field_details);
                         }
                     });
                 }
 
-                for field in self.get_fields() {
-                    field.process(type_unwrapped.parent.as_ref().map(|p| &p.ty), context);
-
-                    match &field.ty {
-                        Some(ty) => {
-                            // Regular field
-
-                            if !type_unwrapped.is_class() {
-                                context.errors.generic(&field.name, format!("only classes can have fields"));
-                                continue;
+                for item in self.get_body_items() {
+                    let (variant_name, variant_fields) = match item {
+                        ParsedTypeDeclarationBodyItem::FieldDeclaration(field) => {
+                            field.process(type_unwrapped.parent.as_ref().map(|p| &p.ty), context);
+
+                            match &field.ty {
+                                Some(ty) => {
+                                    // Regular field
+
+                                    if !type_unwrapped.is_class() {
+                                        context.errors.generic(&field.name, format!("only classes can have fields"));
+                                        continue;
+                                    }
+
+                                    if fields.contains_key(field.name.as_str()) {
+                                        context.errors.generic(&field.name, format!("duplicate field `{}`", field.name.as_str()));
+                                    }
+
+                                    if let Some(field_type) = ty.process(context) {
+                                        context.rename_provider.add_occurence(&field.name, &field.name);
+                                        context.semantic_tokens_provider.add_occurence(&field.name, &field.name, SemanticTokenKind::Property, &[]);
+
+                                        let field_details = Rc::new(FieldInfo {
+                                            owner: type_wrapped.clone(),
+                                            ty: field_type,
+                                            name: field.name.clone(),
+                                            visibility: FieldVisibility::from_name(field.name.as_str()),
+                                            default_value: None,
+                                            is_required: field.default_value.is_none(),
+                                            is_optional: ty.is_option()
+                                        });
+
+                                        fields.insert(field.name.to_string(), field_details);
+                                    }
+
+                                    continue;
+                                },
+                                None => (&field.name, &[][..]),
                             }
+                        },
+                        ParsedTypeDeclarationBodyItem::EnumVariantDeclaration(variant) => (&variant.name, variant.fields.as_slice()),
+                        _ => continue,
+                    };
 
-                            if fields.contains_key(field.name.as_str()) {
-                                context.errors.generic(&field.name, format!("duplicate field `{}`", field.name.as_str()));
-                            }
+                    // Enum variant
 
-                            if let Some(field_type) = ty.process(context) {
-                                context.rename_provider.add_occurence(&field.name, &field.name);
-
-                                let field_details = Rc::new(FieldInfo {
-                                    owner: type_wrapped.clone(),
-                                    ty: field_type,
-                                    name: field.name.clone(),
-                                    visibility: FieldVisibility::from_name(field.name.as_str()),
-                                    offset,
-                                    default_value: None,
-                                    is_required: field.default_value.is_none()
-                                });
-
-                                offset += 1;
-                                fields.insert(field.name.to_string(), field_details);
-                            }
-                        },
-                        None => {
-                            // Enum variant
+                    context.rename_provider.add_occurence(variant_name, variant_name);
+                    context.semantic_tokens_provider.add_occurence(variant_name, variant_name, SemanticTokenKind::EnumMember, &[]);
 
-                            context.rename_provider.add_occurence(&field.name, &field.name);
+                    if !type_unwrapped.is_enum() {
+                        context.errors.generic(variant_name, format!("only enums can have variants"));
+                        continue;
+                    }
 
-                            if !type_unwrapped.is_enum() {
-                                context.errors.generic(&field.name, format!("only enums can have variants"));
-                                continue;
-                            }
+                    if variants.contains_key(variant_name.as_str()) {
+                        context.errors.generic(variant_name, format!("duplicate variant `{}`", self.name.as_str().bold()));
+                    }
 
-                            if variants.contains_key(field.name.as_str()) {
-                                context.errors.generic(&field.name, format!("duplicate variant `{}`", self.name.as_str().bold()));
-                            }
+                    let variant_type = match self.has_variant_payloads() {
+                        true => {
+                            let variant_type = context.types.get_by_location(variant_name, None);
 
-                            let variant_details = Rc::new(EnumVariantInfo {
-                                owner: type_wrapped.clone(),
-                                name: field.name.clone(),
-                                value: variants.len(),
-                            });
+                            process_variant_fields(&variant_type, variant_fields, context);
 
-                            variants.insert(field.name.to_string(), variant_details);
+                            Some(variant_type)
                         },
-                    }
+                        false => None,
+                    };
+
+                    let variant_details = Rc::new(EnumVariantInfo {
+                        owner: type_wrapped.clone(),
+                        name: variant_name.clone(),
+                        value: variants.len(),
+                        variant_type,
+                    });
+
+                    variants.insert(variant_name.to_string(), variant_details);
                 }
             });
 
@@ -452,6 +598,19 @@ impl ParsedTypeDeclaration {
             for method in self.get_methods().iter().filter(|method| !method.is_autogen()) {
                 method.process_signature(context);
             }
+
+            self.check_eq_and_hash_methods(context);
+
+            for variant_type in self.get_variant_types(context) {
+                let (regular_methods, static_methods) = type_wrapped.with_ref(|type_unwrapped| {
+                    (type_unwrapped.regular_methods.clone(), type_unwrapped.static_methods.clone())
+                });
+
+                variant_type.with_mut(|mut variant_type_unwrapped| {
+                    variant_type_unwrapped.regular_methods = regular_methods;
+                    variant_type_unwrapped.static_methods = static_methods;
+                });
+            }
         });
     }
 
@@ -475,6 +634,10 @@ impl ParsedTypeDeclaration {
 
     pub fn process_fields_default_values(&self, context: &mut ProgramContext) {
         self.process(context, |type_wrapped, context| {
+            for (variant_type, (_, variant_fields)) in self.get_variant_types(context).into_iter().zip(self.get_enum_variants()) {
+                process_variant_fields_default_values(&variant_type, variant_fields, context);
+            }
+
             if !type_wrapped.borrow().is_class() {
                 return;
             }
@@ -495,6 +658,7 @@ impl ParsedTypeDeclaration {
                 let self_argument = ArgumentInfo {
                     name: Identifier::unlocated("self"),
                     ty: self_type.clone(),
+                    has_default_value: false,
                     is_optional: false,
                     default_value: context.vasm(),
                 };
@@ -514,6 +678,7 @@ impl ParsedTypeDeclaration {
                                 parameters: IndexMap::new(),
                                 arguments: vec![self_argument.clone()],
                                 signature: Signature::create(None, vec![self_type.clone()], field_info.ty.clone()),
+                                is_return_optional: field_info.is_optional,
                                 argument_variables: vec![],
                                 owner_type: Some(type_wrapped.clone()),
                                 owner_interface: None,
@@ -528,6 +693,7 @@ impl ParsedTypeDeclaration {
 
                             if let Some(vasm) = parsed_default_value.process(Some(&field_info.ty), context) {
                                 if vasm.ty.is_assignable_to(&field_info.ty) {
+                                    context.check_optional_assignment(parsed_default_value, &vasm, field_info.is_optional || field_info.ty.is_parameter());
                                     function_wrapped.borrow_mut().body = FunctionBody::Vasm(vasm);
                                     default_value = Some(function_wrapped.clone());
                                 } else {
@@ -555,6 +721,7 @@ impl ParsedTypeDeclaration {
                                 parameters: IndexMap::new(),
                                 arguments: vec![self_argument.clone()],
                                 signature: Signature::create(None, vec![self_type.clone()], field_info.ty.clone()),
+                                is_return_optional: field_info.is_optional,
                                 argument_variables: vec![],
                                 owner_type: Some(type_wrapped.clone()),
                                 owner_interface: None,
@@ -600,43 +767,11 @@ impl ParsedTypeDeclaration {
 
     pub fn process_dynamic_methods(&self, context: &mut ProgramContext) {
         self.process(context, |type_wrapped, context| {
-            let dynamic_methods = type_wrapped.with_ref(|type_unwrapped| {
-                let mut result : Vec<FuncRef> = type_unwrapped.regular_methods.values()
-                    .filter_map(|func_ref| match func_ref.function.borrow().is_dynamic() {
-                        true => Some(func_ref.clone()),
-                        false => None,
-                    })
-                    .collect();
-                
-                result.sort_by_cached_key(|func_ref| func_ref.function.borrow().name.to_string());
-                result.sort_by_cached_key(|func_ref| func_ref.function.borrow().method_details.as_ref().unwrap().first_declared_by.as_ref().unwrap().borrow().ancestors.len());
-
-                result
-            });
+            process_dynamic_methods(&type_wrapped);
 
-            // if self.name.is("Object") || self.name.is("Foo") {
-            //     println!("=> {}", &self.name);
-            //     for func_ref in dynamic_methods.iter() {
-            //         println!("{}", func_ref.function.borrow().name);
-            //     }
-            // }
-
-            for (i, func_ref) in dynamic_methods.iter().enumerate() {
-                func_ref.function.with_mut(|mut function_unwrapped| {
-                    let mut method_details = function_unwrapped.method_details.as_mut().unwrap();
-                    let dynamic_index = method_details.dynamic_index.unwrap();
-
-                    if dynamic_index == -1 {
-                        method_details.dynamic_index = Some(i as i32);
-                    } else if dynamic_index != i as i32 {
-                        panic!("attempt to assign dynamic index {} to method `{}`, but it already has dynamic index {}", i, function_unwrapped.name.as_str().bold(), dynamic_index);
-                    }
-                });
+            for variant_type in self.get_variant_types(context) {
+                process_dynamic_methods(&variant_type);
             }
-
-            type_wrapped.with_mut(|mut type_unwrapped| {
-                type_unwrapped.dynamic_methods = dynamic_methods;
-            });
         });
     }
 
@@ -723,4 +858,146 @@ impl ParsedTypeDeclaration {
         f(type_blueprint, context);
         context.pop_scope();
     }
-}
\ No newline at end of file
+}
+
+fn process_variant_fields(variant_type: &Link<TypeBlueprint>, variant_fields: &[ParsedFieldDeclaration], context: &mut ProgramContext) {
+    let mut fields = IndexMap::new();
+
+    for field in variant_fields {
+        match &field.ty {
+            Some(ty) => {
+                if fields.contains_key(field.name.as_str()) {
+                    context.errors.generic(&field.name, format!("duplicate field `{}`", field.name.as_str()));
+                }
+
+                if let Some(field_type) = ty.process(context) {
+                    context.rename_provider.add_occurence(&field.name, &field.name);
+                    context.semantic_tokens_provider.add_occurence(&field.name, &field.name, SemanticTokenKind::Property, &[]);
+
+                    let field_details = Rc::new(FieldInfo {
+                        owner: variant_type.clone(),
+                        ty: field_type,
+                        name: field.name.clone(),
+                        visibility: FieldVisibility::from_name(field.name.as_str()),
+                        default_value: None,
+                        is_required: field.default_value.is_none(),
+                        is_optional: ty.is_option()
+                    });
+
+                    fields.insert(field.name.to_string(), field_details);
+                }
+            },
+            None => {
+                context.errors.generic(&field.name, format!("missing type for field `{}`", field.name.as_str()));
+            },
+        }
+    }
+
+    variant_type.with_mut(|mut variant_type_unwrapped| {
+        variant_type_unwrapped.fields = fields;
+    });
+}
+
+fn process_variant_fields_default_values(variant_type: &Link<TypeBlueprint>, variant_fields: &[ParsedFieldDeclaration], context: &mut ProgramContext) {
+    let self_type = variant_type.borrow().self_type.clone();
+    let self_argument = ArgumentInfo {
+        name: Identifier::unlocated("self"),
+        ty: self_type.clone(),
+        has_default_value: false,
+        is_optional: false,
+        default_value: context.vasm(),
+    };
+    let mut default_values = vec![];
+
+    context.push_scope(ScopeKind::Type(variant_type.clone()));
+
+    for field in variant_fields {
+        let field_info = match variant_type.borrow().fields.get(field.name.as_str()) {
+            Some(field_info) => field_info.clone(),
+            None => continue,
+        };
+
+        if let Some(parsed_default_value) = &field.default_value {
+            let function_blueprint = FunctionBlueprint {
+                name: Identifier::unique(&format!("{}_{}_default", variant_type.borrow().name.as_str(), field_info.name.as_str())),
+                visibility: Visibility::None,
+                parameters: IndexMap::new(),
+                arguments: vec![self_argument.clone()],
+                signature: Signature::create(None, vec![self_type.clone()], field_info.ty.clone()),
+                is_return_optional: field_info.is_optional,
+                argument_variables: vec![],
+                owner_type: Some(variant_type.clone()),
+                owner_interface: None,
+                closure_details: None,
+                method_details: None,
+                kind: FunctionKind::DefaultValue,
+                body: FunctionBody::Empty,
+            };
+            let function_wrapped = context.functions.insert(function_blueprint, None);
+
+            context.push_scope(ScopeKind::Function(function_wrapped.clone()));
+
+            if let Some(vasm) = parsed_default_value.process(Some(&field_info.ty), context) {
+                if vasm.ty.is_assignable_to(&field_info.ty) {
+                    context.check_optional_assignment(parsed_default_value, &vasm, field_info.is_optional || field_info.ty.is_parameter());
+                    function_wrapped.borrow_mut().body = FunctionBody::Vasm(vasm);
+                    default_values.push((field_info.name.to_string(), function_wrapped.clone()));
+                } else {
+                    context.errors.type_mismatch(parsed_default_value, &field_info.ty, &vasm.ty);
+                }
+            }
+
+            context.pop_scope();
+        }
+    }
+
+    context.pop_scope();
+
+    variant_type.with_mut(|mut variant_type_unwrapped| {
+        for (name, default_value) in default_values {
+            let mut field_info = Rc::get_mut(variant_type_unwrapped.fields.get_mut(&name).unwrap()).unwrap();
+
+            field_info.default_value = Some(default_value);
+        }
+    });
+}
+
+fn process_dynamic_methods(type_wrapped: &Link<TypeBlueprint>) {
+    let dynamic_methods = type_wrapped.with_ref(|type_unwrapped| {
+        let mut result : Vec<FuncRef> = type_unwrapped.regular_methods.values()
+            .filter_map(|func_ref| match func_ref.function.borrow().is_dynamic() {
+                true => Some(func_ref.clone()),
+                false => None,
+            })
+            .collect();
+        
+        result.sort_by_cached_key(|func_ref| func_ref.function.borrow().name.to_string());
+        result.sort_by_cached_key(|func_ref| func_ref.function.borrow().method_details.as_ref().unwrap().first_declared_by.as_ref().unwrap().borrow().ancestors.len());
+
+        result
+    });
+
+    // if self.name.is("Object") || self.name.is("Foo") {
+    //     println!("=> {}", &self.name);
+    //     for func_ref in dynamic_methods.iter() {
+    //         println!("{}", func_ref.function.borrow().name);
+    //     }
+    // }
+
+    for (i, func_ref) in dynamic_methods.iter().enumerate() {
+        func_ref.function.with_mut(|mut function_unwrapped| {
+            let mut method_details = function_unwrapped.method_details.as_mut().unwrap();
+            let dynamic_index = method_details.dynamic_index.unwrap();
+
+            if dynamic_index == -1 {
+                method_details.dynamic_index = Some(i as i32);
+            } else if dynamic_index != i as i32 {
+                panic!("attempt to assign dynamic index {} to method `{}`, but it already has dynamic index {}", i, function_unwrapped.name.as_str().bold(), dynamic_index);
+            }
+        });
+    }
+
+    type_wrapped.with_mut(|mut type_unwrapped| {
+        type_unwrapped.dynamic_methods = dynamic_methods;
+    });
+}
@@ -1,7 +1,7 @@
 use std::{collections::HashSet, rc::Rc};
 use indexmap::{IndexMap, IndexSet};
 use parsable::parsable;
-use crate::{program::{self, ParameterTypeInfo, InterfaceList, ProgramContext}, utils::Link};
+use crate::{program::{self, ParameterTypeInfo, InterfaceList, ProgramContext}, utils::Link, language_server::SemanticTokenKind};
 use super::{Identifier, ParsedOpeningAngleBracket, ParsedClosingAngleBracket};
 
 #[parsable]
@@ -32,8 +32,11 @@ impl ParsedTypeParameters {
                 context.add_interface_completion_area(interface_name);
 
                 if let Some(interface) = context.interfaces.get_by_identifier(interface_name) {
+                    context.semantic_tokens_provider.add_occurence(interface_name, &interface.borrow().name, SemanticTokenKind::Interface, &[]);
                     required_interfaces.push(interface.clone());
                 } else if let Some(type_wrapped) = context.types.get_by_identifier(interface_name) {
+                    context.semantic_tokens_provider.add_occurence(interface_name, &type_wrapped.borrow().name, SemanticTokenKind::from_type_category(type_wrapped.borrow().category), &[]);
+                    context.mark_type_as_used(&type_wrapped);
                     inherited_type = Some(type_wrapped.borrow().self_type.clone());
                 } else {
                     context.errors.generic(interface_name, format!("undefined interface or class `{}`", interface_name));
@@ -1,10 +1,12 @@
 use parsable::parsable;
 use crate::program::{ProgramContext, Type};
-use super::{ParsedFunctionType, Identifier, ParsedValueType};
+use super::{ParsedFunctionType, Identifier, ParsedQualifiedType, ParsedValueType};
 
 #[parsable]
 pub enum ParsedTypeSingle {
     Function(ParsedFunctionType),
+    #[parsable(ignore_if_marker = "no-qualified-type")]
+    Qualified(ParsedQualifiedType),
     Value(ParsedValueType),
 }
 
@@ -12,6 +14,7 @@ impl ParsedTypeSingle {
     pub fn as_single_identifier(&self) -> Option<&Identifier> {
         match self {
             ParsedTypeSingle::Value(value_type) => value_type.as_single_name(),
+            ParsedTypeSingle::Qualified(_) => None,
             ParsedTypeSingle::Function(_) => None,
         }
     }
@@ -19,6 +22,7 @@ impl ParsedTypeSingle {
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         match self {
             ParsedTypeSingle::Value(value_type) => value_type.collect_instancied_type_names(list),
+            ParsedTypeSingle::Qualified(qualified_type) => qualified_type.collect_instancied_type_names(list),
             ParsedTypeSingle::Function(_) => {},
         }
     }
@@ -26,6 +30,7 @@ impl ParsedTypeSingle {
     pub fn process(&self, check_interfaces: bool, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Type> {
         match self {
             ParsedTypeSingle::Value(value_type) => value_type.process(check_interfaces, type_hint, context),
+            ParsedTypeSingle::Qualified(qualified_type) => qualified_type.process(check_interfaces, type_hint, context),
             ParsedTypeSingle::Function(function_type) => function_type.process(check_interfaces, context),
         }
     }
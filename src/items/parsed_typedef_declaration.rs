@@ -1,5 +1,5 @@
 use parsable::{create_token_struct, parsable};
-use crate::{program::{ProgramContext, TypedefBlueprint, Visibility, TYPE_KEYWORD}, utils::Link};
+use crate::{program::{ProgramContext, TypedefBlueprint, Visibility, TYPE_KEYWORD}, utils::Link, language_server::SemanticTokenKind};
 use super::{ParsedType, Identifier, ParsedVisibilityToken, ParsedVisibility, ParsedEqualToken, ParsedSemicolonToken, unwrap_item};
 
 create_token_struct!(TypeKeyword, TYPE_KEYWORD);
@@ -21,6 +21,7 @@ impl ParsedTypedefDeclaration {
 
         if let Some(ty) = target.process(true, None, context) {
             context.rename_provider.add_occurence(&self.name, &self.name);
+            context.semantic_tokens_provider.add_occurence(&self.name, &self.name, SemanticTokenKind::Type, &[]);
 
             let typedef_blueprint = TypedefBlueprint {
                 type_id: self.location.get_hash(),
@@ -1,6 +1,6 @@
 use parsable::parsable;
-use crate::{program::{ProgramContext, Type, Vasm}, wat};
-use super::{Identifier, ParsedOperand, ParsedUnaryOperator};
+use crate::{program::{ProgramContext, Type, VariableInfo, Vasm}, wat};
+use super::{Identifier, ParsedOperand, ParsedUnaryOperator, ParsedUnaryOperatorToken};
 
 #[parsable]
 pub struct ParsedUnaryOperation {
@@ -9,10 +9,20 @@ pub struct ParsedUnaryOperation {
 }
 
 impl ParsedUnaryOperation {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.operand.collect_assigned_variables(list, context);
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         self.operand.collect_instancied_type_names(list, context);
     }
 
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        if let ParsedUnaryOperatorToken::BooleanNot = &self.operator.token {
+            self.operand.collect_narrowed_variables(!is_truthy, list, context);
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let mut result = None;
 
@@ -0,0 +1,43 @@
+use parsable::{create_token_struct, parsable};
+use crate::program::{ImportDetails, ProgramContext, USE_KEYWORD};
+use super::{ParsedSemicolonToken, ParsedUsePath, unwrap_item};
+
+create_token_struct!(UseKeyword, USE_KEYWORD);
+
+#[parsable]
+pub struct ParsedUseDeclaration {
+    pub use_keyword: UseKeyword,
+    pub path: Option<ParsedUsePath>,
+    pub semicolon: Option<ParsedSemicolonToken>,
+}
+
+impl ParsedUseDeclaration {
+    pub fn process(&self, context: &mut ProgramContext) -> Option<ImportDetails> {
+        let path = unwrap_item(&self.path, &self.use_keyword, context)?;
+        let import = path.process(context)?;
+
+        unwrap_item(&self.semicolon, self, context)?;
+        context.add_import(import.clone());
+
+        Some(import)
+    }
+
+    pub fn check(&self, import: &ImportDetails, context: &mut ProgramContext) {
+        let name = self.path.as_ref().and_then(|path| path.get_name()).unwrap();
+
+        match context.get_imported_item_location(import, true) {
+            Some(definition_location) => {
+                context.rename_provider.add_occurence(name, &definition_location);
+                context.definition_provider.set_definition(name, &definition_location);
+            },
+            None => match context.get_imported_item_location(import, false) {
+                Some(_) => {
+                    context.errors.generic(name, format!("`{}` is private", name));
+                },
+                None => {
+                    context.errors.generic(self.path.as_ref().unwrap(), format!("unresolved import `{}`", self.path.as_ref().unwrap().location.as_str()));
+                },
+            },
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use parsable::parsable;
+use crate::program::{ImportDetails, ProgramContext};
+use super::{Identifier, ParsedDoubleColonToken, ParsedUsePathSegment};
+
+#[parsable]
+pub struct ParsedUsePath {
+    pub namespace: Identifier,
+    pub segments: Vec<ParsedUsePathSegment>,
+}
+
+impl ParsedUsePath {
+    pub fn process(&self, context: &mut ProgramContext) -> Option<ImportDetails> {
+        context.add_namespace_completion_area(&self.namespace, None);
+
+        let package_root_path = match context.get_namespace_root_path(&self.namespace) {
+            Some(package_root_path) => package_root_path,
+            None => {
+                context.errors.generic(&self.namespace, format!("undefined namespace `{}`", &self.namespace));
+                return None;
+            },
+        };
+
+        let mut directories = vec![];
+
+        for (i, segment) in self.segments.iter().enumerate() {
+            context.add_namespace_completion_area(&segment.double_colon, Some((&package_root_path, &directories)));
+
+            let name = match &segment.name {
+                Some(name) => name,
+                None => {
+                    context.errors.expected_identifier(&segment.double_colon);
+                    return None;
+                },
+            };
+
+            context.add_namespace_completion_area(name, Some((&package_root_path, &directories)));
+
+            if i == self.segments.len() - 1 {
+                return Some(ImportDetails {
+                    location: name.location.clone(),
+                    name: name.to_string(),
+                    package_root_path,
+                    directories,
+                });
+            }
+
+            directories.push(name.to_string());
+        }
+
+        context.errors.expected_item::<ParsedDoubleColonToken>(&self.namespace);
+        None
+    }
+
+    pub fn get_name(&self) -> Option<&Identifier> {
+        self.segments.last().and_then(|segment| segment.name.as_ref())
+    }
+}
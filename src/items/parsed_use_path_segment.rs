@@ -0,0 +1,8 @@
+use parsable::parsable;
+use super::{Identifier, ParsedDoubleColonToken};
+
+#[parsable]
+pub struct ParsedUsePathSegment {
+    pub double_colon: ParsedDoubleColonToken,
+    pub name: Option<Identifier>,
+}
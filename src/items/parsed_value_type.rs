@@ -1,7 +1,7 @@
 use std::{rc::Rc};
 use parsable::parsable;
 use colored::*;
-use crate::program::{ActualTypeContent, AssociatedTypeContent, ProgramContext, SELF_TYPE_NAME, SELF_VAR_NAME, Type, TypeContent};
+use crate::{program::{ActualTypeContent, AssociatedTypeContent, ImportDetails, ProgramContext, SELF_TYPE_NAME, SELF_VAR_NAME, Type, TypeContent}, language_server::SemanticTokenKind};
 use super::{ParsedTypeArguments, Identifier, ParsedTypeSuffixToken};
 
 #[parsable]
@@ -27,42 +27,57 @@ impl ParsedValueType {
     }
 
     pub fn process(&self, check_interfaces: bool, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Type> {
+        self.process_in_namespace(None, check_interfaces, type_hint, context)
+    }
+
+    pub fn process_in_namespace(&self, namespace: Option<&ImportDetails>, check_interfaces: bool, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Type> {
         let mut result = Type::undefined();
         let mut must_not_take_parameters = false;
         let mut param_count_error = false;
         let parameters = self.arguments.process(check_interfaces, context);
         let parameter_count = parameters.len();
 
-        context.add_type_completion_area(&self.name, None);
+        if namespace.is_none() {
+            context.add_type_completion_area(&self.name, None);
 
-        if self.name.as_str() == SELF_TYPE_NAME {
-            result = context.get_this_type();
+            if self.name.as_str() == SELF_TYPE_NAME {
+                result = context.get_this_type();
+            }
         }
 
         if result.is_undefined() {
-            if let Some(typedef_blueprint) = context.typedefs.get_by_identifier(&self.name) {
+            let typedef_blueprint = match namespace {
+                Some(import) => context.typedefs.get_by_import(import, true),
+                None => context.typedefs.get_by_identifier(&self.name),
+            };
+
+            if let Some(typedef_blueprint) = typedef_blueprint {
                 must_not_take_parameters = true;
                 result = typedef_blueprint.borrow().target.clone();
 
                 context.rename_provider.add_occurence(&self.name, &typedef_blueprint.borrow().name);
+                context.semantic_tokens_provider.add_occurence(&self.name, &typedef_blueprint.borrow().name, SemanticTokenKind::Type, &[]);
                 context.definition_provider.set_definition(&self.name, &typedef_blueprint.borrow().name);
                 context.hover_provider.set_type(&self.name, &result);
             }
         }
 
-        if result.is_undefined() {
+        if result.is_undefined() && namespace.is_none() {
             if let Some(ty) = context.get_type_parameter(self.name.as_str()) {
                 match ty.content() {
                     TypeContent::TypeParameter(details) => {
                         context.rename_provider.add_occurence(&self.name, &details.name);
+                        context.semantic_tokens_provider.add_occurence(&self.name, &details.name, SemanticTokenKind::TypeParameter, &[]);
                         context.definition_provider.set_definition(&self.name, &details.name);
                     },
                     TypeContent::FunctionParameter(details) => {
                         context.rename_provider.add_occurence(&self.name, &details.name);
+                        context.semantic_tokens_provider.add_occurence(&self.name, &details.name, SemanticTokenKind::TypeParameter, &[]);
                         context.definition_provider.set_definition(&self.name, &details.name);
                     },
                     TypeContent::Associated(details) => {
                         context.rename_provider.add_occurence(&self.name, &details.associated.name);
+                        context.semantic_tokens_provider.add_occurence(&self.name, &details.associated.name, SemanticTokenKind::TypeParameter, &[]);
                         context.definition_provider.set_definition(&self.name, &details.associated.name);
                     },
                     _ => unreachable!()
@@ -75,8 +90,14 @@ impl ParsedValueType {
 
         if result.is_undefined() {
             let mut parameter_list = parameters;
+            let type_blueprint = match namespace {
+                Some(import) => context.types.get_by_import(import, true),
+                None => context.types.get_by_identifier(&self.name),
+            };
+
+            if let Some(type_blueprint) = type_blueprint {
+                context.mark_type_as_used(&type_blueprint);
 
-            if let Some(type_blueprint) = context.types.get_by_identifier(&self.name) {
                 let parameters = &type_blueprint.borrow().parameters;
 
                 // If no parameter is provided but we know what type to expect, infer parameters
@@ -106,6 +127,7 @@ impl ParsedValueType {
                 }
 
                 context.rename_provider.add_occurence(&self.name, &type_blueprint.borrow().name);
+                context.semantic_tokens_provider.add_occurence(&self.name, &type_blueprint.borrow().name, SemanticTokenKind::from_type_category(type_blueprint.borrow().category), &[]);
                 context.definition_provider.set_definition(&self.name, &type_blueprint.borrow().name);
             }
         }
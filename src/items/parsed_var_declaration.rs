@@ -1,7 +1,7 @@
 use std::{collections::HashMap, rc::Rc};
 use parsable::{parsable, ItemLocation};
 use crate::{program::{ProgramContext, TUPLE_FIRST_ASSOCIATED_TYPE_NAME, TUPLE_FIRST_METHOD_NAME, TUPLE_SECOND_ASSOCIATED_TYPE_NAME, TUPLE_SECOND_METHOD_NAME, Type, VariableInfo, VariableKind, Vasm}};
-use super::{ParsedExpression, Identifier, ParsedType, ParsedVarDeclarationQualifier, ParsedVarDeclarationNames, ParsedColonToken, ParsedEqualToken, unwrap_item, ParsedVarDeclarationType};
+use super::{ParsedExpression, Identifier, ParsedType, ParsedVarDeclarationQualifier, ParsedVarDeclarationQualifierToken, ParsedVarDeclarationNames, ParsedColonToken, ParsedEqualToken, unwrap_item, ParsedVarDeclarationType};
 
 #[parsable(cascade = true)]
 pub struct ParsedVarDeclaration {
@@ -19,7 +19,30 @@ impl ParsedVarDeclaration {
         let equal = unwrap_item(&self.equal, &self.var_type, context)?;
         let init_value = unwrap_item(&self.init_value, equal, context)?;
         let vasm = init_value.process(required_type.as_ref(), context).unwrap_or(context.vasm());
+        let is_optional = match &required_type {
+            Some(_) => self.var_type.is_option(),
+            None => vasm.is_optional,
+        };
+        let is_init_value_optional = vasm.is_optional;
 
-        var_names.process(required_type.as_ref(), vasm, Some(&init_value.location), context)
+        context.check_optional_assignment(&init_value.location, &vasm, is_optional);
+
+        let (variables, vasm) = var_names.process(required_type.as_ref(), vasm, Some(&init_value.location), context)?;
+
+        if self.qualifier.token == ParsedVarDeclarationQualifierToken::Const {
+            for var_info in &variables {
+                context.semantic_tokens_provider.add_readonly_definition(&var_info.name());
+            }
+        }
+
+        if let [var_info] = variables.as_slice() {
+            var_info.set_optional(is_optional);
+
+            if is_optional && !is_init_value_optional {
+                context.narrow_variables(&variables);
+            }
+        }
+
+        Some((variables, vasm))
     }
 }
\ No newline at end of file
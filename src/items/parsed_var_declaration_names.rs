@@ -55,6 +55,10 @@ impl ParsedVarDeclarationNames {
 
                 context.hover_provider.set_type(name, &variable_type);
 
+                if required_type.is_none() {
+                    context.inlay_hints_provider.add_type_hint(name, &variable_type);
+                }
+
                 Some((
                     vec![var_info.clone()],
                     context.vasm()
@@ -76,11 +80,21 @@ impl ParsedVarDeclarationNames {
 
                     if let Some(first_type) = variable_type.get_associated_type(TUPLE_FIRST_ASSOCIATED_TYPE_NAME) {
                         context.hover_provider.set_type(&names[0], &first_type);
+
+                        if required_type.is_none() {
+                            context.inlay_hints_provider.add_type_hint(&names[0], &first_type);
+                        }
+
                         var_1.set_type(first_type);
                     }
 
                     if let Some(second_type) = variable_type.get_associated_type(TUPLE_SECOND_ASSOCIATED_TYPE_NAME) {
                         context.hover_provider.set_type(&names[1], &second_type);
+
+                        if required_type.is_none() {
+                            context.inlay_hints_provider.add_type_hint(&names[1], &second_type);
+                        }
+
                         var_2.set_type(second_type);
                     }
 
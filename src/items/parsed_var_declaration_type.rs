@@ -9,6 +9,13 @@ pub struct ParsedVarDeclarationType {
 }
 
 impl ParsedVarDeclarationType {
+    pub fn is_option(&self) -> bool {
+        match &self.var_type {
+            Some(ty) => ty.is_option(),
+            None => false,
+        }
+    }
+
     pub fn process(&self, context: &mut ProgramContext) -> Option<Type> {
         if let Some(colon) = &self.colon {
             if let Some(parsed_type) = &self.var_type {
@@ -1,5 +1,5 @@
 use parsable::parsable;
-use crate::{program::{AccessType, FieldKind, ProgramContext, Type, Vasm}};
+use crate::{program::{AccessType, FieldKind, ProgramContext, Type, VariableInfo, Vasm}};
 use super::{Identifier, ParsedVarPathRoot, ParsedVarPathSegment};
 
 #[parsable(declare_marker = "no-function-call")]
@@ -9,10 +9,31 @@ pub struct ParsedVarPath {
 }
 
 impl ParsedVarPath {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.root.collect_assigned_variables(list, context);
+
+        for segment in &self.path {
+            segment.collect_assigned_variables(list, context);
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         self.root.collect_instancied_type_names(list, context);
     }
 
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        if self.path.is_empty() {
+            self.root.collect_narrowed_variables(is_truthy, list, context);
+        }
+    }
+
+    pub fn get_var_info(&self, context: &ProgramContext) -> Option<VariableInfo> {
+        match self.path.is_empty() {
+            true => self.root.get_var_info(context),
+            false => None,
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, access_type: AccessType, context: &mut ProgramContext) -> Option<Vasm> {
         let mut current_access_type = match self.path.is_empty() {
             true => access_type,
@@ -36,6 +57,8 @@ impl ParsedVarPath {
                     current_type_hint = type_hint;
                 }
 
+                context.check_optional_access(segment, &result);
+
                 if let Some(segment_vasm) = segment.process(&parent_type, current_type_hint, current_access_type, context) {
                     parent_type = segment_vasm.ty.clone();
                     result = result.append(segment_vasm);
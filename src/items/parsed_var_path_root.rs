@@ -1,5 +1,5 @@
 use parsable::{ItemLocation, parsable};
-use crate::{program::{AccessType, ProgramContext, Type, VariableKind, Vasm}};
+use crate::{program::{AccessType, ProgramContext, Type, VariableInfo, VariableKind, Vasm}};
 use super::{ParsedAction, ParsedArrayLiteral, ParsedOperandBody, ParsedBlockExpression, ParsedBooleanLiteral, ParsedCharLiteral, ParsedExpression, ParsedFieldOrMethodAccess, ParsedForBlock, ParsedAnonymousFunction, Identifier, ParsedIfBlock, ParsedIterAncestorsBlock, ParsedIterFieldsBlock, ParsedIterVariantsBlock, ParsedMatchBlock, ParsedNoneLiteral, ParsedNumberLiteral, ParsedObjectLiteral, ParsedParenthesizedExpression, ParsedStaticFieldOrMethod, ParsedStringLiteral, ParsedTemplateString, ParsedVarDeclaration, ParsedVarRef, ParsedWhileBlock, ParsedMacroExpression, ParsedPrefixedVarRef, ParsedMacroDebug, ParsedColorLiteral, ParsedLoadDirective};
 
 #[parsable]
@@ -24,12 +24,13 @@ pub enum ParsedVarPathRoot {
     StringLiteral(ParsedStringLiteral),
     TemplateString(ParsedTemplateString),
     ArrayLiteral(ParsedArrayLiteral),
-    StaticFieldOrMethod(ParsedStaticFieldOrMethod),
     #[parsable(ignore_if_marker = "no-object")]
     ObjectLiteral(ParsedObjectLiteral),
+    StaticFieldOrMethod(ParsedStaticFieldOrMethod),
     ColorLiteral(ParsedColorLiteral),
+    #[parsable(ignore_if_marker = "no-anonymous-function")]
     FunctionLiteral(ParsedAnonymousFunction),
-    #[parsable(unset_marker = "no-object", unset_marker = "no-function-call")]
+    #[parsable(unset_marker = "no-object", unset_marker = "no-function-call", unset_marker = "no-anonymous-function")]
     Parenthesized(ParsedParenthesizedExpression),
     #[parsable(unset_marker = "no-function-call")]
     PrefixedVarRef(ParsedPrefixedVarRef),
@@ -45,6 +46,83 @@ impl ParsedVarPathRoot {
         }
     }
 
+    pub fn collect_narrowed_variables(&self, is_truthy: bool, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        match self {
+            ParsedVarPathRoot::Parenthesized(expr) => expr.collect_narrowed_variables(is_truthy, list, context),
+            _ => {
+                if let (Some(var_info), true) = (self.get_var_info(context), is_truthy) {
+                    // a boolean condition checks the value of the boolean, not whether it is `none`
+                    if var_info.is_optional() && !var_info.ty().is_bool() {
+                        list.push(var_info);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn get_var_info(&self, context: &ProgramContext) -> Option<VariableInfo> {
+        match self {
+            ParsedVarPathRoot::VarRef(var_ref) => var_ref.get_var_info(context),
+            _ => None
+        }
+    }
+
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        let arguments = match self {
+            ParsedVarPathRoot::StaticFieldOrMethod(static_field_or_method) => static_field_or_method.arguments.as_ref(),
+            ParsedVarPathRoot::PrefixedVarRef(prefixed_var_ref) => prefixed_var_ref.arguments.as_ref(),
+            ParsedVarPathRoot::VarRef(var_ref) => var_ref.arguments.as_ref(),
+            _ => None,
+        };
+
+        if let Some(arguments) = arguments {
+            arguments.collect_assigned_variables(list, context);
+        }
+
+        match self {
+            ParsedVarPathRoot::LoadDirective(_) => {},
+            ParsedVarPathRoot::Macro(_) => {},
+            ParsedVarPathRoot::DebugMacro(debug_macro) => {
+                if let Some(expression) = &debug_macro.expression {
+                    expression.collect_assigned_variables(list, context);
+                }
+            },
+            ParsedVarPathRoot::VarDeclaration(var_declaration) => {
+                if let Some(init_value) = &var_declaration.init_value {
+                    init_value.collect_assigned_variables(list, context);
+                }
+            },
+            ParsedVarPathRoot::Action(action) => {
+                if let Some(expression) = &action.expression {
+                    expression.collect_assigned_variables(list, context);
+                }
+            },
+            ParsedVarPathRoot::MatchBlock(match_block) => match_block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::IfBlock(if_block) => if_block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::IterFields(iter_fields) => iter_fields.block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::IterVariants(iter_variants) => iter_variants.block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::IterAncestors(iter_ancestors) => iter_ancestors.block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::WhileBlock(while_block) => while_block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::ForBlock(for_block) => for_block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::Block(block) => block.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::NoneLiteral(_) => {},
+            ParsedVarPathRoot::BooleanLiteral(_) => {},
+            ParsedVarPathRoot::NumberLiteral(_) => {},
+            ParsedVarPathRoot::CharLiteral(_) => {},
+            ParsedVarPathRoot::StringLiteral(_) => {},
+            ParsedVarPathRoot::TemplateString(template_string) => template_string.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::ArrayLiteral(array_literal) => array_literal.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::ObjectLiteral(object_literal) => object_literal.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::StaticFieldOrMethod(_) => {},
+            ParsedVarPathRoot::ColorLiteral(_) => {},
+            // an anonymous function is not executed where it is declared
+            ParsedVarPathRoot::FunctionLiteral(_) => {},
+            ParsedVarPathRoot::Parenthesized(expr) => expr.collect_assigned_variables(list, context),
+            ParsedVarPathRoot::PrefixedVarRef(_) => {},
+            ParsedVarPathRoot::VarRef(_) => {},
+        }
+    }
+
     pub fn collect_instancied_type_names(&self, list: &mut Vec<String>, context: &mut ProgramContext) {
         match self {
             ParsedVarPathRoot::LoadDirective(_) => {},
@@ -105,8 +183,8 @@ impl ParsedVarPathRoot {
             ParsedVarPathRoot::IterFields(iter_fields) => iter_fields.process(context),
             ParsedVarPathRoot::IterVariants(iter_variants) => iter_variants.process(context),
             ParsedVarPathRoot::IterAncestors(iter_ancestors) => iter_ancestors.process(context),
-            ParsedVarPathRoot::WhileBlock(while_block) => while_block.process(context),
-            ParsedVarPathRoot::ForBlock(for_block) => for_block.process(context),
+            ParsedVarPathRoot::WhileBlock(while_block) => while_block.process(type_hint, context),
+            ParsedVarPathRoot::ForBlock(for_block) => for_block.process(type_hint, context),
             ParsedVarPathRoot::MatchBlock(match_block) => match_block.process(type_hint, context),
             ParsedVarPathRoot::Parenthesized(expr) => expr.process(type_hint, context),
             ParsedVarPathRoot::PrefixedVarRef(prefixed_var_ref) => prefixed_var_ref.process(type_hint, access_type, context),
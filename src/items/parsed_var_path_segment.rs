@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use parsable::parsable;
-use crate::{program::{AccessType, FieldKind, ProgramContext, Type, Vasm}};
+use crate::{program::{AccessType, FieldKind, ProgramContext, Type, VariableInfo, Vasm}};
 use super::{ParsedArgumentList, ParsedBracketIndexing, ParsedExpression, ParsedFieldOrMethodAccess, Identifier, ParsedFunctionCall};
 
 #[parsable]
@@ -14,6 +14,18 @@ pub enum ParsedVarPathSegment {
 }
 
 impl ParsedVarPathSegment {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        match self {
+            ParsedVarPathSegment::FieldOrMethodAccess(field_or_method_access) => {
+                if let Some(arguments) = &field_or_method_access.arguments {
+                    arguments.collect_assigned_variables(list, context);
+                }
+            },
+            ParsedVarPathSegment::BracketIndexing(bracket_indexing) => bracket_indexing.index_expr.collect_assigned_variables(list, context),
+            ParsedVarPathSegment::FunctionCall(function_call) => function_call.arguments.collect_assigned_variables(list, context),
+        }
+    }
+
     pub fn has_side_effects(&self) -> bool {
         match self {
             ParsedVarPathSegment::FieldOrMethodAccess(var_ref) => var_ref.has_side_effects(),
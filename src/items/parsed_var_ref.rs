@@ -1,10 +1,11 @@
 use parsable::{ItemLocation, parsable};
 use colored::*;
-use crate::{items::{ParsedObjectLiteral, ParsedTypeSingle, ParsedTypeWithoutSuffix, ParsedValueType, ParsedTypeArguments, process_field_access, process_function_call, process_method_call}, program::{AccessType, AnonymousFunctionCallDetails, BuiltinInterface, FieldKind, FunctionCall, NamedFunctionCallDetails, ProgramContext, SELF_VAR_NAME, Type, VariableKind, Vasm, TypeContent}};
-use super::{ParsedArgumentList, ParsedFieldOrMethodAccess, ParsedType, Identifier, ParsedVarPrefixToken, ParsedVarPrefix, ParsedIdentifierWrapper, instanciate_object};
+use crate::{items::{ParsedObjectLiteral, ParsedTypeSingle, ParsedTypeWithoutSuffix, ParsedValueType, ParsedTypeArguments, process_field_access, process_function_call, process_method_call}, program::{AccessType, AnonymousFunctionCallDetails, BuiltinInterface, FieldKind, FunctionCall, NamedFunctionCallDetails, ProgramContext, SELF_VAR_NAME, Type, VariableInfo, VariableKind, Vasm, TypeContent}, language_server::FieldCompletionOptions};
+use super::{ParsedArgumentList, ParsedFieldOrMethodAccess, ParsedType, Identifier, ParsedVarPrefixToken, ParsedVarPrefix, ParsedIdentifierWrapper, ParsedNamespaceSegment, instanciate_object, instanciate_type, process_qualified_type};
 
 #[parsable]
 pub struct ParsedVarRef {
+    pub namespace: Vec<ParsedNamespaceSegment>,
     pub name: ParsedIdentifierWrapper,
     pub arguments: Option<ParsedArgumentList>
 }
@@ -20,10 +21,23 @@ impl ParsedVarRef {
         }
     }
 
+    pub fn get_var_info(&self, context: &ProgramContext) -> Option<VariableInfo> {
+        match (&self.name, &self.arguments) {
+            (ParsedIdentifierWrapper::Identifier(name), None) if self.namespace.is_empty() => context.find_var_info(name.as_str()),
+            _ => None
+        }
+    }
+
     pub fn process(&self, type_hint: Option<&Type>, access_type: AccessType, context: &mut ProgramContext) -> Option<Vasm> {
         let current_function_level = Some(context.get_function_level());
         let var_name = self.name.process(context)?;
 
+        if !self.namespace.is_empty() {
+            let namespace : Vec<&Identifier> = self.namespace.iter().map(|segment| &segment.name).collect();
+
+            return process_qualified_item(&namespace, &var_name, self.arguments.as_ref(), type_hint, access_type, context);
+        }
+
         context.add_variable_completion_area(&var_name.location, self.arguments.is_none(), type_hint.clone());
 
         match &self.arguments {
@@ -53,6 +67,8 @@ impl ParsedVarRef {
                 },
                 None => match context.functions.get_by_identifier(&var_name) {
                     Some(function_blueprint) => {
+                        context.mark_function_as_used(&function_blueprint);
+
                         let function_call = FunctionCall::Named(NamedFunctionCallDetails {
                             caller_type: None,
                             function: function_blueprint.clone(),
@@ -72,28 +88,36 @@ impl ParsedVarRef {
                     AccessType::Get => Some(context.vasm()
                         .get_var(&var_info, current_function_level)
                         .set_type(var_info.ty().clone())
+                        .set_optional(var_info.is_optional() && !context.is_variable_narrowed(&var_info))
                     ),
                     AccessType::Set(location) => Some(context.vasm()
                         .set_var(&var_info, current_function_level, context.vasm().placeholder(location))
                         .set_type(var_info.ty().clone())
+                        .set_optional(var_info.is_optional())
                     ),
                 },
                 None => match context.functions.get_by_identifier(&var_name) {
-                    Some(function_wrapped) => function_wrapped.with_ref(|function_unwrapped| {
-                        match function_unwrapped.parameters.is_empty() {
-                            true => Some(context.vasm()
-                                .function_index(&function_wrapped, &[])
-                                .set_type(Type::function(&function_unwrapped.signature))
-                            ),
-                            false => {
-                                context.errors.generic(&var_name, format!("cannot use functions with parameters as variables for now"));
-                                None
-                            },
-                        }
-                    }),
+                    Some(function_wrapped) => {
+                        context.mark_function_as_used(&function_wrapped);
+
+                        function_wrapped.with_ref(|function_unwrapped| {
+                            match function_unwrapped.parameters.is_empty() {
+                                true => Some(context.vasm()
+                                    .function_index(&function_wrapped, &[])
+                                    .set_type(Type::function(&function_unwrapped.signature))
+                                ),
+                                false => {
+                                    context.errors.generic(&var_name, format!("cannot use functions with parameters as variables for now"));
+                                    None
+                                },
+                            }
+                        })
+                    },
                     None => match context.types.get_by_identifier(&var_name) {
                         Some(type_wrapped) => match type_wrapped.borrow().is_class() {
                             true => {
+                                context.mark_type_as_used(&type_wrapped);
+
                                 let location = var_name.location.clone();
                                 let mut parsed_type_value = ParsedValueType::default();
                                 parsed_type_value.name = var_name.into_owned();
@@ -124,4 +148,106 @@ impl ParsedVarRef {
             },
         }
     }
+}
+
+// `dir::item` refers to an item of a namespace, `dir::Type::item` to a static field or method of a type of a namespace
+pub fn process_qualified_item(namespace: &[&Identifier], name: &Identifier, arguments: Option<&ParsedArgumentList>, type_hint: Option<&Type>, access_type: AccessType, context: &mut ProgramContext) -> Option<Vasm> {
+    let current_function_level = Some(context.get_function_level());
+    let mut value_type = ParsedValueType::default();
+
+    if let Some(import) = context.get_qualified_import(namespace, name) {
+        if let Some(function_wrapped) = context.functions.get_by_import(&import, true) {
+            context.add_qualified_name_completion_areas(namespace, name);
+            context.mark_function_as_used(&function_wrapped);
+
+            return match arguments {
+                Some(args) => {
+                    let function_call = FunctionCall::Named(NamedFunctionCallDetails {
+                        caller_type: None,
+                        function: function_wrapped.clone(),
+                        parameters: vec![],
+                    });
+
+                    process_function_call(Some(name), function_call, args, type_hint, access_type, context)
+                },
+                None => function_wrapped.with_ref(|function_unwrapped| {
+                    match function_unwrapped.parameters.is_empty() {
+                        true => Some(context.vasm()
+                            .function_index(&function_wrapped, &[])
+                            .set_type(Type::function(&function_unwrapped.signature))
+                        ),
+                        false => {
+                            context.errors.generic(name, format!("cannot use functions with parameters as variables for now"));
+                            None
+                        },
+                    }
+                }),
+            };
+        }
+
+        if let Some(var_info) = context.access_global_var(name, &import) {
+            context.add_qualified_name_completion_areas(namespace, name);
+
+            if arguments.is_some() {
+                context.errors.generic(name, format!("expected function, got `{}`", var_info.ty()));
+                return None;
+            }
+
+            return match access_type {
+                AccessType::Get => Some(context.vasm()
+                    .get_var(&var_info, current_function_level)
+                    .set_type(var_info.ty().clone())
+                    .set_optional(var_info.is_optional())
+                ),
+                AccessType::Set(location) => Some(context.vasm()
+                    .set_var(&var_info, current_function_level, context.vasm().placeholder(location))
+                    .set_type(var_info.ty().clone())
+                    .set_optional(var_info.is_optional())
+                ),
+            };
+        }
+
+        if let Some(type_wrapped) = context.types.get_by_import(&import, true) {
+            context.add_qualified_name_completion_areas(namespace, name);
+
+            if !type_wrapped.borrow().is_class() || arguments.is_some() {
+                context.errors.generic(name, format!("undefined variable `{}`", name.as_str().bold()));
+                return None;
+            }
+
+            value_type.name = name.clone();
+            value_type.location = name.location.clone();
+
+            let object_type = value_type.process_in_namespace(Some(&import), true, type_hint, context)?;
+
+            return Some(instanciate_type(&object_type, &name.location, &[], context));
+        }
+
+        if context.has_directory(&import.package_root_path, &import.directories) {
+            context.add_qualified_name_completion_areas(namespace, name);
+            context.errors.generic(name, format!("undefined item `{}`", name.as_str().bold()));
+            return None;
+        }
+    }
+
+    let (type_name, type_namespace) = namespace.split_last()?;
+
+    value_type.name = (*type_name).clone();
+    value_type.location = type_name.location.clone();
+
+    let ty = match type_namespace.is_empty() {
+        true => value_type.process(true, type_hint, context)?,
+        false => process_qualified_type(type_namespace, &value_type, true, type_hint, context)?,
+    };
+
+    context.completion_provider.add_static_field_completion(name, &ty, type_hint, Some(&FieldCompletionOptions {
+        show_methods: true,
+        insert_arguments: arguments.is_none(),
+        ..Default::default()
+    }));
+
+    match arguments {
+        Some(args) => process_method_call(&ty, FieldKind::Static, name, &[], args, type_hint, access_type, context),
+        None => process_field_access(&ty, FieldKind::Static, name, access_type, context),
+    }
 }
\ No newline at end of file
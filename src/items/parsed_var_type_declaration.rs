@@ -9,6 +9,13 @@ pub struct ParsedVarTypeDeclaration {
 }
 
 impl ParsedVarTypeDeclaration {
+    pub fn is_option(&self) -> bool {
+        match &self.ty {
+            Some(ty) => ty.is_option(),
+            None => false,
+        }
+    }
+
     pub fn process(&self, context: &mut ProgramContext) -> Option<Type> {
         let ty = unwrap_item(&self.ty, self, context)?;
 
@@ -1,23 +1,43 @@
 use parsable::{create_token_struct, parsable};
-use crate::{program::{ProgramContext, ScopeKind, Type, Vasm, WHILE_KEYWORD}, wat};
-use super::ParsedBranch;
+use crate::{program::{ProgramContext, Type, VariableInfo, Vasm, WHILE_KEYWORD}, wat};
+use super::{ParsedBranch, ParsedLoopLabel, wrap_loop_result};
 
 create_token_struct!(WhileKeyword, WHILE_KEYWORD);
 
 #[parsable]
 pub struct ParsedWhileBlock {
+    #[parsable(suffix=":")]
+    pub label: Option<ParsedLoopLabel>,
     pub while_keyword: WhileKeyword,
     pub while_branch: ParsedBranch
 }
 
 impl ParsedWhileBlock {
-    pub fn process(&self, context: &mut ProgramContext) -> Option<Vasm> {
+    pub fn collect_assigned_variables(&self, list: &mut Vec<VariableInfo>, context: &ProgramContext) {
+        self.while_branch.collect_assigned_variables(list, context);
+    }
+
+    pub fn process(&self, type_hint: Option<&Type>, context: &mut ProgramContext) -> Option<Vasm> {
         let mut result = context.vasm().set_void(context);
         let void_type = context.void_type();
 
-        context.push_scope(ScopeKind::Loop);
+        let mut assigned_variables = vec![];
+
+        let is_value_discarded = type_hint.map(|ty| ty.is_void()).unwrap_or(false);
+
+        context.push_loop_scope(self.label.as_ref().map(|label| label.get_name()), is_value_discarded);
+        self.while_branch.collect_assigned_variables(&mut assigned_variables, context);
+        context.suspend_narrowings(&assigned_variables);
+
+        let condition_vasm = self.while_branch.process_condition(context);
+        let narrowed_variables = self.while_branch.get_narrowed_variables(true, context);
+
+        // the condition is checked before each iteration
+        context.narrow_variables(&narrowed_variables);
+
+        let block_vasm = self.while_branch.process_body(Some(&void_type), context);
 
-        if let (Some(condition_vasm), Some(block_vasm)) = (self.while_branch.process_condition(context), self.while_branch.process_body(Some(&void_type), context)) {
+        if let (Some(condition_vasm), Some(block_vasm)) = (condition_vasm, block_vasm) {
             if !block_vasm.ty.is_void() {
                 context.errors.generic(&self, format!("expected `{}`, got `{}`", context.void_type(), &block_vasm.ty));
             }
@@ -30,6 +50,7 @@ impl ParsedWhileBlock {
                     .jump(0)
                 )
             );
+            result = wrap_loop_result(result, context);
         }
 
         context.pop_scope();
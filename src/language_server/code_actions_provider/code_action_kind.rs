@@ -26,4 +26,21 @@ impl ToString for CodeActionKind {
             CodeActionKind::SourceOrganizeImports => "source-organize-imports",
         }.to_string()
     }
+}
+
+impl CodeActionKind {
+    // https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#codeActionKind
+    pub fn get_lsp_kind(&self) -> &'static str {
+        match self {
+            CodeActionKind::Empty => "",
+            CodeActionKind::QuickFix => "quickfix",
+            CodeActionKind::Refactor => "refactor",
+            CodeActionKind::RefactorExtract => "refactor.extract",
+            CodeActionKind::RefactorInline => "refactor.inline",
+            CodeActionKind::RefactorRewrite => "refactor.rewrite",
+            CodeActionKind::Source => "source",
+            CodeActionKind::SourceFixAll => "source.fixAll",
+            CodeActionKind::SourceOrganizeImports => "source.organizeImports",
+        }
+    }
 }
\ No newline at end of file
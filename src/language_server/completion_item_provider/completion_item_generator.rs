@@ -3,7 +3,7 @@ use std::borrow::Borrow;
 use enum_iterator::all;
 use parsable::{ItemLocation, Parsable};
 use crate::{program::{Type, InterfaceBlueprint, VariableInfo, GlobalVarBlueprint, TypeBlueprint, TypedefBlueprint, FunctionBlueprint, FieldKind, SELF_TYPE_NAME, BuiltinType, NONE_LITERAL, EXPRESSION_KEYWORDS}, utils::Link, items::{ParsedBooleanLiteralToken, ParsedActionKeywordToken}};
-use super::{CompletionItem, CompletionItemList, FieldCompletionOptions};
+use super::{CompletionItem, CompletionItemList, CompletionItemKind, FieldCompletionOptions};
 
 #[derive(Debug)]
 pub enum CompletionItemGenerator {
@@ -15,7 +15,9 @@ pub enum CompletionItemGenerator {
     Type(TypeCompletionDetails),
     Variable(VariableCompletionDetails),
     MatchItem(MatchItemCompletionDetails),
-    Enum(Type)
+    Namespace(NamespaceCompletionDetails),
+    Enum(Type),
+    Label(Vec<String>)
 }
 
 #[derive(Debug)]
@@ -29,6 +31,12 @@ pub struct InterfaceCompletionDetails {
     pub available_types: Vec<Type>,
 }
 
+#[derive(Debug)]
+pub struct NamespaceCompletionDetails {
+    pub available_namespaces: Vec<String>,
+    pub available_items: Vec<(String, CompletionItemKind)>,
+}
+
 #[derive(Debug)]
 pub struct MatchItemCompletionDetails {
     pub matched_type: Type,
@@ -251,10 +259,24 @@ impl CompletionItemGenerator {
 
                 items.add_literal(NONE_LITERAL);
             },
+            Self::Namespace(details) => {
+                for namespace in &details.available_namespaces {
+                    items.add(namespace).kind(CompletionItemKind::Module);
+                }
+
+                for (name, kind) in &details.available_items {
+                    items.add(name).kind(*kind);
+                }
+            },
             Self::Enum(enum_type) => {
                 for variant in enum_type.get_all_variants() {
                     items.add_enum_variant(variant.clone(), None, false)
                 }
+            },
+            Self::Label(available_labels) => {
+                for label in available_labels {
+                    items.add_label(label);
+                }
             }
         }
 
@@ -63,4 +63,74 @@ impl ToString for CompletionItemKind {
             CompletionItemKind::Variable => "variable",
         }.to_string()
     }
+}
+
+impl CompletionItemKind {
+    // https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#completionItemKind
+    pub fn get_lsp_kind(&self) -> u32 {
+        match self {
+            CompletionItemKind::Text => 1,
+            CompletionItemKind::Method => 2,
+            CompletionItemKind::Function => 3,
+            CompletionItemKind::Constructor => 4,
+            CompletionItemKind::Field => 5,
+            CompletionItemKind::Variable => 6,
+            CompletionItemKind::Class => 7,
+            CompletionItemKind::Interface => 8,
+            CompletionItemKind::Module => 9,
+            CompletionItemKind::Property => 10,
+            CompletionItemKind::Unit => 11,
+            CompletionItemKind::Value => 12,
+            CompletionItemKind::Enum => 13,
+            CompletionItemKind::Keyword => 14,
+            CompletionItemKind::Snippet => 15,
+            CompletionItemKind::Color => 16,
+            CompletionItemKind::File => 17,
+            CompletionItemKind::Reference => 18,
+            CompletionItemKind::Folder => 19,
+            CompletionItemKind::EnumMember => 20,
+            CompletionItemKind::Constant => 21,
+            CompletionItemKind::Struct => 22,
+            CompletionItemKind::Event => 23,
+            CompletionItemKind::Operator => 24,
+            CompletionItemKind::TypeParameter => 25,
+            // no LSP equivalent
+            CompletionItemKind::Issue => 1,
+            CompletionItemKind::User => 1,
+        }
+    }
+
+    // https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#symbolKind
+    pub fn get_lsp_symbol_kind(&self) -> u32 {
+        match self {
+            CompletionItemKind::File => 1,
+            CompletionItemKind::Module => 2,
+            CompletionItemKind::Class => 5,
+            CompletionItemKind::Method => 6,
+            CompletionItemKind::Property => 7,
+            CompletionItemKind::Field => 8,
+            CompletionItemKind::Constructor => 9,
+            CompletionItemKind::Enum => 10,
+            CompletionItemKind::Interface => 11,
+            CompletionItemKind::Function => 12,
+            CompletionItemKind::Variable => 13,
+            CompletionItemKind::Constant => 14,
+            CompletionItemKind::Text => 15,
+            CompletionItemKind::Unit => 16,
+            CompletionItemKind::Keyword => 20,
+            CompletionItemKind::EnumMember => 22,
+            CompletionItemKind::Struct => 23,
+            CompletionItemKind::Event => 24,
+            CompletionItemKind::Operator => 25,
+            CompletionItemKind::TypeParameter => 26,
+            // no LSP equivalent
+            CompletionItemKind::Color => 13,
+            CompletionItemKind::Folder => 1,
+            CompletionItemKind::Issue => 13,
+            CompletionItemKind::Reference => 13,
+            CompletionItemKind::Snippet => 13,
+            CompletionItemKind::User => 13,
+            CompletionItemKind::Value => 13,
+        }
+    }
 }
\ No newline at end of file
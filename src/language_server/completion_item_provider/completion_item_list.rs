@@ -155,6 +155,14 @@ impl CompletionItemList {
             .kind(CompletionItemKind::EnumMember);
     }
 
+    pub fn add_label(&mut self, label_name: &str) {
+        self
+            .add(format!("'{}", label_name))
+            .position(CompletionItemPosition::PublicVariable)
+            .kind(CompletionItemKind::Reference)
+            .filter_text(label_name.to_string());
+    }
+
     pub fn add_keyword(&mut self, keyword: &str) {
         self
             .add(keyword)
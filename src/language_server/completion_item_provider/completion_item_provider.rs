@@ -69,6 +69,12 @@ impl CompletionItemProvider {
         })
     }
 
+    pub fn add_label_completion(&mut self, location: &ItemLocation, available_labels: Vec<String>) {
+        self.add_completion(location, || {
+            CompletionItemGenerator::Label(available_labels)
+        })
+    }
+
     pub fn get_completion_items(&self) -> Vec<CompletionItem> {
         match self.completion_item_generators.last() {
             Some(generator) => generator.generate(&self.cursor.location.as_ref().unwrap()),
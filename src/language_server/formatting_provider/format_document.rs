@@ -0,0 +1,24 @@
+use crate::{program::ProgramContext, formatter::format_parsed_source_file, language_server::{LanguageServerCommandParameters, LanguageServerCommandOutput}};
+
+pub fn format_document(parameters: &LanguageServerCommandParameters, context: &ProgramContext, output: &mut LanguageServerCommandOutput) {
+    let file_path = match &context.options.cursor_location {
+        Some(cursor_location) => &cursor_location.file_path,
+        None => return,
+    };
+
+    // files that cannot be parsed are not in the list, and are not formatted
+    if let Some(source_file) = context.parsed_source_files.iter().find(|source_file| &source_file.location.file.path == file_path) {
+        let content = &source_file.location.file.content;
+
+        if let Ok(formatted) = format_parsed_source_file(source_file) {
+            if &formatted != content {
+                output
+                    .line("replace")
+                    .push(file_path)
+                    .push(0)
+                    .push(content.len())
+                    .push(formatted);
+            }
+        }
+    }
+}
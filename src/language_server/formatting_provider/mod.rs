@@ -0,0 +1,3 @@
+mod format_document;
+
+pub use format_document::*;
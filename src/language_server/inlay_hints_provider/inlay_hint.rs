@@ -0,0 +1,10 @@
+use parsable::ItemLocation;
+use super::InlayHintKind;
+
+#[derive(Debug, Clone)]
+pub struct InlayHint {
+    // Empty location at which the hint is displayed
+    pub location: ItemLocation,
+    pub kind: InlayHintKind,
+    pub label: String
+}
\ No newline at end of file
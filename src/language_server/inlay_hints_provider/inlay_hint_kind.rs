@@ -0,0 +1,22 @@
+// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#inlayHintKind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InlayHintKind {
+    Type,
+    Parameter,
+}
+
+impl InlayHintKind {
+    pub fn get_lsp_kind(&self) -> u32 {
+        match self {
+            InlayHintKind::Type => 1,
+            InlayHintKind::Parameter => 2,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            InlayHintKind::Type => "type",
+            InlayHintKind::Parameter => "parameter",
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use indexmap::IndexMap;
+use parsable::ItemLocation;
+use crate::program::{Cursor, Type};
+use super::{InlayHint, InlayHintKind};
+
+pub struct InlayHintsProvider {
+    pub cursor: Cursor,
+    hints: IndexMap<(usize, InlayHintKind), InlayHint>
+}
+
+impl InlayHintsProvider {
+    pub fn new(cursor: &Cursor) -> Self {
+        Self {
+            cursor: cursor.clone(),
+            hints: IndexMap::new(),
+        }
+    }
+
+    // Only the hints of the file under the cursor are recorded
+    fn is_in_cursor_file(&self, location: &ItemLocation) -> bool {
+        match &self.cursor.location {
+            Some(cursor_location) => !location.is_empty() && location.file.path == cursor_location.file.path,
+            None => false,
+        }
+    }
+
+    pub fn add_type_hint(&mut self, name: &ItemLocation, ty: &Type) {
+        if ty.is_undefined() {
+            return;
+        }
+
+        self.insert(name.get_end(), InlayHintKind::Type, format!(": {}", ty));
+    }
+
+    pub fn add_parameter_hint(&mut self, argument: &ItemLocation, parameter_name: &str) {
+        // `f(count)` does not need to be displayed as `f(count: count)`
+        if argument.as_str() == parameter_name {
+            return;
+        }
+
+        self.insert(argument.set_start_with_offset(0), InlayHintKind::Parameter, format!("{}:", parameter_name));
+    }
+
+    fn insert(&mut self, location: ItemLocation, kind: InlayHintKind, label: String) {
+        if !self.is_in_cursor_file(&location) {
+            return;
+        }
+
+        self.hints
+            .entry((location.start, kind))
+            .or_insert_with(|| InlayHint { location, kind, label });
+    }
+
+    pub fn get_hints(&self, start: usize, end: usize) -> Vec<InlayHint> {
+        let mut hints : Vec<InlayHint> = self.hints.values()
+            .filter(|hint| hint.location.start >= start && hint.location.start <= end)
+            .cloned()
+            .collect();
+
+        hints.sort_by_key(|hint| hint.location.start);
+
+        hints
+    }
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+mod inlay_hints_provider;
+mod inlay_hint;
+mod inlay_hint_kind;
+mod provide_inlay_hints;
+
+pub use inlay_hints_provider::*;
+pub use inlay_hint::*;
+pub use inlay_hint_kind::*;
+pub use provide_inlay_hints::*;
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::{program::ProgramContext, language_server::{LanguageServerCommandParameters, LanguageServerCommandOutput}};
+
+pub fn provide_inlay_hints(parameters: &LanguageServerCommandParameters, context: &ProgramContext, output: &mut LanguageServerCommandOutput) {
+    for hint in context.inlay_hints_provider.get_hints(parameters.range_start, parameters.range_end) {
+        output
+            .line("hint")
+            .push(hint.location.start)
+            .push(hint.kind.get_name())
+            .push(&hint.label);
+    }
+}
\ No newline at end of file
@@ -25,11 +25,19 @@ impl LanguageServerCommand {
         let cursor_index = arguments.next().and_then(|str| str.parse::<usize>().ok()).unwrap_or(usize::MAX);
         let file_content = arguments.next().and_then(|str| Some(str.to_string())).unwrap_or_default();
         let new_name = arguments.next().and_then(|str| Some(str.to_string())).unwrap_or_default();
-        let package = Package::from_path(&file_path);
+        let include_declaration = arguments.next().map(|str| str == "include-declaration").unwrap_or(false);
+        let query = arguments.next().and_then(|str| Some(str.to_string())).unwrap_or_default();
+        let range_start = arguments.next().and_then(|str| str.parse::<usize>().ok()).unwrap_or(0);
+        let range_end = arguments.next().and_then(|str| str.parse::<usize>().ok()).unwrap_or(usize::MAX);
+        let package = Package::try_from_path(&file_path)?;
 
         let duration = 0;
         let parameters = LanguageServerCommandParameters {
-            new_name
+            new_name,
+            include_declaration,
+            query,
+            range_start,
+            range_end,
         };
 
         Some(Self {
@@ -43,16 +51,32 @@ impl LanguageServerCommand {
         })
     }
 
-    pub fn run(mut self, mut cache: Option<&mut FileSystemCache<ParsedSourceFile, ParseError>>) -> String {
+    pub fn run(mut self, cache: Option<&mut FileSystemCache<ParsedSourceFile, ParseError>>) -> String {
         let callback = self.kind.get_callback();
+        let mut timer = PerfTimer::new();
+        let context = self.create_context(cache, &mut timer);
+        let mut output = LanguageServerCommandOutput::new(self.id);
+
+        timer.trigger("cleanup");
+        callback(&self.parameters, &context, &mut output);
+
+        context.destroy();
+
+        // let header = format!("{}ms", timer.get_total());
+        let header = timer.to_string(", ", 0);
+
+        output.format(Some(header))
+    }
+
+    // Parses and processes the package of the file, the content of the command taking precedence over the one on the disk
+    pub fn create_context(&mut self, mut cache: Option<&mut FileSystemCache<ParsedSourceFile, ParseError>>, timer: &mut PerfTimer) -> ProgramContext {
         let options = ProgramContextOptions {
             package: self.package.clone(),
             mode: ProgramContextMode::Validate,
             cursor_location: Some(CursorLocation::new(self.package.src_path.to_str().unwrap(), &self.file_path, self.cursor_index)),
+            use_parse_cache: false,
         };
-        let mut timer = PerfTimer::new();
         let mut context = ProgramContext::new(options);
-        let mut output = LanguageServerCommandOutput::new(self.id);
 
         if let Some(cache) = &mut cache {
             cache.delete_hook();
@@ -70,14 +94,6 @@ impl LanguageServerCommand {
             context.process_source_files();
         }
 
-        timer.trigger("cleanup");
-        callback(&self.parameters, &context, &mut output);
-
-        context.destroy();
-
-        // let header = format!("{}ms", timer.get_total());
-        let header = timer.to_string(", ", 0);
-
-        output.format(Some(header))
+        context
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{program::ProgramContext, command_line::CommandLineOptions, language_server::{validate, prepare_rename, provide_rename_edits, provide_definition, provide_hover, provide_completion_items, provide_signature_help, provide_code_actions}};
+use crate::{program::ProgramContext, command_line::CommandLineOptions, language_server::{validate, prepare_rename, provide_rename_edits, provide_definition, provide_hover, provide_completion_items, provide_signature_help, provide_code_actions, format_document, provide_references, provide_document_symbols, provide_workspace_symbols, provide_semantic_tokens, provide_inlay_hints}};
 use super::{LanguageServerCommandParameters, LanguageServerCommandOutput, LanguageServerCommandReload};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -6,11 +6,17 @@ pub enum LanguageServerCommandKind {
     Validate,
     PrepareRename,
     ProvideRenameEdits,
+    ProvideReferences,
     ProvideDefinition,
     ProvideHover,
     ProvideCompletionItems,
     ProvideSignatureHelp,
     ProvideCodeActions,
+    FormatDocument,
+    ProvideDocumentSymbols,
+    ProvideWorkspaceSymbols,
+    ProvideSemanticTokens,
+    ProvideInlayHints,
 }
 
 pub type LanguageServerCommandCallback = fn(&LanguageServerCommandParameters, &ProgramContext, &mut LanguageServerCommandOutput);
@@ -21,11 +27,17 @@ impl LanguageServerCommandKind {
             "validate" => Some(Self::Validate),
             "prepare-rename" => Some(Self::PrepareRename),
             "provide-rename-edits" => Some(Self::ProvideRenameEdits),
+            "provide-references" => Some(Self::ProvideReferences),
             "provide-definition" => Some(Self::ProvideDefinition),
             "provide-hover" => Some(Self::ProvideHover),
             "provide-completion-items" => Some(Self::ProvideCompletionItems),
             "provide-signature-help" => Some(Self::ProvideSignatureHelp),
             "provide-code-actions" => Some(Self::ProvideCodeActions),
+            "format-document" => Some(Self::FormatDocument),
+            "provide-document-symbols" => Some(Self::ProvideDocumentSymbols),
+            "provide-workspace-symbols" => Some(Self::ProvideWorkspaceSymbols),
+            "provide-semantic-tokens" => Some(Self::ProvideSemanticTokens),
+            "provide-inlay-hints" => Some(Self::ProvideInlayHints),
             _ => None
         }
     }
@@ -35,11 +47,17 @@ impl LanguageServerCommandKind {
             LanguageServerCommandKind::Validate => validate,
             LanguageServerCommandKind::PrepareRename => prepare_rename,
             LanguageServerCommandKind::ProvideRenameEdits => provide_rename_edits,
+            LanguageServerCommandKind::ProvideReferences => provide_references,
             LanguageServerCommandKind::ProvideDefinition => provide_definition,
             LanguageServerCommandKind::ProvideHover => provide_hover,
             LanguageServerCommandKind::ProvideCompletionItems => provide_completion_items,
             LanguageServerCommandKind::ProvideSignatureHelp => provide_signature_help,
             LanguageServerCommandKind::ProvideCodeActions => provide_code_actions,
+            LanguageServerCommandKind::FormatDocument => format_document,
+            LanguageServerCommandKind::ProvideDocumentSymbols => provide_document_symbols,
+            LanguageServerCommandKind::ProvideWorkspaceSymbols => provide_workspace_symbols,
+            LanguageServerCommandKind::ProvideSemanticTokens => provide_semantic_tokens,
+            LanguageServerCommandKind::ProvideInlayHints => provide_inlay_hints,
         }
     }
 }
\ No newline at end of file
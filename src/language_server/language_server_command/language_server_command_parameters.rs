@@ -1,6 +1,11 @@
 use parsable::ItemLocation;
 use crate::program::{ProgramContext};
 
+#[derive(Default)]
 pub struct LanguageServerCommandParameters {
     pub new_name: String,
+    pub include_declaration: bool,
+    pub query: String,
+    pub range_start: usize,
+    pub range_end: usize,
 }
\ No newline at end of file
@@ -0,0 +1,23 @@
+// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#errorCodes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LspErrorCode {
+    ParseError = -32700,
+    InvalidRequest = -32600,
+    MethodNotFound = -32601,
+    InvalidParams = -32602,
+    ServerNotInitialized = -32002,
+}
+
+pub struct LspError {
+    pub code: LspErrorCode,
+    pub message: String,
+}
+
+impl LspError {
+    pub fn new<S : ToString>(code: LspErrorCode, message: S) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
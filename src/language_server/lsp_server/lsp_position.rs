@@ -0,0 +1,46 @@
+use parsable::ItemLocation;
+use serde_json::{json, Value};
+
+// The compiler works with byte offsets, while LSP positions are made of a line and a number of UTF-16 code units
+pub fn offset_to_lsp_position(content: &str, offset: usize) -> Value {
+    let mut offset = offset.min(content.len());
+
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let line_start = content[..offset].rfind('\n').map(|index| index + 1).unwrap_or(0);
+    let line = content[..line_start].matches('\n').count();
+    let character : usize = content[line_start..offset].chars().map(|c| c.len_utf16()).sum();
+
+    json!({ "line": line, "character": character })
+}
+
+pub fn lsp_position_to_offset(content: &str, position: &Value) -> usize {
+    let line = position["line"].as_u64().unwrap_or(0) as usize;
+    let character = position["character"].as_u64().unwrap_or(0) as usize;
+    let line_start = content.split_inclusive('\n').take(line).map(|line_content| line_content.len()).sum::<usize>();
+    let line_content = content[line_start..].split('\n').next().unwrap_or_default();
+    let mut offset = line_start;
+    let mut utf16_count = 0;
+
+    for c in line_content.chars() {
+        if utf16_count >= character {
+            break;
+        }
+
+        utf16_count += c.len_utf16();
+        offset += c.len_utf8();
+    }
+
+    offset
+}
+
+pub fn location_to_lsp_range(location: &ItemLocation) -> Value {
+    let content = &location.file.content;
+
+    json!({
+        "start": offset_to_lsp_position(content, location.start),
+        "end": offset_to_lsp_position(content, location.end),
+    })
+}
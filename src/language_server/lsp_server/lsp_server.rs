@@ -0,0 +1,620 @@
+use std::{collections::{HashMap, HashSet}, fs, io::{self, Stdout}, process};
+use indexmap::IndexMap;
+use parsable::ParseError;
+use serde_json::{json, Value};
+use crate::{program::{ProgramContext, Severity}, utils::{FileSystemCache, PerfTimer, is_valid_identifier}, items::ParsedSourceFile, package::Package, formatter::format_source_file, language_server::{LanguageServerCommand, LanguageServerCommandKind, LanguageServerCommandParameters, WorkspaceEdit, DocumentSymbol, get_document_symbols, get_workspace_symbols, SEMANTIC_TOKEN_KINDS, SEMANTIC_TOKEN_MODIFIERS, InlayHintKind}};
+use super::{LspError, LspErrorCode, read_lsp_message, write_lsp_message, uri_to_file_path, file_path_to_uri, offset_to_lsp_position, lsp_position_to_offset, location_to_lsp_range};
+
+const SERVER_NAME : &'static str = "lotus-compiler";
+const DIAGNOSTIC_SOURCE : &'static str = "lotus";
+
+pub fn start_lsp_server() {
+    let stdin = io::stdin();
+    let mut reader = stdin.lock();
+    let mut server = LspServer::new();
+
+    // the standard output is the channel with the client, it must not contain escape codes
+    colored::control::set_override(false);
+
+    while let Some(message) = read_lsp_message(&mut reader) {
+        match message {
+            Ok(message) => server.handle_message(message),
+            Err(error) => server.send_response(Value::Null, Err(LspError::new(LspErrorCode::ParseError, error))),
+        }
+
+        if let Some(exit_code) = server.exit_code {
+            process::exit(exit_code);
+        }
+    }
+}
+
+pub struct LspServer {
+    output: Stdout,
+    cache: FileSystemCache<ParsedSourceFile, ParseError>,
+    root_path: Option<String>,
+    documents: HashMap<String, String>,
+    files_with_diagnostics: HashSet<String>,
+    initialized: bool,
+    shutdown_requested: bool,
+    exit_code: Option<i32>,
+}
+
+impl LspServer {
+    pub fn new() -> Self {
+        Self {
+            output: io::stdout(),
+            cache: FileSystemCache::new(),
+            root_path: None,
+            documents: HashMap::new(),
+            files_with_diagnostics: HashSet::new(),
+            initialized: false,
+            shutdown_requested: false,
+            exit_code: None,
+        }
+    }
+
+    pub fn handle_message(&mut self, message: Value) {
+        let method = match message["method"].as_str() {
+            Some(method) => method.to_string(),
+            None => return, // response to a request of the server
+        };
+        let params = &message["params"];
+
+        match message.get("id") {
+            Some(id) => {
+                let result = match (self.initialized, self.shutdown_requested) {
+                    (_, true) => Err(LspError::new(LspErrorCode::InvalidRequest, "the server is shutting down")),
+                    (false, false) if method != "initialize" => Err(LspError::new(LspErrorCode::ServerNotInitialized, "the server is not initialized")),
+                    _ => self.handle_request(&method, params),
+                };
+
+                self.send_response(id.clone(), result);
+            },
+            None => match method.as_str() {
+                "exit" => self.exit_code = Some(match self.shutdown_requested {
+                    true => 0,
+                    false => 1,
+                }),
+                _ if !self.initialized || self.shutdown_requested => {},
+                _ => self.handle_notification(&method, params),
+            },
+        }
+    }
+
+    fn handle_request(&mut self, method: &str, params: &Value) -> Result<Value, LspError> {
+        match method {
+            "initialize" => self.initialize(params),
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Ok(Value::Null)
+            },
+            "textDocument/hover" => self.provide_hover(params),
+            "textDocument/definition" => self.provide_definition(params),
+            "textDocument/prepareRename" => self.prepare_rename(params),
+            "textDocument/rename" => self.provide_rename_edits(params),
+            "textDocument/references" => self.provide_references(params),
+            "textDocument/completion" => self.provide_completion_items(params),
+            "textDocument/signatureHelp" => self.provide_signature_help(params),
+            "textDocument/codeAction" => self.provide_code_actions(params),
+            "textDocument/formatting" => self.format_document(params),
+            "textDocument/documentSymbol" => self.provide_document_symbols(params),
+            "workspace/symbol" => self.provide_workspace_symbols(params),
+            "textDocument/semanticTokens/full" => self.provide_semantic_tokens(params),
+            "textDocument/inlayHint" => self.provide_inlay_hints(params),
+            _ => Err(LspError::new(LspErrorCode::MethodNotFound, format!("unsupported method `{}`", method))),
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: &Value) {
+        let file_path = match get_document_path(params) {
+            Ok(file_path) => file_path,
+            Err(_) => return,
+        };
+
+        match method {
+            "textDocument/didOpen" => {
+                let content = params["textDocument"]["text"].as_str().unwrap_or_default().to_string();
+
+                self.documents.insert(file_path.clone(), content);
+                self.publish_diagnostics(&file_path);
+            },
+            "textDocument/didChange" => {
+                // the server only supports full document synchronization, so the last change contains the whole document
+                if let Some(change) = params["contentChanges"].as_array().and_then(|changes| changes.last()) {
+                    let content = change["text"].as_str().unwrap_or_default().to_string();
+
+                    self.documents.insert(file_path.clone(), content);
+                    self.publish_diagnostics(&file_path);
+                }
+            },
+            "textDocument/didSave" => self.publish_diagnostics(&file_path),
+            "textDocument/didClose" => {
+                self.documents.remove(&file_path);
+            },
+            _ => {},
+        }
+    }
+
+    fn initialize(&mut self, params: &Value) -> Result<Value, LspError> {
+        self.initialized = true;
+        self.root_path = params["rootUri"].as_str()
+            .or_else(|| params["workspaceFolders"][0]["uri"].as_str())
+            .and_then(uri_to_file_path);
+
+        Ok(json!({
+            "capabilities": {
+                "textDocumentSync": {
+                    "openClose": true,
+                    "change": 1,
+                    "save": true,
+                },
+                "completionProvider": {
+                    "triggerCharacters": [".", ":", "@"],
+                },
+                "hoverProvider": true,
+                "definitionProvider": true,
+                "referencesProvider": true,
+                "renameProvider": {
+                    "prepareProvider": true,
+                },
+                "signatureHelpProvider": {
+                    "triggerCharacters": ["(", ","],
+                },
+                "codeActionProvider": true,
+                "documentFormattingProvider": true,
+                "documentSymbolProvider": true,
+                "workspaceSymbolProvider": true,
+                "semanticTokensProvider": {
+                    "legend": {
+                        "tokenTypes": SEMANTIC_TOKEN_KINDS.iter().map(|kind| kind.get_name()).collect::<Vec<&str>>(),
+                        "tokenModifiers": SEMANTIC_TOKEN_MODIFIERS.iter().map(|modifier| modifier.get_name()).collect::<Vec<&str>>(),
+                    },
+                    "full": true,
+                },
+                "inlayHintProvider": true,
+            },
+            "serverInfo": {
+                "name": SERVER_NAME,
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+        }))
+    }
+
+    fn get_document_content(&self, file_path: &str) -> String {
+        match self.documents.get(file_path) {
+            Some(content) => content.clone(),
+            None => fs::read_to_string(file_path).unwrap_or_default(),
+        }
+    }
+
+    // Processes the package of the file, with the cursor at the specified index of the document
+    fn with_context<T : Default, F : FnOnce(&ProgramContext) -> T>(&mut self, file_path: &str, cursor_index: usize, callback: F) -> T {
+        // the client can send any path: nothing is provided for a file that is not inside a package
+        let package = match Package::try_from_path(file_path) {
+            Some(package) => package,
+            None => return T::default(),
+        };
+        let mut command = LanguageServerCommand {
+            id: 0,
+            kind: LanguageServerCommandKind::Validate,
+            package,
+            file_path: file_path.to_string(),
+            cursor_index,
+            file_content: self.get_document_content(file_path),
+            parameters: LanguageServerCommandParameters::default(),
+        };
+        let context = command.create_context(Some(&mut self.cache), &mut PerfTimer::new());
+        let result = callback(&context);
+
+        context.destroy();
+
+        result
+    }
+
+    fn with_context_at_position<T : Default, F : FnOnce(&ProgramContext) -> T>(&mut self, params: &Value, callback: F) -> Result<T, LspError> {
+        let file_path = get_document_path(params)?;
+        let content = self.get_document_content(&file_path);
+        let cursor_index = lsp_position_to_offset(&content, &params["position"]);
+
+        Ok(self.with_context(&file_path, cursor_index, callback))
+    }
+
+    fn publish_diagnostics(&mut self, file_path: &str) {
+        let mut file_diagnostics : IndexMap<String, Vec<Value>> = IndexMap::new();
+
+        self.with_context(file_path, usize::MAX, |context| {
+            for error in context.errors.get_warnings().iter().chain(context.errors.get_all()) {
+                if let Some(message) = error.get_message() {
+                    let severity = match error.severity {
+                        Severity::Error => 1,
+                        Severity::Warning => 2,
+                    };
+                    let diagnostic = json!({
+                        "range": location_to_lsp_range(&error.location),
+                        "severity": severity,
+                        "source": DIAGNOSTIC_SOURCE,
+                        "message": message,
+                    });
+                    let diagnostics = file_diagnostics.entry(error.location.file.path.clone()).or_default();
+
+                    if !diagnostics.contains(&diagnostic) {
+                        diagnostics.push(diagnostic);
+                    }
+                }
+            }
+        });
+
+        // files that no longer have diagnostics must be cleared
+        for previous_file_path in &self.files_with_diagnostics {
+            if !file_diagnostics.contains_key(previous_file_path) {
+                file_diagnostics.insert(previous_file_path.clone(), vec![]);
+            }
+        }
+
+        if !file_diagnostics.contains_key(file_path) {
+            file_diagnostics.insert(file_path.to_string(), vec![]);
+        }
+
+        self.files_with_diagnostics.clear();
+
+        for (path, diagnostics) in file_diagnostics {
+            if !diagnostics.is_empty() {
+                self.files_with_diagnostics.insert(path.clone());
+            }
+
+            self.send_notification("textDocument/publishDiagnostics", json!({
+                "uri": file_path_to_uri(&path),
+                "diagnostics": diagnostics,
+            }));
+        }
+    }
+
+    fn provide_hover(&mut self, params: &Value) -> Result<Value, LspError> {
+        self.with_context_at_position(params, |context| {
+            match context.hover_provider.get_hover() {
+                Some(hover) => match &hover.ty {
+                    Some(ty) => json!({
+                        "contents": {
+                            "kind": "markdown",
+                            "value": format!("```lotus\n{}\n```", ty.to_string()),
+                        },
+                        "range": location_to_lsp_range(&hover.location),
+                    }),
+                    None => Value::Null,
+                },
+                None => Value::Null,
+            }
+        })
+    }
+
+    fn provide_definition(&mut self, params: &Value) -> Result<Value, LspError> {
+        self.with_context_at_position(params, |context| {
+            match context.definition_provider.get_definition() {
+                Some(definition) => json!({
+                    "uri": file_path_to_uri(&definition.target_location.file.path),
+                    "range": location_to_lsp_range(&definition.target_location),
+                }),
+                None => Value::Null,
+            }
+        })
+    }
+
+    fn prepare_rename(&mut self, params: &Value) -> Result<Value, LspError> {
+        self.with_context_at_position(params, |context| {
+            match context.rename_provider.get_shared_name() {
+                Some((_, occurence)) => json!({
+                    "range": location_to_lsp_range(occurence),
+                    "placeholder": occurence.as_str(),
+                }),
+                None => Value::Null,
+            }
+        })
+    }
+
+    fn provide_rename_edits(&mut self, params: &Value) -> Result<Value, LspError> {
+        let new_name = params["newName"].as_str().unwrap_or_default();
+
+        if !is_valid_identifier(new_name) {
+            return Err(LspError::new(LspErrorCode::InvalidParams, format!("`{}` is not a valid identifier", new_name)));
+        }
+
+        self.with_context_at_position(params, |context| {
+            match context.rename_provider.get_shared_name() {
+                Some((shared_name, _)) => {
+                    let mut changes : IndexMap<String, Vec<Value>> = IndexMap::new();
+
+                    for occurence in &shared_name.occurences {
+                        changes.entry(file_path_to_uri(&occurence.file.path)).or_default().push(json!({
+                            "range": location_to_lsp_range(occurence),
+                            "newText": new_name,
+                        }));
+                    }
+
+                    changes_to_json(changes)
+                },
+                None => Value::Null,
+            }
+        })
+    }
+
+    fn provide_references(&mut self, params: &Value) -> Result<Value, LspError> {
+        let include_declaration = params["context"]["includeDeclaration"].as_bool().unwrap_or(false);
+
+        self.with_context_at_position(params, |context| {
+            match context.rename_provider.get_shared_name() {
+                Some((shared_name, _)) => {
+                    let locations : Vec<Value> = shared_name.get_references(include_declaration).into_iter()
+                        .map(|reference| json!({
+                            "uri": file_path_to_uri(&reference.file.path),
+                            "range": location_to_lsp_range(reference),
+                        }))
+                        .collect();
+
+                    json!(locations)
+                },
+                None => Value::Null,
+            }
+        })
+    }
+
+    fn provide_completion_items(&mut self, params: &Value) -> Result<Value, LspError> {
+        self.with_context_at_position(params, |context| {
+            let mut items = vec![];
+
+            for item in context.completion_provider.get_completion_items() {
+                let position = item.position.map(|position| position as u32).unwrap_or(0);
+                let mut lsp_item = json!({
+                    "label": item.label,
+                    "sortText": format!("{:02}{}", position, item.sort_text.as_ref().unwrap_or(&item.label)),
+                });
+
+                if let Some(kind) = &item.kind {
+                    lsp_item["kind"] = json!(kind.get_lsp_kind());
+                }
+
+                if let Some(description) = item.description {
+                    lsp_item["labelDetails"] = json!({ "description": description });
+                }
+
+                if let Some(detail) = item.detail {
+                    lsp_item["detail"] = json!(detail);
+                }
+
+                if let Some(documentation) = item.documentation {
+                    lsp_item["documentation"] = json!(documentation);
+                }
+
+                // insert texts are snippets (e.g `$0` marks the final position of the cursor)
+                if let Some(insert_text) = item.insert_text {
+                    lsp_item["insertText"] = json!(insert_text);
+                    lsp_item["insertTextFormat"] = json!(2);
+                }
+
+                if let Some(filter_text) = item.filter_text {
+                    lsp_item["filterText"] = json!(filter_text);
+                }
+
+                items.push(lsp_item);
+            }
+
+            json!({
+                "isIncomplete": false,
+                "items": items,
+            })
+        })
+    }
+
+    fn provide_signature_help(&mut self, params: &Value) -> Result<Value, LspError> {
+        self.with_context_at_position(params, |context| {
+            match context.signature_help_provider.get_signature_help() {
+                Some(signature_help) => {
+                    let (label, argument_ranges) = signature_help.get_label();
+                    let parameters : Vec<Value> = argument_ranges.into_iter()
+                        .map(|(start, end)| json!({ "label": [start, end] }))
+                        .collect();
+
+                    json!({
+                        "signatures": [{
+                            "label": label,
+                            "parameters": parameters,
+                        }],
+                        "activeSignature": 0,
+                        "activeParameter": signature_help.active_argument_index,
+                    })
+                },
+                None => Value::Null,
+            }
+        })
+    }
+
+    fn provide_code_actions(&mut self, params: &Value) -> Result<Value, LspError> {
+        let params = json!({
+            "textDocument": params["textDocument"],
+            "position": params["range"]["start"],
+        });
+
+        self.with_context_at_position(&params, |context| {
+            let code_actions : Vec<Value> = context.code_actions_provider.get_code_actions().into_iter()
+                .map(|code_action| json!({
+                    "title": code_action.title,
+                    "kind": code_action.kind.get_lsp_kind(),
+                    "edit": workspace_edit_to_json(&code_action.workspace_edit),
+                }))
+                .collect();
+
+            json!(code_actions)
+        })
+    }
+
+    fn format_document(&mut self, params: &Value) -> Result<Value, LspError> {
+        let file_path = get_document_path(params)?;
+        let content = self.get_document_content(&file_path);
+
+        // files that cannot be parsed are not formatted
+        let edits = match format_source_file(&content, &file_path) {
+            Ok(formatted) if formatted != content => json!([{
+                "range": {
+                    "start": offset_to_lsp_position(&content, 0),
+                    "end": offset_to_lsp_position(&content, content.len()),
+                },
+                "newText": formatted,
+            }]),
+            _ => json!([]),
+        };
+
+        Ok(edits)
+    }
+
+    fn provide_document_symbols(&mut self, params: &Value) -> Result<Value, LspError> {
+        let file_path = get_document_path(params)?;
+
+        Ok(self.with_context(&file_path, usize::MAX, |context| {
+            let symbols : Vec<Value> = context.parsed_source_files.iter()
+                .find(|source_file| source_file.location.file.path == file_path)
+                .map(|source_file| get_document_symbols(source_file).iter().map(document_symbol_to_json).collect())
+                .unwrap_or_default();
+
+            json!(symbols)
+        }))
+    }
+
+    fn provide_workspace_symbols(&mut self, params: &Value) -> Result<Value, LspError> {
+        let query = params["query"].as_str().unwrap_or_default();
+        // the package is the one of the workspace, or the one of any open document
+        let file_path = match self.root_path.clone().or_else(|| self.documents.keys().next().cloned()) {
+            Some(file_path) => file_path,
+            None => return Ok(json!([])),
+        };
+
+        Ok(self.with_context(&file_path, usize::MAX, |context| {
+            let symbols : Vec<Value> = get_workspace_symbols(context, query).into_iter()
+                .map(|symbol| json!({
+                    "name": symbol.name,
+                    "kind": symbol.kind.get_lsp_symbol_kind(),
+                    "location": {
+                        "uri": file_path_to_uri(&symbol.location.file.path),
+                        "range": location_to_lsp_range(&symbol.location),
+                    },
+                }))
+                .collect();
+
+            json!(symbols)
+        }))
+    }
+
+    fn provide_semantic_tokens(&mut self, params: &Value) -> Result<Value, LspError> {
+        let file_path = get_document_path(params)?;
+        let content = self.get_document_content(&file_path);
+
+        Ok(self.with_context(&file_path, usize::MAX, |context| {
+            let mut data : Vec<u32> = vec![];
+            let mut previous_line = 0;
+            let mut previous_character = 0;
+
+            // each token is encoded relatively to the previous one: line delta, start delta (if on the same line), length, kind, modifiers
+            for token in context.semantic_tokens_provider.get_tokens() {
+                let position = offset_to_lsp_position(&content, token.location.start);
+                let line = position["line"].as_u64().unwrap_or(0) as u32;
+                let character = position["character"].as_u64().unwrap_or(0) as u32;
+                let length : usize = token.location.as_str().chars().map(|c| c.len_utf16()).sum();
+                let delta_character = match line == previous_line {
+                    true => character - previous_character,
+                    false => character,
+                };
+
+                data.extend([line - previous_line, delta_character, length as u32, token.kind.get_index(), token.get_modifier_bits()]);
+                previous_line = line;
+                previous_character = character;
+            }
+
+            json!({ "data": data })
+        }))
+    }
+
+    fn provide_inlay_hints(&mut self, params: &Value) -> Result<Value, LspError> {
+        let file_path = get_document_path(params)?;
+        let content = self.get_document_content(&file_path);
+        let range_start = lsp_position_to_offset(&content, &params["range"]["start"]);
+        let range_end = lsp_position_to_offset(&content, &params["range"]["end"]);
+
+        Ok(self.with_context(&file_path, usize::MAX, |context| {
+            let hints : Vec<Value> = context.inlay_hints_provider.get_hints(range_start, range_end).into_iter()
+                .map(|hint| json!({
+                    "position": offset_to_lsp_position(&content, hint.location.start),
+                    "label": hint.label,
+                    "kind": hint.kind.get_lsp_kind(),
+                    "paddingRight": hint.kind == InlayHintKind::Parameter,
+                }))
+                .collect();
+
+            json!(hints)
+        }))
+    }
+
+    fn send_response(&mut self, id: Value, result: Result<Value, LspError>) {
+        let message = match result {
+            Ok(result) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "result": result,
+            }),
+            Err(error) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": {
+                    "code": error.code as i32,
+                    "message": error.message,
+                },
+            }),
+        };
+
+        write_lsp_message(&mut self.output, &message);
+    }
+
+    fn send_notification(&mut self, method: &str, params: Value) {
+        write_lsp_message(&mut self.output, &json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params,
+        }));
+    }
+}
+
+fn get_document_path(params: &Value) -> Result<String, LspError> {
+    params["textDocument"]["uri"].as_str()
+        .and_then(uri_to_file_path)
+        .ok_or_else(|| LspError::new(LspErrorCode::InvalidParams, "missing or invalid document uri"))
+}
+
+fn document_symbol_to_json(symbol: &DocumentSymbol) -> Value {
+    let children : Vec<Value> = symbol.children.iter().map(document_symbol_to_json).collect();
+
+    json!({
+        "name": symbol.name,
+        "kind": symbol.kind.get_lsp_symbol_kind(),
+        "range": location_to_lsp_range(&symbol.location),
+        "selectionRange": location_to_lsp_range(&symbol.name_location),
+        "children": children,
+    })
+}
+
+fn workspace_edit_to_json(workspace_edit: &WorkspaceEdit) -> Value {
+    let mut changes : IndexMap<String, Vec<Value>> = IndexMap::new();
+
+    for text_edit in &workspace_edit.text_edits {
+        changes.entry(file_path_to_uri(&text_edit.edit_location.file.path)).or_default().push(json!({
+            "range": location_to_lsp_range(&text_edit.edit_location),
+            "newText": text_edit.replacement_text,
+        }));
+    }
+
+    changes_to_json(changes)
+}
+
+fn changes_to_json(changes: IndexMap<String, Vec<Value>>) -> Value {
+    let changes : serde_json::Map<String, Value> = changes.into_iter()
+        .map(|(uri, edits)| (uri, Value::Array(edits)))
+        .collect();
+
+    json!({ "changes": changes })
+}
@@ -0,0 +1,44 @@
+use std::io::{BufRead, Write};
+use serde_json::Value;
+
+const CONTENT_LENGTH_HEADER : &'static str = "content-length:";
+
+// Reads the next message, framed by a `Content-Length` header. Returns `None` once the input is closed
+pub fn read_lsp_message<R : BufRead>(reader: &mut R) -> Option<Result<Value, serde_json::Error>> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line).ok()? == 0 {
+            return None;
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+
+            continue;
+        }
+
+        if line.to_ascii_lowercase().starts_with(CONTENT_LENGTH_HEADER) {
+            content_length = line[CONTENT_LENGTH_HEADER.len()..].trim().parse::<usize>().ok();
+        }
+    }
+
+    let mut content = vec![0; content_length.unwrap()];
+
+    reader.read_exact(&mut content).ok()?;
+
+    Some(serde_json::from_slice(&content))
+}
+
+pub fn write_lsp_message<W : Write>(writer: &mut W, message: &Value) {
+    let content = message.to_string();
+
+    write!(writer, "Content-Length: {}\r\n\r\n{}", content.len(), content).unwrap();
+    writer.flush().unwrap();
+}
@@ -0,0 +1,53 @@
+const FILE_URI_PREFIX : &'static str = "file://";
+
+pub fn uri_to_file_path(uri: &str) -> Option<String> {
+    let path = uri.strip_prefix(FILE_URI_PREFIX)?;
+    let bytes = path.as_bytes();
+    let mut result = vec![];
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let decoded = match bytes[index] {
+            b'%' => path.get(index + 1..index + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+            _ => None,
+        };
+
+        match decoded {
+            Some(byte) => {
+                result.push(byte);
+                index += 3;
+            },
+            None => {
+                result.push(bytes[index]);
+                index += 1;
+            },
+        }
+    }
+
+    let mut file_path = String::from_utf8(result).ok()?;
+
+    // `file:///C:/dir` on Windows
+    if file_path.as_bytes().get(2) == Some(&b':') {
+        file_path.remove(0);
+    }
+
+    Some(file_path)
+}
+
+pub fn file_path_to_uri(file_path: &str) -> String {
+    let mut result = FILE_URI_PREFIX.to_string();
+
+    if !file_path.starts_with('/') {
+        result.push('/');
+    }
+
+    for byte in file_path.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => result.push(byte as char),
+            b'\\' => result.push('/'),
+            _ => result.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    result
+}
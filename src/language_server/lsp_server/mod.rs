@@ -0,0 +1,11 @@
+mod lsp_server;
+mod lsp_transport;
+mod lsp_position;
+mod lsp_uri;
+mod lsp_error;
+
+pub use lsp_server::*;
+pub use lsp_transport::*;
+pub use lsp_position::*;
+pub use lsp_uri::*;
+pub use lsp_error::*;
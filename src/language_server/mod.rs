@@ -5,10 +5,16 @@ mod workspace_edit;
 mod validation_provider;
 mod completion_item_provider;
 mod rename_provider;
+mod references_provider;
 mod hover_provider;
 mod signature_help_provider;
 mod code_actions_provider;
 mod definition_provider;
+mod formatting_provider;
+mod symbols_provider;
+mod semantic_tokens_provider;
+mod inlay_hints_provider;
+mod lsp_server;
 
 pub use utils::*;
 pub use language_server::*;
@@ -17,7 +23,13 @@ pub use validation_provider::*;
 pub use workspace_edit::*;
 pub use completion_item_provider::*;
 pub use rename_provider::*;
+pub use references_provider::*;
 pub use hover_provider::*;
 pub use signature_help_provider::*;
 pub use code_actions_provider::*;
-pub use definition_provider::*;
\ No newline at end of file
+pub use definition_provider::*;
+pub use formatting_provider::*;
+pub use symbols_provider::*;
+pub use semantic_tokens_provider::*;
+pub use inlay_hints_provider::*;
+pub use lsp_server::*;
\ No newline at end of file
@@ -0,0 +1,3 @@
+mod provide_references;
+
+pub use provide_references::*;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use crate::{program::ProgramContext, language_server::{LanguageServerCommandParameters, LanguageServerCommandOutput}};
+
+pub fn provide_references(parameters: &LanguageServerCommandParameters, context: &ProgramContext, output: &mut LanguageServerCommandOutput) {
+    if let Some((shared_name, _)) = context.rename_provider.get_shared_name() {
+        for reference in shared_name.get_references(parameters.include_declaration) {
+            output
+                .line("reference")
+                .push(&reference.file.path)
+                .push(reference.start)
+                .push(reference.end);
+        }
+    }
+}
\ No newline at end of file
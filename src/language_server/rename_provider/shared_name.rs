@@ -18,4 +18,13 @@ impl SharedName {
     pub fn add_occurence(&mut self, occurence: &ItemLocation) {
         self.occurences.insert(occurence.clone());
     }
+
+    pub fn get_references(&self, include_declaration: bool) -> Vec<&ItemLocation> {
+        let mut references : Vec<&ItemLocation> = self.occurences.iter()
+            .filter(|occurence| include_declaration || *occurence != &self.definition)
+            .collect();
+
+        references.sort_by(|a, b| (&a.file.path, a.start).cmp(&(&b.file.path, b.start)));
+        references
+    }
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+mod semantic_tokens_provider;
+mod semantic_token;
+mod semantic_token_kind;
+mod semantic_token_modifier;
+mod provide_semantic_tokens;
+
+pub use semantic_tokens_provider::*;
+pub use semantic_token::*;
+pub use semantic_token_kind::*;
+pub use semantic_token_modifier::*;
+pub use provide_semantic_tokens::*;
\ No newline at end of file
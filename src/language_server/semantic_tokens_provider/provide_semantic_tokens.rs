@@ -0,0 +1,12 @@
+use crate::{program::ProgramContext, language_server::{LanguageServerCommandParameters, LanguageServerCommandOutput}};
+
+pub fn provide_semantic_tokens(parameters: &LanguageServerCommandParameters, context: &ProgramContext, output: &mut LanguageServerCommandOutput) {
+    for token in context.semantic_tokens_provider.get_tokens() {
+        output
+            .line("token")
+            .push(token.location.start)
+            .push(token.location.end)
+            .push(token.kind.get_name())
+            .push(token.get_modifier_names().join(","));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use parsable::ItemLocation;
+use super::{SemanticTokenKind, SemanticTokenModifier};
+
+#[derive(Debug, Clone)]
+pub struct SemanticToken {
+    pub location: ItemLocation,
+    pub kind: SemanticTokenKind,
+    pub modifiers: Vec<SemanticTokenModifier>,
+    pub definition: Option<ItemLocation>
+}
+
+impl SemanticToken {
+    pub fn get_modifier_bits(&self) -> u32 {
+        self.modifiers.iter().fold(0, |bits, modifier| bits | modifier.get_bit())
+    }
+
+    pub fn get_modifier_names(&self) -> Vec<&'static str> {
+        self.modifiers.iter().map(|modifier| modifier.get_name()).collect()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use crate::program::TypeCategory;
+
+// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#semanticTokenTypes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SemanticTokenKind {
+    Type,
+    Class,
+    Enum,
+    Interface,
+    Struct,
+    TypeParameter,
+    Parameter,
+    Variable,
+    Property,
+    EnumMember,
+    Event,
+    Function,
+    Method,
+    Macro,
+}
+
+pub const SEMANTIC_TOKEN_KINDS : &'static [SemanticTokenKind] = &[
+    SemanticTokenKind::Type,
+    SemanticTokenKind::Class,
+    SemanticTokenKind::Enum,
+    SemanticTokenKind::Interface,
+    SemanticTokenKind::Struct,
+    SemanticTokenKind::TypeParameter,
+    SemanticTokenKind::Parameter,
+    SemanticTokenKind::Variable,
+    SemanticTokenKind::Property,
+    SemanticTokenKind::EnumMember,
+    SemanticTokenKind::Event,
+    SemanticTokenKind::Function,
+    SemanticTokenKind::Method,
+    SemanticTokenKind::Macro,
+];
+
+impl SemanticTokenKind {
+    pub fn from_type_category(category: TypeCategory) -> Self {
+        match category {
+            TypeCategory::Type => SemanticTokenKind::Struct,
+            TypeCategory::Enum => SemanticTokenKind::Enum,
+            TypeCategory::Class => SemanticTokenKind::Class,
+        }
+    }
+
+    // Index of the kind in the legend sent to the client
+    pub fn get_index(&self) -> u32 {
+        *self as u32
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SemanticTokenKind::Type => "type",
+            SemanticTokenKind::Class => "class",
+            SemanticTokenKind::Enum => "enum",
+            SemanticTokenKind::Interface => "interface",
+            SemanticTokenKind::Struct => "struct",
+            SemanticTokenKind::TypeParameter => "typeParameter",
+            SemanticTokenKind::Parameter => "parameter",
+            SemanticTokenKind::Variable => "variable",
+            SemanticTokenKind::Property => "property",
+            SemanticTokenKind::EnumMember => "enumMember",
+            SemanticTokenKind::Event => "event",
+            SemanticTokenKind::Function => "function",
+            SemanticTokenKind::Method => "method",
+            SemanticTokenKind::Macro => "macro",
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+// https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#semanticTokenModifiers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SemanticTokenModifier {
+    Declaration,
+    Static,
+    Readonly,
+    DefaultLibrary,
+    // not standard
+    Dyn,
+    Autogen,
+}
+
+pub const SEMANTIC_TOKEN_MODIFIERS : &'static [SemanticTokenModifier] = &[
+    SemanticTokenModifier::Declaration,
+    SemanticTokenModifier::Static,
+    SemanticTokenModifier::Readonly,
+    SemanticTokenModifier::DefaultLibrary,
+    SemanticTokenModifier::Dyn,
+    SemanticTokenModifier::Autogen,
+];
+
+impl SemanticTokenModifier {
+    // Bit of the modifier in the set sent to the client
+    pub fn get_bit(&self) -> u32 {
+        1 << (*self as u32)
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SemanticTokenModifier::Declaration => "declaration",
+            SemanticTokenModifier::Static => "static",
+            SemanticTokenModifier::Readonly => "readonly",
+            SemanticTokenModifier::DefaultLibrary => "defaultLibrary",
+            SemanticTokenModifier::Dyn => "dyn",
+            SemanticTokenModifier::Autogen => "autogen",
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+use indexmap::IndexMap;
+use parsable::ItemLocation;
+use crate::program::{Cursor, FunctionBlueprint, MethodQualifier, VariableInfo, VariableKind};
+use super::{SemanticToken, SemanticTokenKind, SemanticTokenModifier};
+
+pub struct SemanticTokensProvider {
+    pub cursor: Cursor,
+    tokens: IndexMap<ItemLocation, SemanticToken>,
+    readonly_definitions: HashSet<ItemLocation>
+}
+
+impl SemanticTokensProvider {
+    pub fn new(cursor: &Cursor) -> Self {
+        Self {
+            cursor: cursor.clone(),
+            tokens: IndexMap::new(),
+            readonly_definitions: HashSet::new(),
+        }
+    }
+
+    // Only the tokens of the file under the cursor are recorded
+    fn is_in_cursor_file(&self, location: &ItemLocation) -> bool {
+        match &self.cursor.location {
+            Some(cursor_location) => !location.is_empty() && location.file.path == cursor_location.file.path,
+            None => false,
+        }
+    }
+
+    pub fn add_token(&mut self, location: &ItemLocation, kind: SemanticTokenKind, modifiers: &[SemanticTokenModifier]) {
+        self.insert(location, None, kind, modifiers);
+    }
+
+    pub fn add_occurence(&mut self, occurence: &ItemLocation, definition: &ItemLocation, kind: SemanticTokenKind, modifiers: &[SemanticTokenModifier]) {
+        self.insert(occurence, Some(definition), kind, modifiers);
+    }
+
+    pub fn add_variable_occurence(&mut self, occurence: &ItemLocation, var_info: &VariableInfo) {
+        let kind = match *var_info.kind() {
+            VariableKind::Argument => SemanticTokenKind::Parameter,
+            VariableKind::Global | VariableKind::Local => SemanticTokenKind::Variable,
+        };
+
+        self.add_occurence(occurence, &var_info.name(), kind, &[]);
+    }
+
+    pub fn add_function_occurence(&mut self, occurence: &ItemLocation, function_unwrapped: &FunctionBlueprint) {
+        let mut modifiers = vec![];
+        let kind = match function_unwrapped.owner_type.is_some() || function_unwrapped.owner_interface.is_some() {
+            true => SemanticTokenKind::Method,
+            false => SemanticTokenKind::Function,
+        };
+
+        if let Some(method_details) = &function_unwrapped.method_details {
+            match method_details.qualifier {
+                MethodQualifier::None => {},
+                MethodQualifier::Static => modifiers.push(SemanticTokenModifier::Static),
+                MethodQualifier::Dynamic => modifiers.push(SemanticTokenModifier::Dyn),
+            };
+
+            if method_details.is_autogen {
+                modifiers.push(SemanticTokenModifier::Autogen);
+            }
+        }
+
+        self.add_occurence(occurence, &function_unwrapped.name, kind, &modifiers);
+    }
+
+    pub fn add_readonly_definition(&mut self, definition: &ItemLocation) {
+        if self.is_in_cursor_file(definition) {
+            self.readonly_definitions.insert(definition.clone());
+        }
+    }
+
+    fn insert(&mut self, location: &ItemLocation, definition: Option<&ItemLocation>, kind: SemanticTokenKind, modifiers: &[SemanticTokenModifier]) {
+        if !self.is_in_cursor_file(location) || self.tokens.contains_key(location) {
+            return;
+        }
+
+        let mut modifiers = modifiers.to_vec();
+
+        if definition == Some(location) {
+            modifiers.insert(0, SemanticTokenModifier::Declaration);
+        }
+
+        self.tokens.insert(location.clone(), SemanticToken {
+            location: location.clone(),
+            kind,
+            modifiers,
+            definition: definition.cloned(),
+        });
+    }
+
+    // Returns the tokens sorted by position; when two tokens overlap, the one that starts first (or the longest) is kept
+    pub fn get_tokens(&self) -> Vec<SemanticToken> {
+        let mut tokens : Vec<SemanticToken> = self.tokens.values().cloned().collect();
+        let mut result : Vec<SemanticToken> = vec![];
+
+        tokens.sort_by_key(|token| (token.location.start, usize::MAX - token.location.end));
+
+        for mut token in tokens {
+            if result.last().is_some_and(|previous| previous.location.end > token.location.start) {
+                continue;
+            }
+
+            // a variable can be used before its declaration is processed, so this is resolved at the end
+            if token.definition.as_ref().is_some_and(|definition| self.readonly_definitions.contains(definition)) {
+                token.modifiers.push(SemanticTokenModifier::Readonly);
+            }
+
+            result.push(token);
+        }
+
+        result
+    }
+}
\ No newline at end of file
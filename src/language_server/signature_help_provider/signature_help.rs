@@ -1,5 +1,5 @@
 use parsable::ItemLocation;
-use crate::{program::{Signature, FunctionBlueprint, FunctionCall, Cursor}, utils::Link};
+use crate::{program::{Signature, FunctionBlueprint, FunctionCall, Cursor, VariableInfo, SELF_VAR_NAME}, utils::Link};
 
 pub struct SignatureHelp {
     pub location: ItemLocation,
@@ -44,4 +44,55 @@ impl SignatureHelp {
             panic!("attempting to assign argument with index {}, but only {} arguments where assigned before", index, self.argument_locations.len());
         }
     }
+
+    // Returns the label of the signature, and the range of each argument inside it
+    pub fn get_label(&self) -> (String, Vec<(usize, usize)>) {
+        let mut label = format!("fn {}(", &self.function_name);
+        let mut argument_ranges = vec![];
+        let mut return_type_string = String::new();
+
+        match &self.function_call {
+            FunctionCall::Named(details) => {
+                details.function.with_ref(|function_unwrapped| {
+                    let arguments : Vec<&VariableInfo> = function_unwrapped.argument_variables.iter().filter(|var| var.name().as_str() != SELF_VAR_NAME).collect();
+
+                    for (i, arg_info) in arguments.iter().enumerate() {
+                        let arg_str = format!("{}: {}", arg_info.name().as_str(), arg_info.with_ref(|info| info.ty.to_string()));
+
+                        argument_ranges.push((label.len(), label.len() + arg_str.len()));
+                        label.push_str(&arg_str);
+
+                        if i != arguments.len() - 1 {
+                            label.push_str(", ");
+                        }
+                    }
+
+                    if !function_unwrapped.signature.return_type.is_void() {
+                        return_type_string = format!(" -> {}", function_unwrapped.signature.return_type.to_string());
+                    }
+                });
+            },
+            FunctionCall::Anonymous(details) => {
+                for (i, ty) in details.signature.argument_types.iter().enumerate() {
+                    let arg_str = format!("{}", ty.to_string());
+
+                    argument_ranges.push((label.len(), label.len() + arg_str.len()));
+                    label.push_str(&arg_str);
+
+                    if i != details.signature.argument_types.len() - 1 {
+                        label.push_str(", ");
+                    }
+                }
+
+                if !details.signature.return_type.is_void() {
+                    return_type_string = format!(" -> {}", details.signature.return_type.to_string());
+                }
+            },
+        };
+
+        label.push_str(")");
+        label.push_str(&return_type_string);
+
+        (label, argument_ranges)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,137 @@
+use parsable::{ItemLocation, Parsable};
+use crate::{items::{ParsedSourceFile, ParsedTopLevelBlock, ParsedTypeDeclarationBodyItem, ParsedTypeQualifier, ParsedFieldDeclaration, ParsedVarDeclarationNamesContent, ParsedVarDeclarationQualifierToken, Identifier}, language_server::CompletionItemKind};
+
+pub struct DocumentSymbol {
+    pub name: String,
+    pub kind: CompletionItemKind,
+    // Location of the whole declaration
+    pub location: ItemLocation,
+    // Location of the name inside the declaration
+    pub name_location: ItemLocation,
+    pub children: Vec<DocumentSymbol>
+}
+
+impl DocumentSymbol {
+    pub fn new(name: &Identifier, kind: CompletionItemKind, location: &ItemLocation) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            location: location.clone(),
+            name_location: name.location.clone(),
+            children: vec![],
+        }
+    }
+
+    pub fn with_children(mut self, children: Vec<DocumentSymbol>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+// Lists the items declared in a file, from its syntax tree only (so the outline is available even if the package has errors)
+pub fn get_document_symbols(source_file: &ParsedSourceFile) -> Vec<DocumentSymbol> {
+    let mut result = vec![];
+
+    for block in &source_file.blocks {
+        match block {
+            ParsedTopLevelBlock::TypedefDeclaration(typedef_declaration) => {
+                result.push(DocumentSymbol::new(&typedef_declaration.name, CompletionItemKind::TypeParameter, &typedef_declaration.location));
+            },
+            ParsedTopLevelBlock::InterfaceDeclaration(interface_declaration) => {
+                let mut children = vec![];
+
+                if let Some(body) = &interface_declaration.body {
+                    for associated_type in &body.associated_types {
+                        children.push(DocumentSymbol::new(&associated_type.name, CompletionItemKind::TypeParameter, &associated_type.location));
+                    }
+
+                    for method in &body.methods {
+                        children.push(DocumentSymbol::new(&method.name, CompletionItemKind::Method, &method.location));
+                    }
+                }
+
+                result.push(DocumentSymbol::new(&interface_declaration.name, CompletionItemKind::Interface, &interface_declaration.location).with_children(children));
+            },
+            ParsedTopLevelBlock::TypeDeclaration(type_declaration) => {
+                let is_enum = type_declaration.qualifier == ParsedTypeQualifier::Enum;
+                let kind = match type_declaration.qualifier {
+                    ParsedTypeQualifier::Type => CompletionItemKind::Struct,
+                    ParsedTypeQualifier::Enum => CompletionItemKind::Enum,
+                    ParsedTypeQualifier::Class => CompletionItemKind::Class,
+                };
+                let mut children = vec![];
+                let items = match &type_declaration.body {
+                    Some(body) => body.items.as_slice(),
+                    None => &[],
+                };
+
+                for item in items {
+                    match item {
+                        ParsedTypeDeclarationBodyItem::EventCallbackDeclaration(event_callback) => {
+                            if let Some(name) = &event_callback.name {
+                                let mut symbol = DocumentSymbol::new(name, CompletionItemKind::Event, &event_callback.location);
+
+                                symbol.name = format!("@{}", name.as_str());
+                                children.push(symbol);
+                            }
+                        },
+                        ParsedTypeDeclarationBodyItem::AssociatedTypeDeclaration(associated_type) => {
+                            children.push(DocumentSymbol::new(&associated_type.name, CompletionItemKind::TypeParameter, &associated_type.location));
+                        },
+                        ParsedTypeDeclarationBodyItem::SuperFieldDefaultValue(_) => {},
+                        ParsedTypeDeclarationBodyItem::MethodDeclaration(method) => {
+                            children.push(DocumentSymbol::new(&method.content.name, CompletionItemKind::Method, &method.location));
+                        },
+                        ParsedTypeDeclarationBodyItem::EnumVariantDeclaration(variant) => {
+                            let fields = variant.fields.iter().map(|field| get_field_symbol(field, false)).collect();
+
+                            children.push(DocumentSymbol::new(&variant.name, CompletionItemKind::EnumMember, &variant.location).with_children(fields));
+                        },
+                        ParsedTypeDeclarationBodyItem::FieldDeclaration(field) => {
+                            children.push(get_field_symbol(field, is_enum));
+                        },
+                    }
+                }
+
+                result.push(DocumentSymbol::new(&type_declaration.name, kind, &type_declaration.location).with_children(children));
+            },
+            ParsedTopLevelBlock::FunctionDeclaration(function_declaration) => {
+                result.push(DocumentSymbol::new(&function_declaration.content.name, CompletionItemKind::Function, &function_declaration.location));
+            },
+            ParsedTopLevelBlock::GlobalDeclaration(global_declaration) => {
+                let var_declaration = &global_declaration.var_declaration;
+                let kind = match var_declaration.qualifier.token {
+                    ParsedVarDeclarationQualifierToken::Let => CompletionItemKind::Variable,
+                    ParsedVarDeclarationQualifierToken::Const => CompletionItemKind::Constant,
+                };
+                let names = match &var_declaration.var_names {
+                    Some(var_names) => match &var_names.content {
+                        ParsedVarDeclarationNamesContent::Single(name) => vec![name],
+                        ParsedVarDeclarationNamesContent::Multiple(names) => names.iter().collect(),
+                    },
+                    None => vec![],
+                };
+
+                for name in names {
+                    result.push(DocumentSymbol::new(name, kind, &global_declaration.location));
+                }
+            },
+            ParsedTopLevelBlock::RootTagDeclaration(_) => {},
+            ParsedTopLevelBlock::MainTypeDeclaration(_) => {},
+            ParsedTopLevelBlock::UseDeclaration(_) => {},
+            ParsedTopLevelBlock::InProgress(_) => {},
+        }
+    }
+
+    result
+}
+
+fn get_field_symbol(field: &ParsedFieldDeclaration, is_enum: bool) -> DocumentSymbol {
+    // in an enum, a field without type is a variant
+    let kind = match is_enum && field.ty.is_none() {
+        true => CompletionItemKind::EnumMember,
+        false => CompletionItemKind::Field,
+    };
+
+    DocumentSymbol::new(&field.name, kind, &field.location)
+}
@@ -0,0 +1,9 @@
+mod document_symbol;
+mod workspace_symbol;
+mod provide_document_symbols;
+mod provide_workspace_symbols;
+
+pub use document_symbol::*;
+pub use workspace_symbol::*;
+pub use provide_document_symbols::*;
+pub use provide_workspace_symbols::*;
\ No newline at end of file
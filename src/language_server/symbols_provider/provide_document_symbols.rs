@@ -0,0 +1,32 @@
+use crate::{program::ProgramContext, language_server::{LanguageServerCommandParameters, LanguageServerCommandOutput}};
+use super::{DocumentSymbol, get_document_symbols};
+
+pub fn provide_document_symbols(parameters: &LanguageServerCommandParameters, context: &ProgramContext, output: &mut LanguageServerCommandOutput) {
+    let file_path = match &context.options.cursor_location {
+        Some(cursor_location) => &cursor_location.file_path,
+        None => return,
+    };
+
+    if let Some(source_file) = context.parsed_source_files.iter().find(|source_file| &source_file.location.file.path == file_path) {
+        for symbol in get_document_symbols(source_file) {
+            output_symbol(&symbol, 0, output);
+        }
+    }
+}
+
+// Children are listed right after their parent, with a greater depth
+fn output_symbol(symbol: &DocumentSymbol, depth: u32, output: &mut LanguageServerCommandOutput) {
+    output
+        .line("symbol")
+        .push(&symbol.name)
+        .push(symbol.kind)
+        .push(depth)
+        .push(symbol.location.start)
+        .push(symbol.location.end)
+        .push(symbol.name_location.start)
+        .push(symbol.name_location.end);
+
+    for child in &symbol.children {
+        output_symbol(child, depth + 1, output);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use crate::{program::ProgramContext, language_server::{LanguageServerCommandParameters, LanguageServerCommandOutput}};
+use super::get_workspace_symbols;
+
+pub fn provide_workspace_symbols(parameters: &LanguageServerCommandParameters, context: &ProgramContext, output: &mut LanguageServerCommandOutput) {
+    for symbol in get_workspace_symbols(context, &parameters.query) {
+        output
+            .line("symbol")
+            .push(&symbol.name)
+            .push(symbol.kind)
+            .push(&symbol.location.file.path)
+            .push(symbol.location.start)
+            .push(symbol.location.end);
+    }
+}
\ No newline at end of file
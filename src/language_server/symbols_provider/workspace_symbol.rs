@@ -0,0 +1,52 @@
+use parsable::ItemLocation;
+use crate::{program::{ProgramContext, GlobalItem, GlobalItemIndex, TypeCategory}, utils::get_fuzzy_match_score, language_server::{CompletionItemKind, is_invalid_location}};
+
+pub struct WorkspaceSymbol {
+    pub name: String,
+    pub kind: CompletionItemKind,
+    pub location: ItemLocation,
+    score: u32,
+}
+
+// Searches the global items of the package, its dependencies and the prelude by name
+pub fn get_workspace_symbols(context: &ProgramContext, query: &str) -> Vec<WorkspaceSymbol> {
+    let mut result = vec![];
+
+    collect_symbols(&context.types, query, &mut result, |type_unwrapped| match type_unwrapped.category {
+        TypeCategory::Type => Some(CompletionItemKind::Struct),
+        TypeCategory::Enum => Some(CompletionItemKind::Enum),
+        TypeCategory::Class => Some(CompletionItemKind::Class),
+    });
+    collect_symbols(&context.typedefs, query, &mut result, |_| Some(CompletionItemKind::TypeParameter));
+    collect_symbols(&context.interfaces, query, &mut result, |_| Some(CompletionItemKind::Interface));
+    // the function index also contains the methods, which are not global
+    collect_symbols(&context.functions, query, &mut result, |function_unwrapped| match function_unwrapped.owner_type.is_none() && function_unwrapped.owner_interface.is_none() {
+        true => Some(CompletionItemKind::Function),
+        false => None,
+    });
+    collect_symbols(&context.global_vars, query, &mut result, |_| Some(CompletionItemKind::Variable));
+
+    result.sort_by(|a, b| (a.score, a.name.len(), &a.name).cmp(&(b.score, b.name.len(), &b.name)));
+    result
+}
+
+fn collect_symbols<V : GlobalItem, F : Fn(&V) -> Option<CompletionItemKind>>(index: &GlobalItemIndex<V>, query: &str, symbols: &mut Vec<WorkspaceSymbol>, get_kind: F) {
+    for item in index.items_by_id.values() {
+        item.with_ref(|item_unwrapped| {
+            let name = item_unwrapped.get_name();
+
+            if is_invalid_location(&name.location) {
+                return;
+            }
+
+            if let (Some(kind), Some(score)) = (get_kind(&item_unwrapped), get_fuzzy_match_score(query, name.as_str())) {
+                symbols.push(WorkspaceSymbol {
+                    name: name.to_string(),
+                    kind,
+                    location: name.location.clone(),
+                    score,
+                });
+            }
+        });
+    }
+}
\ No newline at end of file
@@ -9,7 +9,7 @@ pub fn validate(parameters: &LanguageServerCommandParameters, context: &ProgramC
         file_errors.insert(source_file.file_path.as_str(), vec![]);
     }
 
-    for error in context.errors.get_all() {
+    for error in context.errors.get_warnings().iter().chain(context.errors.get_all()) {
         file_errors.get_mut(error.location.file.path.as_str()).unwrap().push(error);
     }
 
@@ -21,7 +21,7 @@ pub fn validate(parameters: &LanguageServerCommandParameters, context: &ProgramC
         for error in errors {
             if let Some(message) = error.get_message() {
                 output
-                    .line("error")
+                    .line(error.severity.get_name())
                     .push(error.location.start)
                     .push(error.location.end)
                     .push(message);
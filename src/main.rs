@@ -1,12 +1,16 @@
 #![allow(unused)]
-use std::{env, process, fmt::Binary};
+use std::{env, process, fs, path::{Path, PathBuf}, fmt::Binary, thread::sleep, time::{Duration, SystemTime}};
 use colored::*;
-use command_line::{CommandLineOptions, LogLevel, Timer, ProgramStep};
+use command_line::{CommandLineOptions, CommandLineError, CommandKind, CompileOptions, BenchOptions, TestOptions, FmtOptions, LogLevel, MessageFormat, EmitKind, Timer, ProgramStep, display_help, get_usage_string};
 use indexmap::IndexSet;
-use language_server::start_language_server;
+use items::ParsedSourceFile;
+use parsable::ParseError;
+use formatter::{format_source_file, FormatError};
+use language_server::{start_language_server, start_lsp_server};
 use package::Package;
-use program::{ProgramContext, ProgramContextOptions, BinaryKind};
-use utils::FileSystemCache;
+use program::{ProgramContext, ProgramContextOptions, BinaryKind, CompilationError, CompilationErrorList, SOURCE_FILE_EXTENSION};
+use test_runner::{run_tests, execute_cli_module};
+use utils::{FileSystemCache, read_directory_recursively};
 
 use crate::program::ProgramContextMode;
 
@@ -16,111 +20,357 @@ mod items;
 mod command_line;
 mod package;
 mod language_server;
+mod test_runner;
+mod formatter;
 
 const PROGRAM_NAME : &'static str = "lotus-compiler";
+const USAGE_ERROR_EXIT_CODE : i32 = 2;
+const WATCH_POLL_INTERVAL_MS : u64 = 200;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    let options = CommandLineOptions::parse_from_args(args);
-
-    if options.run_benchmark {
-        let package = Package::from_path(options.input_path.as_ref().unwrap());
-        let mut cache = FileSystemCache::new();
-        let mut timer = Timer::new();
-
-        for i in 0..3 {
-            let duration = timer.time(ProgramStep::Total, || {
-                let mut context = ProgramContext::new(ProgramContextOptions {
-                    package: package.clone(),
-                    mode: ProgramContextMode::Validate,
-                    cursor_location: None,
-                });
-                context.parse_source_files(Some(&mut cache));
-                context.process_source_files();
+    let options = match CommandLineOptions::parse_from_args(args) {
+        Ok(options) => options,
+        Err(error) => display_error_and_exit(error),
+    };
+
+    match options {
+        CommandLineOptions::Build(options) => compile(options, false),
+        CommandLineOptions::Check(options) => compile(options, true),
+        CommandLineOptions::Run(options) => run(options),
+        CommandLineOptions::Lsp(options) => match options.stdio {
+            true => start_lsp_server(),
+            false => start_language_server(&options.command),
+        },
+        CommandLineOptions::Bench(options) => run_benchmark(options),
+        CommandLineOptions::Test(options) => test(options),
+        CommandLineOptions::Fmt(options) => format(options),
+        CommandLineOptions::Help(kind) => display_help(PROGRAM_NAME, kind),
+    }
+}
+
+fn run_benchmark(options: BenchOptions) {
+    let package = load_package(CommandKind::Bench, &options.input_path);
+    let mut cache = FileSystemCache::new();
+    let mut timer = Timer::new();
+
+    for i in 0..options.iterations {
+        let duration = timer.time(ProgramStep::Total, || {
+            let mut context = ProgramContext::new(ProgramContextOptions {
+                package: package.clone(),
+                mode: ProgramContextMode::Validate,
+                cursor_location: None,
+                use_parse_cache: false,
             });
+            context.parse_source_files(Some(&mut cache));
+            context.process_source_files();
+        });
+
+        println!("validation #{}: {} ms", i + 1, (duration * 1000.0).round());
+    }
+}
+
+fn test(options: TestOptions) {
+    if !run_tests(&options) {
+        process::exit(1);
+    }
+}
+
+fn format(options: FmtOptions) {
+    let mut file_paths : Vec<PathBuf> = read_directory_recursively(Path::new(&options.input_path))
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|extension| extension == SOURCE_FILE_EXTENSION))
+        .collect();
+    let mut success = true;
+
+    file_paths.sort();
+
+    for file_path in file_paths {
+        let file_path_string = file_path.to_string_lossy().to_string();
+        let content = match fs::read_to_string(&file_path) {
+            Ok(content) => content,
+            Err(error) => {
+                println!("{} cannot read `{}`: {}", "error:".red().bold(), file_path_string, error);
+                success = false;
+                continue;
+            },
+        };
 
-            println!("validation #{}: {} ms", i + 1, (duration * 1000.0).round());
+        match format_source_file(&content, &file_path_string) {
+            Ok(formatted) if formatted == content => {},
+            Ok(formatted) => match options.check {
+                true => {
+                    println!("{} {}", "unformatted:".yellow().bold(), file_path_string.bold());
+                    success = false;
+                },
+                false => {
+                    if let Err(error) = fs::write(&file_path, formatted) {
+                        println!("{} cannot write `{}`: {}", "error:".red().bold(), file_path_string, error);
+                        success = false;
+                    } else if !matches!(options.log_level, LogLevel::Silent) {
+                        println!("{} {}", "formatted:".blue().bold(), file_path_string.bold());
+                    }
+                },
+            },
+            Err(FormatError::Parse(parse_error)) => {
+                let mut errors = CompilationErrorList::new();
+
+                errors.parse_error(&parse_error);
+                display_diagnostics(errors.get_all(), MessageFormat::Human);
+                success = false;
+            },
+            Err(FormatError::Mismatch) => {
+                println!("{} cannot format `{}` without changing its meaning", "error:".red().bold(), file_path_string);
+                success = false;
+            },
         }
+    }
 
-    } else if options.run_as_server {
-        start_language_server(&options.command);
-    } else {
-        // dbg!(&options);
-        if let (Some(input_path), Some(output_path)) = (&options.input_path, &options.output_path) {
-            let mut package = Package::from_path(input_path);
-            let source_directories = package.get_source_directories();
-            let binary_kind = match options.app_mode {
-                true => BinaryKind::App,
-                false => BinaryKind::Cli,
-            };
+    if !success {
+        process::exit(1);
+    }
+}
 
-            if !options.app_mode && !package.package_file_exists {
-                package.exclude_engine = true;
-                package.exclude_framework = true;
-            }
+fn compile(options: CompileOptions, validate: bool) {
+    let kind = match validate {
+        true => CommandKind::Check,
+        false => CommandKind::Build,
+    };
+    let package = create_package(kind, &options);
 
-            let mode = match options.validate {
-                true => ProgramContextMode::Validate,
-                false => ProgramContextMode::Compile(binary_kind)
-            };
-            let program_options = ProgramContextOptions {
-                package,
-                mode,
-                cursor_location: None,
-            };
-            let mut timer = Timer::new();
-            let mut context = ProgramContext::new(program_options);
+    if options.watch {
+        watch_package(&options, &package, validate);
+    }
+
+    if !compile_package(&options, &package, validate, None) {
+        process::exit(1);
+    }
+}
+
+fn run(options: CompileOptions) {
+    let package = create_package(CommandKind::Run, &options);
+    let mut context = ProgramContext::new(ProgramContextOptions {
+        package,
+        mode: ProgramContextMode::Compile(BinaryKind::Cli),
+        cursor_location: None,
+        use_parse_cache: true,
+    });
+
+    context.parse_source_files(None);
+
+    if !context.has_errors() {
+        context.process_source_files();
+    }
+
+    for line in &context.debug_output {
+        println!("{}", line);
+    }
+
+    if should_display_warnings(&options) {
+        display_diagnostics(context.get_warnings(), options.message_format);
+    }
+
+    if let Some(errors) = context.take_errors() {
+        display_diagnostics(errors, options.message_format);
+        process::exit(1);
+    }
+
+    context.resolve_wat();
+    context.encode_output_file();
+
+    let execution = execute_cli_module(context.get_output_file());
+
+    context.destroy();
+
+    for log in &execution.logs {
+        println!("{}", log);
+    }
+
+    for line_number in &execution.failed_assertions {
+        eprintln!("{} assertion failed at line {}", "error:".red().bold(), line_number);
+    }
+
+    if let Some(error) = &execution.error {
+        eprintln!("{} execution aborted: {}", "error:".red().bold(), error);
+    }
+
+    if !execution.failed_assertions.is_empty() || execution.error.is_some() {
+        process::exit(1);
+    }
+}
+
+fn create_package(kind: CommandKind, options: &CompileOptions) -> Package {
+    if options.message_format == MessageFormat::Json {
+        colored::control::set_override(false);
+    }
+
+    let mut package = load_package(kind, &options.input_path);
+
+    if !options.app_mode && !package.package_file_exists {
+        package.exclude_engine = true;
+        package.exclude_framework = true;
+    }
+
+    if let Some(prelude_path) = &options.prelude {
+        package.prelude_path = PathBuf::from(prelude_path);
+    }
+
+    if let Some(framework_path) = &options.framework {
+        package.set_framework_path(Path::new(framework_path));
+    }
+
+    if options.deny_warnings {
+        package.deny_warnings = true;
+    }
 
-            timer.time(ProgramStep::Parse, || context.parse_source_files(None));
+    package
+}
 
-            if !options.validate && !context.has_errors() {
-                timer.time(ProgramStep::Process, || context.process_source_files());
+fn watch_package(options: &CompileOptions, package: &Package, validate: bool) -> ! {
+    let mut cache = FileSystemCache::new();
+    let mut snapshot = vec![];
+
+    loop {
+        let new_snapshot = get_source_files_snapshot(package);
+
+        if new_snapshot != snapshot {
+            snapshot = new_snapshot;
+            compile_package(options, package, validate, Some(&mut cache));
+
+            if options.message_format == MessageFormat::Human && !matches!(options.log_level, LogLevel::Silent) {
+                println!("{} waiting for changes...", "watch:".magenta().bold());
             }
+        }
 
-            match context.take_errors() {
-                Some(errors) => {
-                    let mut set = IndexSet::new();
+        sleep(Duration::from_millis(WATCH_POLL_INTERVAL_MS));
+    }
+}
 
-                    for error in errors {
-                        if let Some(string) = error.to_string() {
-                            set.insert(string);
-                        }
-                    }
+fn get_source_files_snapshot(package: &Package) -> Vec<(String, Option<SystemTime>)> {
+    let mut result = vec![];
 
-                    for string in set {
-                        println!("{}", string);
-                    }
-                    
-                    process::exit(1);
+    for directory in package.get_source_directories() {
+        for details in directory.get_source_files() {
+            let modified = fs::metadata(&details.file_path).and_then(|metadata| metadata.modified()).ok();
+
+            result.push((details.file_path, modified));
+        }
+    }
+
+    result
+}
+
+fn compile_package(options: &CompileOptions, package: &Package, validate: bool, cache: Option<&mut FileSystemCache<ParsedSourceFile, ParseError>>) -> bool {
+    let binary_kind = match options.app_mode {
+        true => BinaryKind::App,
+        false => BinaryKind::Cli,
+    };
+    let mode = match validate {
+        true => ProgramContextMode::Validate,
+        false => ProgramContextMode::Compile(binary_kind)
+    };
+    let program_options = ProgramContextOptions {
+        package: package.clone(),
+        mode,
+        cursor_location: None,
+        use_parse_cache: true,
+    };
+    let mut timer = Timer::new();
+    let mut context = ProgramContext::new(program_options);
+
+    timer.time(ProgramStep::Parse, || context.parse_source_files(cache));
+
+    if !context.has_errors() {
+        timer.time(ProgramStep::Process, || context.process_source_files());
+    }
+
+    for line in &context.debug_output {
+        println!("{}", line);
+    }
+
+    if should_display_warnings(options) {
+        display_diagnostics(context.get_warnings(), options.message_format);
+    }
+
+    let success = match context.take_errors() {
+        Some(errors) => {
+            display_diagnostics(errors, options.message_format);
+
+            false
+        },
+        None => {
+            let output_path = match &options.output_path {
+                Some(output_path) => {
+                    timer.time(ProgramStep::Resolve, || context.resolve_wat());
+                    match options.emit {
+                        EmitKind::Wat => timer.time(ProgramStep::Stringify, || context.generate_output_file()),
+                        EmitKind::Wasm => timer.time(ProgramStep::Encode, || context.encode_output_file()),
+                    };
+                    timer.time(ProgramStep::Write, || context.write_output_file(output_path));
+
+                    output_path
                 },
-                None => {
-                    if !options.validate {
-                        timer.time(ProgramStep::Resolve, || context.resolve_wat());
-                        timer.time(ProgramStep::Stringify, || context.generate_output_file());
-                        timer.time(ProgramStep::Write, || context.write_output_file(output_path));
-                    }
+                None => &options.input_path,
+            };
 
-                    match options.log_level {
-                        LogLevel::Silent => {},
-                        LogLevel::Short => {
-                            println!("{} {} ({}s)", "ok:".blue().bold(), output_path.bold(), timer.get_total_duration());
-                        },
-                        LogLevel::Detailed => {
-                            for (step, duration) in timer.get_all_durations() {
-                                if !step.is_negligible() {
-                                    print_step(step.get_name(), duration);
-                                }
+            if options.message_format == MessageFormat::Human {
+                match options.log_level {
+                    LogLevel::Silent => {},
+                    LogLevel::Short => {
+                        println!("{} {} ({}s)", "ok:".blue().bold(), output_path.bold(), timer.get_total_duration());
+                    },
+                    LogLevel::Detailed => {
+                        for (step, duration) in timer.get_all_durations() {
+                            if !step.is_negligible() {
+                                print_step(step.get_name(), duration);
                             }
+                        }
 
-                            print_step("total", timer.get_total_duration());
-                        },
-                    }
-                },
+                        print_step("total", timer.get_total_duration());
+                    },
+                }
             }
-        } else {
-            display_usage_and_exit()
+
+            true
+        },
+    };
+
+    context.destroy();
+
+    success
+}
+
+fn load_package(kind: CommandKind, input_path: &str) -> Package {
+    match Package::try_from_path(input_path) {
+        Some(package) => package,
+        None => display_error_and_exit(CommandLineError::new(Some(kind), format!("`{}` is not inside a Lotus package", input_path))),
+    }
+}
+
+fn should_display_warnings(options: &CompileOptions) -> bool {
+    match options.message_format {
+        MessageFormat::Human => !matches!(options.log_level, LogLevel::Silent),
+        MessageFormat::Json => true,
+    }
+}
+
+fn display_diagnostics(diagnostics: &[CompilationError], message_format: MessageFormat) {
+    let mut set = IndexSet::new();
+
+    for diagnostic in diagnostics {
+        let string = match message_format {
+            MessageFormat::Human => diagnostic.to_string(),
+            MessageFormat::Json => diagnostic.to_json().map(|value| value.to_string()),
+        };
+
+        if let Some(string) = string {
+            set.insert(string);
         }
     }
+
+    for string in set {
+        println!("{}", string);
+    }
 }
 
 fn print_step(name: &str, time: f64) {
@@ -129,7 +379,18 @@ fn print_step(name: &str, time: f64) {
     println!("{: <10} {}s", name_string.bold(), time);
 }
 
-fn display_usage_and_exit() -> ! {
-    println!("{} {} <input_directory> <output_file>", "usage:".magenta().bold(), PROGRAM_NAME.bold());
-    process::exit(1)
-}
\ No newline at end of file
+fn display_error_and_exit(error: CommandLineError) -> ! {
+    eprintln!("{} {}", "error:".red().bold(), error.message);
+
+    match error.command {
+        Some(kind) => {
+            eprintln!("{} {}", "usage:".magenta().bold(), get_usage_string(PROGRAM_NAME, kind));
+            eprintln!("Run `{} help {}` for more information.", PROGRAM_NAME, kind.get_name());
+        },
+        None => {
+            eprintln!("Run `{} help` for the list of available commands.", PROGRAM_NAME);
+        },
+    }
+
+    process::exit(USAGE_ERROR_EXIT_CODE)
+}
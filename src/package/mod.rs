@@ -1,5 +1,9 @@
 mod package;
+mod package_dependency;
+mod package_error;
 mod constants;
 
 pub use package::*;
-pub use constants::*;
\ No newline at end of file
+pub use package_dependency::*;
+pub use package_error::*;
+pub use constants::*;
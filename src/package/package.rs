@@ -1,7 +1,8 @@
-use std::{path::{Path, PathBuf}, fs};
+use std::{path::{Path, PathBuf}, fs, env, rc::Rc};
+use parsable::{FileInfo, ItemLocation};
 use toml::Value;
-use crate::program::SourceDirectory;
-use super::{CONFIG_FILE_NAME, SRC_DIR_NAME, CARGO_MANIFEST_DIR_PATH, PRELUDE_DIR_NAME, CACHE_DIR_NAME, DATA_DIR_NAME};
+use crate::program::{SourceDirectory, LintKind, LintLevel};
+use super::{PackageDependency, PackageError, CONFIG_FILE_NAME, SRC_DIR_NAME, CARGO_MANIFEST_DIR_PATH, PRELUDE_DIR_NAME, CACHE_DIR_NAME, DATA_DIR_NAME, ENGINE_DIR_NAME, FRAMEWORK_DIR_NAME};
 
 #[derive(Debug, Clone)]
 pub struct Package {
@@ -9,17 +10,24 @@ pub struct Package {
     pub src_path: PathBuf,
     pub cache_path: PathBuf,
     pub data_path: PathBuf,
+    pub prelude_path: PathBuf,
+    pub framework_path: Option<PathBuf>,
     pub package_file_exists: bool,
     pub exclude_framework: bool,
     pub exclude_engine: bool,
     pub no_alloc: bool,
+    pub deny_warnings: bool,
+    pub strict_none: bool,
+    pub lint_levels: Vec<(LintKind, LintLevel)>,
+    pub dependencies: Vec<PackageDependency>,
+    pub errors: Vec<PackageError>,
 }
 
 impl Package {
-    pub fn from_path(path: &str) -> Self {
-        let root_path = infer_root_directory(Path::new(path)).unwrap();
+    // Returns `None` if the path does not exist or is not inside a package
+    pub fn try_from_path(path: &str) -> Option<Self> {
+        let root_path = infer_root_directory(Path::new(path))?;
         let src_path = root_path.join(SRC_DIR_NAME);
-        let config_path = root_path.join(CONFIG_FILE_NAME);
         let cache_path = root_path.join(CACHE_DIR_NAME);
         let data_path = cache_path.join(DATA_DIR_NAME);
 
@@ -28,30 +36,69 @@ impl Package {
             src_path,
             cache_path,
             data_path,
+            prelude_path: get_default_prelude_path(),
+            framework_path: None,
             package_file_exists: false,
             exclude_framework: true,
             exclude_engine: false,
             no_alloc: false,
+            deny_warnings: false,
+            strict_none: false,
+            lint_levels: vec![],
+            dependencies: vec![],
+            errors: vec![],
         };
 
-        if let Ok(content) = fs::read_to_string(config_path) {
-            if let Ok(config) = content.parse::<Value>() {
-                result.package_file_exists = true;
-
-                result.exclude_framework = config.get("framework")
-                    .and_then(|value| value.as_bool())
-                    .map(|b| !b)
-                    .unwrap_or(true);
-                
-                result.exclude_engine = config.get("engine")
-                    .and_then(|value| value.as_bool())
-                    .map(|b| !b)
-                    .unwrap_or(false);
-                
-                result.no_alloc = config.get("no-alloc")
-                    .and_then(|value| value.as_bool())
-                    .unwrap_or(false);
+        if let Some((config_file, config)) = result.read_config_file(&result.root_path.clone()) {
+            result.package_file_exists = true;
+
+            if let Some(prelude_path) = config.get("prelude").and_then(|value| value.as_str()) {
+                result.prelude_path = result.root_path.join(prelude_path);
+            }
+
+            result.exclude_framework = match config.get("framework") {
+                Some(Value::Boolean(b)) => !b,
+                Some(Value::String(framework_path)) => {
+                    result.framework_path = Some(result.root_path.join(framework_path));
+                    false
+                },
+                _ => true,
+            };
+            
+            result.exclude_engine = config.get("engine")
+                .and_then(|value| value.as_bool())
+                .map(|b| !b)
+                .unwrap_or(false);
+            
+            result.no_alloc = config.get("no-alloc")
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false);
+
+            result.deny_warnings = config.get("deny-warnings")
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false);
+
+            result.strict_none = config.get("strict-none")
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false);
+
+            if let Some(lints) = config.get("lints").and_then(|value| value.as_table()) {
+                for (name, value) in lints {
+                    let kind = LintKind::from_str(name);
+                    let level = value.as_str().and_then(LintLevel::from_str);
+
+                    match (kind, level) {
+                        (Some(kind), Some(level)) => result.lint_levels.push((kind, level)),
+                        (None, _) => result.add_config_error(&config_file, name, format!("unknown lint `{}`", name)),
+                        (Some(_), None) => result.add_config_error(&config_file, name, format!("lint `{}`: level must be `allow`, `warn` or `deny`", name)),
+                    }
+                }
             }
+
+            let root_path = result.root_path.canonicalize().unwrap_or_else(|_| result.root_path.clone());
+            let root_name = get_directory_name(&root_path);
+
+            result.collect_dependencies(&root_path, &config_file, &config, &mut vec![(root_name, root_path.clone())]);
         }
 
         if result.exclude_engine {
@@ -63,32 +110,153 @@ impl Package {
             result.exclude_framework = true;
         }
 
-        result
+        Some(result)
+    }
+
+    fn read_config_file(&mut self, package_root_path: &Path) -> Option<(Rc<FileInfo>, Value)> {
+        let config_path = package_root_path.join(CONFIG_FILE_NAME);
+        let content = fs::read_to_string(&config_path).ok()?;
+        let file = Rc::new(FileInfo::new(content, config_path.to_string_lossy().to_string(), self.root_path.to_string_lossy().to_string()));
+
+        match file.content.parse::<Value>() {
+            Ok(config) => Some((file, config)),
+            Err(error) => {
+                let (start, end) = error.span().map(|span| (span.start, span.end)).unwrap_or((0, 0));
+
+                self.errors.push(PackageError {
+                    location: ItemLocation { file, start, end },
+                    message: format!("malformed `{}`: {}", CONFIG_FILE_NAME, error.message().trim().replace('\n', ", ")),
+                });
+
+                None
+            },
+        }
+    }
+
+    fn collect_dependencies(&mut self, package_root_path: &Path, config_file: &Rc<FileInfo>, config: &Value, stack: &mut Vec<(String, PathBuf)>) {
+        let dependencies = match config.get("dependencies") {
+            Some(Value::Table(table)) => table,
+            Some(_) => {
+                self.add_config_error(config_file, "dependencies", "`dependencies` must be a table".to_string());
+                return;
+            },
+            None => return,
+        };
+
+        for (name, value) in dependencies {
+            let dependency_path = match value.get("path").and_then(|path| path.as_str()) {
+                Some(path) => path,
+                None => {
+                    self.add_config_error(config_file, name, format!("dependency `{}` must be specified as `{{ path = \"<path>\" }}`", name));
+                    continue;
+                },
+            };
+            let root_path = match package_root_path.join(dependency_path).canonicalize() {
+                Ok(root_path) => root_path,
+                Err(_) => {
+                    self.add_config_error(config_file, name, format!("dependency `{}`: directory `{}` does not exist", name, dependency_path));
+                    continue;
+                },
+            };
+
+            if let Some(index) = stack.iter().position(|(_, path)| path == &root_path) {
+                let mut cycle : Vec<String> = stack[index..].iter().map(|(name, _)| format!("`{}`", name)).collect();
+
+                cycle.push(format!("`{}`", name));
+                self.add_config_error(config_file, name, format!("dependency cycle: {}", cycle.join(" -> ")));
+                continue;
+            }
+
+            if self.dependencies.iter().any(|dependency| dependency.root_path == root_path) {
+                continue;
+            }
+
+            let src_path = root_path.join(SRC_DIR_NAME);
+
+            if !src_path.is_dir() {
+                self.add_config_error(config_file, name, format!("dependency `{}`: `{}` is not a Lotus package (no `{}` directory)", name, dependency_path, SRC_DIR_NAME));
+                continue;
+            }
+
+            self.dependencies.push(PackageDependency {
+                name: name.clone(),
+                root_path: root_path.clone(),
+                src_path,
+            });
+
+            if let Some((dependency_config_file, dependency_config)) = self.read_config_file(&root_path) {
+                stack.push((name.clone(), root_path.clone()));
+                self.collect_dependencies(&root_path, &dependency_config_file, &dependency_config, stack);
+                stack.pop();
+            }
+        }
+    }
+
+    fn add_config_error(&mut self, config_file: &Rc<FileInfo>, key: &str, message: String) {
+        let start = find_key_index(&config_file.content, key).unwrap_or(0);
+
+        self.errors.push(PackageError {
+            location: ItemLocation { file: config_file.clone(), start, end: start + key.len() },
+            message,
+        });
+    }
+
+    pub fn set_framework_path(&mut self, framework_path: &Path) {
+        // the framework cannot be included without the engine
+        self.framework_path = Some(framework_path.to_path_buf());
+        self.exclude_framework = self.exclude_engine;
+    }
+
+    pub fn get_prelude_src_path(&self) -> String {
+        self.prelude_path.join(SRC_DIR_NAME).to_string_lossy().to_string()
     }
 
     pub fn get_source_directories(&self) -> Vec<SourceDirectory> {
         let mut result = vec![];
-        let prelude_path = get_default_prelude_path();
         let mut exclude = vec![];
 
         if self.exclude_engine {
-            exclude.push("engine");
+            exclude.push(ENGINE_DIR_NAME);
         }
 
-        if self.exclude_framework {
-            exclude.push("framework");
+        if self.exclude_framework || self.framework_path.is_some() {
+            exclude.push(FRAMEWORK_DIR_NAME);
         }
 
+        let prelude_src_path = self.get_prelude_src_path();
         let prelude_source_directory = SourceDirectory {
-            root_path: prelude_path.join(SRC_DIR_NAME).to_string_lossy().to_string(),
+            root_path: prelude_src_path.clone(),
+            package_root_path: prelude_src_path.clone(),
             exclude,
         };
 
         result.push(prelude_source_directory);
 
-        if self.root_path != prelude_path {
+        if let (Some(framework_path), false) = (&self.framework_path, self.exclude_framework) {
+            // an alternate framework is part of the prelude, so that it has access to its non-exported items
+            result.push(SourceDirectory {
+                root_path: framework_path.to_string_lossy().to_string(),
+                package_root_path: prelude_src_path,
+                exclude: vec![],
+            });
+        }
+
+        for dependency in &self.dependencies {
+            let src_path = dependency.src_path.to_string_lossy().to_string();
+
             result.push(SourceDirectory {
-                root_path: self.src_path.to_string_lossy().to_string(),
+                root_path: src_path.clone(),
+                package_root_path: src_path,
+                exclude: vec![],
+            });
+        }
+
+        if self.root_path != self.prelude_path {
+            let src_path = self.src_path.to_string_lossy().to_string();
+
+            result.push(SourceDirectory {
+                root_path: src_path.clone(),
+                package_root_path: src_path,
                 exclude: vec![],
             });
         }
@@ -103,9 +271,42 @@ fn get_default_prelude_path() -> PathBuf {
     path_buf.push(CARGO_MANIFEST_DIR_PATH);
     path_buf.push(PRELUDE_DIR_NAME);
 
+    if !path_buf.is_dir() {
+        // the compiler may run on a machine where it has not been built: look for a prelude next to the executable
+        if let Some(executable_dir_path) = env::current_exe().ok().as_deref().and_then(Path::parent) {
+            let executable_prelude_path = executable_dir_path.join(PRELUDE_DIR_NAME);
+
+            if executable_prelude_path.is_dir() {
+                return executable_prelude_path;
+            }
+        }
+    }
+
     path_buf
 }
 
+fn get_directory_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+fn find_key_index(content: &str, key: &str) -> Option<usize> {
+    let mut index = 0;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+
+        if let Some(rest) = trimmed.strip_prefix(key) {
+            if rest.trim_start().starts_with('=') {
+                return Some(index + line.len() - trimmed.len());
+            }
+        }
+
+        index += line.len();
+    }
+
+    None
+}
+
 fn infer_root_directory(path: &Path) -> Option<PathBuf> {
     let mut result = None;
 
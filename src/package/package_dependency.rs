@@ -0,0 +1,8 @@
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub struct PackageDependency {
+    pub name: String,
+    pub root_path: PathBuf,
+    pub src_path: PathBuf,
+}
@@ -0,0 +1,7 @@
+use parsable::ItemLocation;
+
+#[derive(Debug, Clone)]
+pub struct PackageError {
+    pub location: ItemLocation,
+    pub message: String,
+}
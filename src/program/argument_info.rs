@@ -5,6 +5,7 @@ use super::{Vasm, Type};
 pub struct ArgumentInfo {
     pub name: Identifier,
     pub ty: Type,
+    pub has_default_value: bool,
     pub is_optional: bool,
     pub default_value: Vasm
 }
\ No newline at end of file
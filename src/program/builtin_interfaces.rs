@@ -10,6 +10,8 @@ pub enum BuiltinInterface {
     And,
     Or,
     Xor,
+    Eq,
+    Hash,
     Ge,
     Gt,
     Le,
@@ -20,6 +22,7 @@ pub enum BuiltinInterface {
     GetAtIndex,
     SetAtIndex,
     Iterable,
+    Iterator,
     Unwrap,
     Builtin,
     Tuple,
@@ -28,6 +31,8 @@ pub enum BuiltinInterface {
 
 pub const DEFAULT_INTERFACES : &'static[BuiltinInterface] = &[
     BuiltinInterface::Builtin,
+    BuiltinInterface::Eq,
+    BuiltinInterface::Hash,
 ];
 
 impl BuiltinInterface {
@@ -43,6 +48,8 @@ impl BuiltinInterface {
             BuiltinInterface::And => "And",
             BuiltinInterface::Or => "Or",
             BuiltinInterface::Xor => "Xor",
+            BuiltinInterface::Eq => "Eq",
+            BuiltinInterface::Hash => "Hash",
             BuiltinInterface::Ge => "Ge",
             BuiltinInterface::Gt => "Gt",
             BuiltinInterface::Le => "Le",
@@ -53,6 +60,7 @@ impl BuiltinInterface {
             BuiltinInterface::GetAtIndex => "GetAtIndex",
             BuiltinInterface::SetAtIndex => "SetAtIndex",
             BuiltinInterface::Iterable => "Iterable",
+            BuiltinInterface::Iterator => "Iterator",
             BuiltinInterface::Unwrap => "Unwrap",
             BuiltinInterface::Builtin => "Builtin",
             BuiltinInterface::Tuple => "Tuple",
@@ -8,12 +8,15 @@ pub enum BuiltinType {
     Bool,
     Int,
     Float,
+    Long,
+    Double,
     Char,
     String,
     Pointer,
     Array,
     Enum,
     Object,
+    EnumObject,
     Function,
     Pair,
     Set,
@@ -33,12 +36,15 @@ impl BuiltinType {
             BuiltinType::Bool => "bool",
             BuiltinType::Int => "int",
             BuiltinType::Float => "float",
+            BuiltinType::Long => "long",
+            BuiltinType::Double => "double",
             BuiltinType::Char => "char",
             BuiltinType::String => "string",
             BuiltinType::Pointer => "Pointer",
             BuiltinType::Array => "Array",
             BuiltinType::Enum => "Enum",
             BuiltinType::Object => "Object",
+            BuiltinType::EnumObject => "EnumObject",
             BuiltinType::Function => "Function",
             BuiltinType::Pair => "Pair",
             BuiltinType::Set => "Set",
@@ -1,11 +1,13 @@
 use std::collections::HashSet;
 use colored::Colorize;
 use parsable::{ItemLocation, ParseError};
+use serde_json::{json, Value};
 use crate::utils::Link;
-use super::{InterfaceBlueprint, Type, ItemKind, ExpectedKind};
+use super::{InterfaceBlueprint, Type, ItemKind, ExpectedKind, Severity, LintKind};
 
 #[derive(Debug)]
 pub struct CompilationError {
+    pub severity: Severity,
     pub location: ItemLocation,
     pub details: CompilationErrorDetails,
 }
@@ -21,7 +23,8 @@ pub enum CompilationErrorDetails {
     UnexpectedVoidExpression,
     InvalidCharacter(InvalidCharacterDetails),
     ExpectedClassType(ExpectedClassTypeDetails),
-    UndefinedItem(UndefinedItemDetails)
+    UndefinedItem(UndefinedItemDetails),
+    Lint(LintDetails)
 }
 
 #[derive(Debug)]
@@ -68,23 +71,81 @@ pub struct InterfaceMismatchDetails {
     pub actual_type: Type
 }
 
+#[derive(Debug)]
+pub struct LintDetails {
+    pub kind: LintKind,
+    pub name: String
+}
+
 #[derive(Debug)]
 pub struct InvalidCharacterDetails {
     pub character: String
 }
 
+impl CompilationErrorDetails {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            CompilationErrorDetails::Generic(_) => "Generic",
+            CompilationErrorDetails::ParseError(_) => "ParseError",
+            CompilationErrorDetails::TypeMismatch(_) => "TypeMismatch",
+            CompilationErrorDetails::InterfaceMismatch(_) => "InterfaceMismatch",
+            CompilationErrorDetails::ExpectedToken(_) => "ExpectedToken",
+            CompilationErrorDetails::UnexpectedToken(_) => "UnexpectedToken",
+            CompilationErrorDetails::UnexpectedVoidExpression => "UnexpectedVoidExpression",
+            CompilationErrorDetails::InvalidCharacter(_) => "InvalidCharacter",
+            CompilationErrorDetails::ExpectedClassType(_) => "ExpectedClassType",
+            CompilationErrorDetails::UndefinedItem(_) => "UndefinedItem",
+            CompilationErrorDetails::Lint(_) => "Lint",
+        }
+    }
+}
+
 impl CompilationError {
+    pub fn to_json(&self) -> Option<Value> {
+        let message = self.get_message()?;
+        let (start_line, start_column) = self.location.file.get_line_col(self.location.start).unwrap_or_default();
+        let (end_line, end_column) = self.location.file.get_line_col(self.location.end).unwrap_or_default();
+        let mut result = json!({
+            "severity": self.severity.get_name(),
+            "kind": self.details.get_name(),
+            "file": self.location.file.path,
+            "range": {
+                "start": { "offset": self.location.start, "line": start_line, "column": start_column },
+                "end": { "offset": self.location.end, "line": end_line, "column": end_column },
+            },
+            "message": message,
+        });
+
+        match &self.details {
+            CompilationErrorDetails::TypeMismatch(details) => {
+                result["expected_type"] = json!(details.expected_type.to_string());
+                result["actual_type"] = json!(details.actual_type.to_string());
+            },
+            CompilationErrorDetails::InterfaceMismatch(details) => {
+                result["expected_interface"] = json!(details.expected_interface.borrow().name.as_str());
+                result["actual_type"] = json!(details.actual_type.to_string());
+            },
+            CompilationErrorDetails::ExpectedClassType(details) => {
+                result["actual_type"] = json!(details.actual_type.to_string());
+            },
+            CompilationErrorDetails::Lint(details) => {
+                result["lint"] = json!(details.kind.get_name());
+            },
+            _ => {}
+        }
+
+        Some(result)
+    }
+
     pub fn to_string(&self) -> Option<String> {
         match self.get_message() {
             Some(first_line) => {
-                let error_string = format!("{} {}", "error:".red().bold(), first_line);
-                let (line, col) = self.location.file.get_line_col(self.location.start).unwrap();
-                let file_name = match self.location.file.path.starts_with(self.location.file.package_root_path.as_str()) {
-                    true => &self.location.file.path[(self.location.file.package_root_path.len() + 1)..],
-                    false => self.location.file.path.as_str(),
+                let severity_string = format!("{}:", self.severity.get_name());
+                let error_string = match self.severity {
+                    Severity::Error => format!("{} {}", severity_string.red().bold(), first_line),
+                    Severity::Warning => format!("{} {}", severity_string.yellow().bold(), first_line),
                 };
-
-                let location_string = format!("{}:{}:{}: ", file_name, line, col);
+                let location_string = format!("{}: ", get_location_string(&self.location));
                 let mut result = format!("{}{}", location_string.bold(), error_string);
 
                 // for detail in self.get_details() {
@@ -159,6 +220,16 @@ impl CompilationError {
             CompilationErrorDetails::UndefinedItem(details) => {
                 Some(format!("undefined {} `{}`", details.kind.to_str(), details.name.bold()))
             },
+            CompilationErrorDetails::Lint(details) => {
+                Some(match details.kind {
+                    LintKind::UnusedVariable => format!("unused variable `{}`", details.name.bold()),
+                    LintKind::UnusedFunction => format!("unused function `{}`", details.name.bold()),
+                    LintKind::UnusedType => format!("unused type `{}`", details.name.bold()),
+                    LintKind::UnreachableCode => "unreachable code".to_string(),
+                    LintKind::UnreachableMatchArm => "unreachable match arm".to_string(),
+                    LintKind::ShadowedVariable => format!("variable `{}` shadows a previous declaration", details.name.bold()),
+                })
+            },
         }
     }
 
@@ -168,4 +239,14 @@ impl CompilationError {
     //         CompilationErrorDetails::TypeMismatch(_) => vec![],
     //     }
     // }
-}
\ No newline at end of file
+}
+
+pub fn get_location_string(location: &ItemLocation) -> String {
+    let (line, col) = location.file.get_line_col(location.start).unwrap_or_default();
+    let file_name = match location.file.path.starts_with(location.file.package_root_path.as_str()) {
+        true => &location.file.path[(location.file.package_root_path.len() + 1)..],
+        false => location.file.path.as_str(),
+    };
+
+    format!("{}:{}:{}", file_name, line, col)
+}
@@ -1,11 +1,13 @@
-use std::{ops::Deref, mem::take};
+use std::{ops::Deref, mem::take, collections::HashMap};
 use parsable::{ItemLocation, ParseError, Parsable};
 use crate::{utils::{Link, is_valid_identifier}, items::{Identifier, Word}};
-use super::{CompilationError, CompilationErrorDetails, GenericErrorDetails, ParseErrorDetails, Type, TypeMismatchDetails, InterfaceBlueprint, InterfaceMismatchDetails, InvalidCharacterDetails, ExpectedClassTypeDetails, UndefinedItemDetails, ItemKind, UnexpectedTokenDetails, ExpectedKind, ExpectedTokenDetails, CompilationErrorChain};
+use super::{CompilationError, CompilationErrorDetails, GenericErrorDetails, ParseErrorDetails, Type, TypeMismatchDetails, InterfaceBlueprint, InterfaceMismatchDetails, InvalidCharacterDetails, ExpectedClassTypeDetails, UndefinedItemDetails, ItemKind, UnexpectedTokenDetails, ExpectedKind, ExpectedTokenDetails, CompilationErrorChain, Severity, LintKind, LintLevel, LintDetails};
 
 #[derive(Debug)]
 pub struct CompilationErrorList {
     errors: Vec<CompilationError>,
+    warnings: Vec<CompilationError>,
+    lint_levels: HashMap<LintKind, LintLevel>,
     enabled: bool
 }
 
@@ -13,18 +15,27 @@ impl CompilationErrorList {
     pub fn new() -> Self {
         Self {
             errors: vec![],
+            warnings: vec![],
+            lint_levels: HashMap::new(),
             enabled: true,
         }
     }
 
     pub fn add(&mut self, error: CompilationError) -> CompilationErrorChain {
         if self.enabled {
-            self.errors.push(error)
+            match error.severity {
+                Severity::Error => self.errors.push(error),
+                Severity::Warning => self.warnings.push(error),
+            }
         }
 
         CompilationErrorChain
     }
 
+    pub fn set_lint_level(&mut self, kind: LintKind, level: LintLevel) {
+        self.lint_levels.insert(kind, level);
+    }
+
     pub fn set_enabled(&mut self, value: bool) {
         self.enabled = value;
     }
@@ -37,8 +48,30 @@ impl CompilationErrorList {
         &self.errors
     }
 
+    pub fn get_warnings(&self) -> &[CompilationError] {
+        &self.warnings
+    }
+
+    pub fn lint(&mut self, location: &ItemLocation, kind: LintKind, name: &str) -> CompilationErrorChain {
+        let severity = match self.lint_levels.get(&kind).copied().unwrap_or_default() {
+            LintLevel::Allow => return CompilationErrorChain,
+            LintLevel::Warn => Severity::Warning,
+            LintLevel::Deny => Severity::Error,
+        };
+
+        self.add(CompilationError {
+            severity,
+            location: location.clone(),
+            details: CompilationErrorDetails::Lint(LintDetails {
+                kind,
+                name: name.to_string(),
+            }),
+        })
+    }
+
     pub fn generic(&mut self, location: &ItemLocation, error: String) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::Generic(GenericErrorDetails {
                 error,
@@ -48,6 +81,7 @@ impl CompilationErrorList {
 
     pub fn parse_error(&mut self, parse_error: &ParseError) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: ItemLocation {
                 file: parse_error.file.clone(),
                 start: parse_error.index,
@@ -61,6 +95,7 @@ impl CompilationErrorList {
 
     pub fn type_mismatch(&mut self, location: &ItemLocation, expected_type: &Type, actual_type: &Type) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::TypeMismatch(TypeMismatchDetails {
                 expected_type: expected_type.clone(),
@@ -71,6 +106,7 @@ impl CompilationErrorList {
 
     pub fn interface_mismatch(&mut self, location: &ItemLocation, expected_interface: &Link<InterfaceBlueprint>, actual_type: &Type) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::InterfaceMismatch(InterfaceMismatchDetails {
                 expected_interface: expected_interface.clone(),
@@ -81,6 +117,7 @@ impl CompilationErrorList {
 
     pub fn unexpected_expression(&mut self, location: &ItemLocation) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::UnexpectedToken(UnexpectedTokenDetails {
                 kind: ExpectedKind::Expression,
@@ -91,6 +128,7 @@ impl CompilationErrorList {
 
     pub fn unexpected_void_expression(&mut self, location: &ItemLocation) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::UnexpectedVoidExpression,
         })
@@ -98,6 +136,7 @@ impl CompilationErrorList {
 
     pub fn unexpected_keyword(&mut self, location: &ItemLocation, keyword: &str) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::UnexpectedToken(UnexpectedTokenDetails {
                 kind: ExpectedKind::Keyword,
@@ -108,6 +147,7 @@ impl CompilationErrorList {
 
     pub fn invalid_character(&mut self, location: &ItemLocation, character: &str) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::InvalidCharacter(InvalidCharacterDetails {
                 character: character.to_string(),
@@ -117,6 +157,7 @@ impl CompilationErrorList {
 
     pub fn expected_type(&mut self, location: &ItemLocation) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::ExpectedToken(ExpectedTokenDetails {
                 kind: ExpectedKind::Type,
@@ -126,6 +167,7 @@ impl CompilationErrorList {
 
     pub fn expected_class_type(&mut self, location: &ItemLocation, actual_type: &Type) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: location.clone(),
             details: CompilationErrorDetails::ExpectedClassType(ExpectedClassTypeDetails {
                 actual_type: actual_type.clone(),
@@ -135,6 +177,7 @@ impl CompilationErrorList {
 
     pub fn undefined_type(&mut self, identifier: &Identifier) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: identifier.location.clone(),
             details: CompilationErrorDetails::UndefinedItem(UndefinedItemDetails {
                 kind: ItemKind::Type,
@@ -181,6 +224,7 @@ impl CompilationErrorList {
 
     pub fn keyword_mismatch(&mut self, word: &Word, expected: &[&'static str]) -> CompilationErrorChain {
         self.add(CompilationError {
+            severity: Severity::Error,
             location: word.location.clone(),
             details: CompilationErrorDetails::ExpectedToken(ExpectedTokenDetails {
                 kind: ExpectedKind::TokenAmong(expected.to_vec()),
@@ -195,6 +239,7 @@ impl CompilationErrorList {
         };
 
         self.add(CompilationError {
+            severity: Severity::Error,
             location: final_location,
             details: CompilationErrorDetails::ExpectedToken(ExpectedTokenDetails {
                 kind: token,
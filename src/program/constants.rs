@@ -72,9 +72,15 @@ pub const GET_AT_INDEX_FUNC_NAME : &'static str = "__get_at_index";
 pub const SET_AT_INDEX_FUNC_NAME : &'static str = "__set_at_index";
 pub const GET_ITERABLE_LEN_FUNC_NAME : &'static str = "__get_iterable_len";
 pub const GET_ITERABLE_PTR_FUNC_NAME : &'static str = "__get_iterable_ptr";
+pub const ITER_METHOD_NAME : &'static str = "iter";
+pub const NEXT_METHOD_NAME : &'static str = "next";
 
 pub const EQ_METHOD_NAME : &'static str = "__eq";
 pub const NE_METHOD_NAME : &'static str = "__ne";
+pub const HASH_METHOD_NAME : &'static str = "__hash";
+pub const GE_METHOD_NAME : &'static str = "__ge";
+pub const LE_METHOD_NAME : &'static str = "__le";
+pub const LT_METHOD_NAME : &'static str = "__lt";
 
 pub const EVENT_VAR_NAME : &'static str = "evt";
 pub const EVENT_OPTIONS_VAR_NAME : &'static str = "opt";
@@ -105,8 +111,11 @@ pub const CONST_KEYWORD : &'static str = "const";
 pub const SYS_KEYWORD : &'static str = "sys";
 pub const PUB_KEYWORD : &'static str = "pub";
 pub const EXPORT_KEYWORD : &'static str = "export";
+pub const USE_KEYWORD : &'static str = "use";
 pub const I32_KEYWORD : &'static str = "i32";
 pub const F32_KEYWORD : &'static str = "f32";
+pub const I64_KEYWORD : &'static str = "i64";
+pub const F64_KEYWORD : &'static str = "f64";
 pub const VOID_KEYWORD : &'static str = "void";
 
 pub const EXPRESSION_KEYWORDS : &'static[&'static str] = &[ MATCH_KEYWORD, FOR_KEYWORD, WHILE_KEYWORD, IF_KEYWORD, ELSE_KEYWORD ];
@@ -114,7 +123,7 @@ pub const VISIBILITY_KEYWORDS : &'static[&'static str] = &[ PUB_KEYWORD, EXPORT_
 pub const VAR_DECLARATION_KEYWORDS : &'static[&'static str] = &[ CONST_KEYWORD, LET_KEYWORD ];
 pub const FUNCTION_DECLARATION_KEYWORDS : &'static[&'static str] = &[ FN_KEYWORD ];
 pub const TYPE_DECLARATION_KEYWORDS : &'static[&'static str] = &[ INTERFACE_KEYWORD, TYPE_KEYWORD, CLASS_KEYWORD, ENUM_KEYWORD, EVENT_KEYWORD, VIEW_KEYWORD ];
-pub const STACK_TYPE_KEYWORDS : &'static[&'static str] = &[ I32_KEYWORD, F32_KEYWORD, VOID_KEYWORD ];
+pub const STACK_TYPE_KEYWORDS : &'static[&'static str] = &[ I32_KEYWORD, F32_KEYWORD, I64_KEYWORD, F64_KEYWORD, VOID_KEYWORD ];
 
 pub const CLI_EXPORTED_FUNCTION_NAME : &'static str = "main";
 pub const APP_EXPORTED_FUNCTION_NAMES : &'static [&'static str] = &[
@@ -11,6 +11,7 @@ pub struct FunctionBlueprint {
     pub parameters: IndexMap<String, Rc<ParameterTypeInfo>>,
     pub arguments: Vec<ArgumentInfo>,
     pub signature: Signature,
+    pub is_return_optional: bool,
     pub argument_variables: Vec<VariableInfo>,
     pub owner_type: Option<Link<TypeBlueprint>>,
     pub owner_interface: Option<Link<InterfaceBlueprint>>,
@@ -44,6 +45,7 @@ impl FunctionBlueprint {
             parameters: IndexMap::new(),
             arguments: vec![],
             signature: Signature::void(context),
+            is_return_optional: false,
             argument_variables: vec![],
             owner_type: None,
             owner_interface: None,
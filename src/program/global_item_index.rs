@@ -1,13 +1,15 @@
-use std::{borrow::Borrow, cell::Ref, collections::{HashMap, hash_map::DefaultHasher}, hash::{Hash, Hasher}, mem::take};
+use std::{borrow::Borrow, cell::{Ref, RefCell}, collections::{HashMap, hash_map::DefaultHasher}, hash::{Hash, Hasher}, mem::take};
 use indexmap::IndexMap;
 use parsable::ItemLocation;
 use crate::{items::{Identifier}, utils::Link};
-use super::{Visibility, GlobalItem};
+use super::{Visibility, GlobalItem, ImportDetails, get_item_directories};
 
 #[derive(Debug)]
 pub struct GlobalItemIndex<V> {
     pub items_by_id: IndexMap<u64, Link<V>>,
-    pub items_by_name: HashMap<String, Vec<Link<V>>>
+    pub items_by_name: HashMap<String, Vec<Link<V>>>,
+    imports: HashMap<(String, String), ImportDetails>,
+    ambiguous_references: RefCell<IndexMap<ItemLocation, Vec<ItemLocation>>>,
 }
 
 fn get_id_from_location(location: &ItemLocation, marker: Option<u64>) -> u64 {
@@ -43,17 +45,77 @@ impl<V : GlobalItem> GlobalItemIndex<V> {
         item
     }
 
+    pub fn add_import(&mut self, import: ImportDetails) {
+        self.imports.insert((import.location.file.path.clone(), import.name.clone()), import);
+    }
+
     pub fn get_by_identifier(&self, getter_name: &Identifier) -> Option<Link<V>> {
+        self.find_by_identifier(getter_name, true)
+    }
+
+    pub fn find_by_identifier(&self, getter_name: &Identifier, check_ambiguity: bool) -> Option<Link<V>> {
         let candidates = self.items_by_name.get(getter_name.as_str())?;
         let getter_location : &ItemLocation = &getter_name.location;
 
-        for item_wrapped in candidates.iter() {
-            if is_item_accessible_from_location(item_wrapped, getter_location) {
+        if let Some(import) = self.imports.get(&(getter_location.file.path.clone(), getter_name.to_string())) {
+            if let Some(item_wrapped) = candidates.iter().find(|item| is_item_imported(item, import) && is_item_accessible_from_location(item, getter_location)) {
                 return Some(item_wrapped.clone());
             }
         }
 
-        None
+        let accessible_candidates : Vec<&Link<V>> = candidates.iter().filter(|item| is_item_accessible_from_location(item, getter_location)).collect();
+        let package_candidates : Vec<&Link<V>> = accessible_candidates.iter().filter(|item| get_package_root_path(item) == getter_location.file.package_root_path).cloned().collect();
+
+        // items of the package of the getter shadow the ones of the prelude and of the dependencies
+        if !package_candidates.is_empty() {
+            let getter_directories = get_item_directories(getter_location);
+
+            // items of the directory of the getter shadow the ones of the other directories
+            if let Some(item_wrapped) = package_candidates.iter().find(|item| get_directories(item) == getter_directories) {
+                return Some((*item_wrapped).clone());
+            }
+
+            if check_ambiguity && package_candidates.len() > 1 {
+                self.add_ambiguous_reference(getter_location, &package_candidates);
+            }
+
+            return Some(package_candidates[0].clone());
+        }
+
+        let first = accessible_candidates.first()?;
+        let first_package_root_path = get_package_root_path(first);
+
+        if check_ambiguity && accessible_candidates.iter().any(|item| get_package_root_path(item) != first_package_root_path) {
+            self.add_ambiguous_reference(getter_location, &accessible_candidates);
+        }
+
+        Some((*first).clone())
+    }
+
+    fn add_ambiguous_reference(&self, getter_location: &ItemLocation, candidates: &[&Link<V>]) {
+        let locations = candidates.iter().map(|item| item.with_ref(|item_unwrapped| item_unwrapped.get_name().location.clone())).collect();
+
+        self.ambiguous_references.borrow_mut().insert(getter_location.clone(), locations);
+    }
+
+    pub fn get_by_import(&self, import: &ImportDetails, check_accessibility: bool) -> Option<Link<V>> {
+        let candidates = self.items_by_name.get(&import.name)?;
+
+        candidates.iter()
+            .find(|item| is_item_imported(item, import) && (!check_accessibility || is_item_accessible_from_location(item, &import.location)))
+            .cloned()
+    }
+
+    pub fn get_conflicting_item(&self, name: &Identifier) -> Option<Link<V>> {
+        let candidates = self.items_by_name.get(name.as_str())?;
+
+        candidates.iter()
+            .find(|item| get_package_root_path(item) == name.location.file.package_root_path && get_directories(item) == get_item_directories(&name.location) && is_item_accessible_from_location(item, &name.location))
+            .cloned()
+    }
+
+    pub fn take_ambiguous_references(&self) -> Vec<(ItemLocation, Vec<ItemLocation>)> {
+        take(&mut *self.ambiguous_references.borrow_mut()).into_iter().collect()
     }
 
     pub fn get_by_name(&self, name: &str) -> Option<Link<V>> {
@@ -115,6 +177,24 @@ impl<V : GlobalItem> GlobalItemIndex<V> {
         self.items_by_id.values().map(|v| v.clone()).collect()
     }
 
+    pub fn get_all_from_namespace(&self, package_root_path: &str, directories: &[String], location: &ItemLocation) -> Vec<Link<V>> {
+        let mut result = vec![];
+
+        for item in self.items_by_id.values() {
+            let in_namespace = item.with_ref(|item_unwrapped| {
+                let item_location = &item_unwrapped.get_name().location;
+
+                item_location.file.package_root_path == package_root_path && get_item_directories(item_location).starts_with(directories)
+            });
+
+            if in_namespace && is_item_accessible_from_location(item, location) {
+                result.push(item.clone());
+            }
+        }
+
+        result
+    }
+
     pub fn get_all_from_location(&self, location: &ItemLocation) -> Vec<Link<V>> {
         let mut result = vec![];
 
@@ -128,6 +208,18 @@ impl<V : GlobalItem> GlobalItemIndex<V> {
     }
 }
 
+fn get_package_root_path<I : GlobalItem>(item: &Link<I>) -> String {
+    item.with_ref(|item_unwrapped| item_unwrapped.get_name().location.file.package_root_path.clone())
+}
+
+fn get_directories<I : GlobalItem>(item: &Link<I>) -> Vec<String> {
+    item.with_ref(|item_unwrapped| get_item_directories(&item_unwrapped.get_name().location))
+}
+
+fn is_item_imported<I : GlobalItem>(item: &Link<I>, import: &ImportDetails) -> bool {
+    item.with_ref(|item_unwrapped| import.matches(&item_unwrapped.get_name().location))
+}
+
 fn is_item_accessible_from_location<I : GlobalItem>(item: &Link<I>, location: &ItemLocation) -> bool {
     item.with_ref(|item_unwrapped| {
         let item_name = item_unwrapped.get_name();
@@ -148,6 +240,8 @@ impl<V> Default for GlobalItemIndex<V> {
         Self {
             items_by_id: IndexMap::new(),
             items_by_name: HashMap::new(),
+            imports: HashMap::new(),
+            ambiguous_references: RefCell::new(IndexMap::new()),
         }
     }
 }
\ No newline at end of file
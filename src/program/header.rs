@@ -9,6 +9,10 @@ pub const LOAD_INT_WASM_FUNC_NAME : &'static str = "load_int";
 pub const STORE_INT_WASM_FUNC_NAME : &'static str = "store_int";
 pub const LOAD_FLOAT_WASM_FUNC_NAME : &'static str = "load_float";
 pub const STORE_FLOAT_WASM_FUNC_NAME : &'static str = "store_float";
+pub const LOAD_LONG_WASM_FUNC_NAME : &'static str = "load_long";
+pub const STORE_LONG_WASM_FUNC_NAME : &'static str = "store_long";
+pub const LOAD_DOUBLE_WASM_FUNC_NAME : &'static str = "load_double";
+pub const STORE_DOUBLE_WASM_FUNC_NAME : &'static str = "store_double";
 
 pub const TMP_VAR_NAME : &'static str = "tmp";
 pub const CLOSURE_TMP_VAR_NAME : &'static str = "tmp_closure";
@@ -47,6 +51,10 @@ pub static HEADER_FUNCTIONS : &'static[Function] = &[
     (STORE_INT_WASM_FUNC_NAME, &[("addr", "i32"), ("value", "i32")], &[], &[], store_int),
     (LOAD_FLOAT_WASM_FUNC_NAME, &[("addr", "i32")], &["f32"], &[], load_float),
     (STORE_FLOAT_WASM_FUNC_NAME, &[("addr", "i32"), ("value", "f32")], &[], &[], store_float),
+    (LOAD_LONG_WASM_FUNC_NAME, &[("addr", "i32")], &["i64"], &[], load_long),
+    (STORE_LONG_WASM_FUNC_NAME, &[("addr", "i32"), ("value", "i64")], &[], &[], store_long),
+    (LOAD_DOUBLE_WASM_FUNC_NAME, &[("addr", "i32")], &["f64"], &[], load_double),
+    (STORE_DOUBLE_WASM_FUNC_NAME, &[("addr", "i32"), ("value", "f64")], &[], &[], store_double),
 ];
 
 fn dummy() -> Vec<Wat> {
@@ -99,4 +107,38 @@ fn store_float() -> Vec<Wat> {
         Wat::get_local("value"),
         wat!["f32.store"]
     ]
-}
\ No newline at end of file
+}
+
+fn load_long() -> Vec<Wat> {
+    vec![
+        Wat::get_local("addr"),
+        wat!["i32.mul", Wat::const_i32(4)],
+        wat!["i64.load"]
+    ]
+}
+
+fn store_long() -> Vec<Wat> {
+    vec![
+        Wat::get_local("addr"),
+        wat!["i32.mul", Wat::const_i32(4)],
+        Wat::get_local("value"),
+        wat!["i64.store"]
+    ]
+}
+
+fn load_double() -> Vec<Wat> {
+    vec![
+        Wat::get_local("addr"),
+        wat!["i32.mul", Wat::const_i32(4)],
+        wat!["f64.load"]
+    ]
+}
+
+fn store_double() -> Vec<Wat> {
+    vec![
+        Wat::get_local("addr"),
+        wat!["i32.mul", Wat::const_i32(4)],
+        Wat::get_local("value"),
+        wat!["f64.store"]
+    ]
+}
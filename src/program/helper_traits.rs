@@ -95,6 +95,18 @@ impl ToWat for f32 {
     }
 }
 
+impl ToWat for i64 {
+    fn to_wat(self) -> Wat {
+        Wat::single(self.to_string())
+    }
+}
+
+impl ToWat for f64 {
+    fn to_wat(self) -> Wat {
+        Wat::single(self.to_string())
+    }
+}
+
 impl ToWatVec for Vec<Wat> {
     fn to_wat_vec(self) -> Vec<Wat> {
         self
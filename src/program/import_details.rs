@@ -0,0 +1,27 @@
+use parsable::ItemLocation;
+
+#[derive(Debug, Clone)]
+pub struct ImportDetails {
+    pub location: ItemLocation,
+    pub name: String,
+    pub package_root_path: String,
+    pub directories: Vec<String>,
+}
+
+impl ImportDetails {
+    pub fn matches(&self, location: &ItemLocation) -> bool {
+        location.file.package_root_path == self.package_root_path && get_item_directories(location).starts_with(&self.directories)
+    }
+}
+
+pub fn get_item_directories(location: &ItemLocation) -> Vec<String> {
+    get_path_directories(&location.file.path, &location.file.package_root_path)
+}
+
+pub fn get_path_directories(file_path: &str, package_root_path: &str) -> Vec<String> {
+    let relative_path = file_path.strip_prefix(package_root_path).unwrap_or_default();
+    let mut directories : Vec<String> = relative_path.split(['/', '\\']).filter(|name| !name.is_empty()).map(|name| name.to_string()).collect();
+
+    directories.pop();
+    directories
+}
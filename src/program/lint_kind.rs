@@ -0,0 +1,47 @@
+use enum_iterator::Sequence;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
+pub enum LintKind {
+    UnusedVariable,
+    UnusedFunction,
+    UnusedType,
+    UnreachableCode,
+    UnreachableMatchArm,
+    ShadowedVariable
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LintLevel {
+    Allow,
+    #[default]
+    Warn,
+    Deny
+}
+
+impl LintKind {
+    pub fn from_str(string: &str) -> Option<Self> {
+        enum_iterator::all::<Self>().find(|kind| kind.get_name() == string)
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            LintKind::UnusedVariable => "unused-variable",
+            LintKind::UnusedFunction => "unused-function",
+            LintKind::UnusedType => "unused-type",
+            LintKind::UnreachableCode => "unreachable-code",
+            LintKind::UnreachableMatchArm => "unreachable-match-arm",
+            LintKind::ShadowedVariable => "shadowed-variable",
+        }
+    }
+}
+
+impl LintLevel {
+    pub fn from_str(string: &str) -> Option<Self> {
+        match string {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None
+        }
+    }
+}
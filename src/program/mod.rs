@@ -23,6 +23,7 @@ mod virtual_assembly;
 mod helper_traits;
 mod helper_macros;
 mod wat;
+mod wasm_binary_encoder;
 mod header;
 mod type_instance_parameters;
 mod parameter_type_info;
@@ -65,6 +66,9 @@ mod root_tags;
 mod event_callback_step;
 mod event_callback;
 mod function_kind;
+mod severity;
+mod lint_kind;
+mod import_details;
 
 pub use program_context::*;
 pub use utils::*;
@@ -90,6 +94,7 @@ pub use virtual_instruction::*;
 pub use virtual_assembly::*;
 pub use helper_traits::*;
 pub use wat::*;
+pub use wasm_binary_encoder::*;
 pub use header::*;
 pub use type_instance_parameters::*;
 pub use parameter_type_info::*;
@@ -131,4 +136,7 @@ pub use argument_info::*;
 pub use root_tags::*;
 pub use event_callback_step::*;
 pub use event_callback::*;
-pub use function_kind::*;
\ No newline at end of file
+pub use function_kind::*;
+pub use severity::*;
+pub use lint_kind::*;
+pub use import_details::*;
\ No newline at end of file
@@ -4,8 +4,8 @@ use indexmap::{IndexMap, IndexSet};
 use enum_iterator::all;
 use colored::*;
 use parsable::{ItemLocation, Parsable, ParseOptions, ParseError};
-use crate::{items::{ParsedEventCallbackQualifierKeyword, Identifier, ParsedSourceFile, ParsedTopLevelBlock, ParsedTypeDeclaration, init_string_literal, init_color_literal}, program::{AssociatedTypeContent, DUMMY_FUNC_NAME, END_INIT_TYPE_METHOD_NAME, ENTRY_POINT_FUNC_NAME, EVENT_CALLBACKS_GLOBAL_NAME, FunctionCall, HEADER_FUNCTIONS, HEADER_FUNC_TYPES, HEADER_GLOBALS, HEADER_IMPORTS, HEADER_MEMORIES, INIT_EVENTS_FUNC_NAME, INIT_GLOBALS_FUNC_NAME, INIT_TYPES_FUNC_NAME, INIT_TYPE_METHOD_NAME, INSERT_EVENT_CALLBACK_FUNC_NAME, ItemGenerator, NamedFunctionCallDetails, RETAIN_GLOBALS_FUNC_NAME, TypeIndex, Wat, typedef_blueprint}, utils::{Link, sort_dependancy_graph, read_directory_recursively, compute_hash, FileSystemCache, PerfTimer}, wat, language_server::{CompletionItemProvider, RenameProvider, HoverProvider, SignatureHelpProvider, CompletionItemGenerator, VariableCompletionDetails, FieldCompletionDetails, MatchItemCompletionDetails, TypeCompletionDetails, EventCompletionDetails, DefinitionProvider, CodeActionsProvider, InterfaceCompletionDetails}, package::Package};
-use super::{ActualTypeContent, BuiltinInterface, BuiltinType, ClosureDetails, CompilationError, CompilationErrorList, DEFAULT_INTERFACES, FunctionBlueprint, FunctionInstanceContent, FunctionInstanceHeader, FunctionInstanceParameters, FunctionInstanceWasmType, GeneratedItemIndex, GlobalItemIndex, GlobalVarBlueprint, GlobalVarInstance, Id, InterfaceBlueprint, InterfaceList, MainType, ResolvedSignature, Scope, ScopeKind, SELF_VAR_NAME, Type, TypeBlueprint, TypeInstanceContent, TypeInstanceHeader, TypeInstanceParameters, TypedefBlueprint, VariableInfo, VariableKind, Vasm, SORT_EVENT_CALLBACK_FUNC_NAME, GlobalItem, SourceDirectory, SOURCE_FILE_EXTENSION, SourceFileDetails, COMMENT_START_TOKEN, insert_in_vec_hashmap, EVENT_VAR_NAME, EVENT_OPTIONS_VAR_NAME, TypeContent, CursorLocation, FunctionBody, ProgramContextOptions, Cursor, ProgramContextMode, LiteralItemManager, RETAIN_METHOD_NAME, ANONYMOUS_FUNCTION_NAME, MainTypeIndex, RootTags, BinaryKind, CLI_EXPORTED_FUNCTION_NAME, APP_EXPORTED_FUNCTION_NAMES, NONE_METHOD_NAME, CHECK_FIELD_ACCESS_FUNC_NAME};
+use crate::{items::{ParsedEventCallbackQualifierKeyword, Identifier, ParsedSourceFile, ParsedTopLevelBlock, ParsedTypeDeclaration, init_string_literal, init_color_literal}, program::{AssociatedTypeContent, DUMMY_FUNC_NAME, END_INIT_TYPE_METHOD_NAME, ENTRY_POINT_FUNC_NAME, EVENT_CALLBACKS_GLOBAL_NAME, FunctionCall, HEADER_FUNCTIONS, HEADER_FUNC_TYPES, HEADER_GLOBALS, HEADER_IMPORTS, HEADER_MEMORIES, INIT_EVENTS_FUNC_NAME, INIT_GLOBALS_FUNC_NAME, INIT_TYPES_FUNC_NAME, INIT_TYPE_METHOD_NAME, INSERT_EVENT_CALLBACK_FUNC_NAME, ItemGenerator, NamedFunctionCallDetails, RETAIN_GLOBALS_FUNC_NAME, TypeIndex, Wat, typedef_blueprint}, utils::{Link, sort_dependancy_graph, read_directory_recursively, compute_hash, FileSystemCache, ParseCache, PerfTimer}, wat, language_server::{CompletionItemProvider, RenameProvider, HoverProvider, SignatureHelpProvider, CompletionItemGenerator, VariableCompletionDetails, FieldCompletionDetails, MatchItemCompletionDetails, TypeCompletionDetails, EventCompletionDetails, DefinitionProvider, CodeActionsProvider, InterfaceCompletionDetails, NamespaceCompletionDetails, CompletionItemKind, SemanticTokensProvider, InlayHintsProvider}, package::Package};
+use super::{ActualTypeContent, BuiltinInterface, BuiltinType, ClosureDetails, CompilationError, CompilationErrorList, DEFAULT_INTERFACES, FunctionBlueprint, FunctionInstanceContent, FunctionInstanceHeader, FunctionInstanceParameters, FunctionInstanceWasmType, GeneratedItemIndex, GlobalItemIndex, GlobalVarBlueprint, GlobalVarInstance, Id, InterfaceBlueprint, InterfaceList, MainType, ResolvedSignature, Scope, ScopeKind, SELF_VAR_NAME, Type, TypeBlueprint, TypeInstanceContent, TypeInstanceHeader, TypeInstanceParameters, TypedefBlueprint, VariableInfo, VariableKind, Vasm, SORT_EVENT_CALLBACK_FUNC_NAME, GlobalItem, SourceDirectory, SOURCE_FILE_EXTENSION, SourceFileDetails, COMMENT_START_TOKEN, insert_in_vec_hashmap, EVENT_VAR_NAME, EVENT_OPTIONS_VAR_NAME, TypeContent, CursorLocation, FunctionBody, ProgramContextOptions, Cursor, ProgramContextMode, LiteralItemManager, RETAIN_METHOD_NAME, ANONYMOUS_FUNCTION_NAME, MainTypeIndex, RootTags, BinaryKind, CLI_EXPORTED_FUNCTION_NAME, APP_EXPORTED_FUNCTION_NAMES, NONE_METHOD_NAME, CHECK_FIELD_ACCESS_FUNC_NAME, WasmBinaryEncoder, LintKind, LintLevel, Visibility, FunctionKind, ImportDetails, PRELUDE_NAMESPACE, SELF_NAMESPACE, get_item_directories, get_path_directories};
 
 pub struct ProgramContext {
     pub options: ProgramContextOptions,
@@ -13,6 +13,7 @@ pub struct ProgramContext {
     pub source_file_list: Vec<SourceFileDetails>,
     pub parsed_source_files: Vec<Rc<ParsedSourceFile>>,
     pub errors: CompilationErrorList,
+    used_items: HashSet<ItemLocation>,
     pub root_tags: RootTags,
 
     pub default_interfaces: InterfaceList,
@@ -30,6 +31,9 @@ pub struct ProgramContext {
     pub hover_provider: HoverProvider,
     pub rename_provider: RenameProvider,
     pub signature_help_provider: SignatureHelpProvider,
+    pub semantic_tokens_provider: SemanticTokensProvider,
+    pub inlay_hints_provider: InlayHintsProvider,
+    pub debug_output: Vec<String>,
 
     builtin_types: HashMap<BuiltinType, Link<TypeBlueprint>>,
     pub main_types: MainTypeIndex,
@@ -55,19 +59,33 @@ pub struct ProgramContext {
     update_server_function: Option<Rc<FunctionInstanceHeader>>,
 
     output_wat: Wat,
-    output_file: String
+    output_file: Vec<u8>
 }
 
 impl ProgramContext {
     pub fn new(options: ProgramContextOptions) -> Self {
         let cursor = Cursor::new(&options.cursor_location);
+        let mut errors = CompilationErrorList::new();
+
+        for kind in all::<LintKind>() {
+            let level = match options.package.lint_levels.iter().rev().find(|(lint_kind, _)| *lint_kind == kind) {
+                Some((_, level)) => *level,
+                None => LintLevel::default(),
+            };
+
+            match options.package.deny_warnings && level == LintLevel::Warn {
+                true => errors.set_lint_level(kind, LintLevel::Deny),
+                false => errors.set_lint_level(kind, level),
+            }
+        }
 
         Self {
             options: options.clone(),
             cursor_location: None,
             source_file_list: vec![],
             parsed_source_files: vec![],
-            errors: Default::default(),
+            errors,
+            used_items: Default::default(),
             root_tags: RootTags::new(),
             default_interfaces: Default::default(),
             types: Default::default(),
@@ -83,6 +101,9 @@ impl ProgramContext {
             hover_provider: HoverProvider::new(&cursor),
             rename_provider: RenameProvider::new(&cursor),
             signature_help_provider: SignatureHelpProvider::new(&cursor),
+            semantic_tokens_provider: SemanticTokensProvider::new(&cursor),
+            inlay_hints_provider: InlayHintsProvider::new(&cursor),
+            debug_output: vec![],
             builtin_types: Default::default(),
             main_types: MainTypeIndex::new(),
             autogen_type: Default::default(),
@@ -121,6 +142,68 @@ impl ProgramContext {
         }
     }
 
+    pub fn get_warnings(&self) -> &[CompilationError] {
+        self.errors.get_warnings()
+    }
+
+    pub fn lint(&mut self, location: &ItemLocation, kind: LintKind, name: &str) {
+        // only report warnings for the files of the package being compiled, not for the prelude
+        if location.file.path.starts_with(self.options.package.src_path.to_string_lossy().as_ref()) {
+            self.errors.lint(location, kind, name);
+        }
+    }
+
+    pub fn is_strict_none_enabled(&self, location: &ItemLocation) -> bool {
+        // like warnings, `none` checks only apply to the files of the package being compiled
+        self.options.package.strict_none && location.file.path.starts_with(self.options.package.src_path.to_string_lossy().as_ref())
+    }
+
+    pub fn check_optional_assignment(&mut self, location: &ItemLocation, vasm: &Vasm, is_target_optional: bool) {
+        if vasm.is_optional && !is_target_optional && !vasm.ty.is_undefined() && self.is_strict_none_enabled(location) {
+            self.errors.generic(location, format!("expected `{}`, got `{}?`", &vasm.ty, &vasm.ty));
+        }
+    }
+
+    pub fn check_optional_access(&mut self, location: &ItemLocation, vasm: &Vasm) {
+        if vasm.is_optional && !vasm.ty.is_undefined() && self.is_strict_none_enabled(location) {
+            self.errors.generic(location, format!("value of type `{}?` may be `{}`", &vasm.ty, "none".bold()));
+        }
+    }
+
+    pub fn mark_function_as_used(&mut self, function_wrapped: &Link<FunctionBlueprint>) {
+        if self.get_current_function().as_ref() != Some(function_wrapped) {
+            self.used_items.insert(function_wrapped.borrow().name.location.clone());
+        }
+    }
+
+    pub fn mark_type_as_used(&mut self, type_wrapped: &Link<TypeBlueprint>) {
+        if self.get_current_type().as_ref() != Some(type_wrapped) {
+            self.used_items.insert(type_wrapped.borrow().name.location.clone());
+        }
+    }
+
+    fn check_unused_items(&mut self) {
+        for function_wrapped in self.functions.get_all() {
+            let function_unwrapped = function_wrapped.borrow();
+            let is_private_function = function_unwrapped.visibility == Visibility::Private
+                && function_unwrapped.kind == FunctionKind::Standard
+                && function_unwrapped.owner_type.is_none()
+                && function_unwrapped.owner_interface.is_none();
+
+            if is_private_function && !self.used_items.contains(&function_unwrapped.name.location) {
+                self.lint(&function_unwrapped.name, LintKind::UnusedFunction, function_unwrapped.name.as_str());
+            }
+        }
+
+        for type_wrapped in self.types.get_all() {
+            let type_unwrapped = type_wrapped.borrow();
+
+            if type_unwrapped.visibility == Visibility::Private && !self.used_items.contains(&type_unwrapped.name.location) {
+                self.lint(&type_unwrapped.name, LintKind::UnusedType, type_unwrapped.name.as_str());
+            }
+        }
+    }
+
     fn index_builtin_types(&mut self) {
         for builtin_interface in DEFAULT_INTERFACES {
             let interface = self.interfaces.get_by_name(builtin_interface.get_name()).unwrap();
@@ -164,6 +247,14 @@ impl ProgramContext {
         self.get_builtin_type(BuiltinType::Float, vec![])
     }
 
+    pub fn long_type(&self) -> Type {
+        self.get_builtin_type(BuiltinType::Long, vec![])
+    }
+
+    pub fn double_type(&self) -> Type {
+        self.get_builtin_type(BuiltinType::Double, vec![])
+    }
+
     pub fn function_type(&self) -> Type {
         self.get_builtin_type(BuiltinType::Function, vec![])
     }
@@ -272,6 +363,13 @@ impl ProgramContext {
         }
     }
 
+    pub fn is_current_function_return_optional(&self) -> bool {
+        match self.get_current_function() {
+            Some(function_wrapped) => function_wrapped.borrow().is_return_optional,
+            None => true,
+        }
+    }
+
     pub fn push_scope(&mut self, kind: ScopeKind) {
         if kind.is_function() {
             self.function_level += 1;
@@ -297,6 +395,32 @@ impl ProgramContext {
             if scope.kind.is_function() {
                 self.function_level -= 1;
             }
+
+            // a block is always fully executed, so what it has narrowed stays narrowed after it
+            if matches!(scope.kind, ScopeKind::Block) {
+                self.narrow_variables(&scope.narrowed_variables);
+            }
+
+            self.narrow_variables(&scope.suspended_narrowings);
+
+            let mut unused_variable_names = vec![];
+
+            for var_info_list in scope.variables.values() {
+                for var_info in var_info_list {
+                    let name = var_info.name().clone();
+
+                    if var_info.kind().is_local() && !name.as_str().starts_with('_') && !self.used_items.contains(&name.location) {
+                        unused_variable_names.push(name);
+                    }
+                }
+            }
+
+            // the variables are stored in a `HashMap`: sort them so that the warnings are always reported in the same order
+            unused_variable_names.sort_by_key(|name| name.location.start);
+
+            for name in unused_variable_names {
+                self.lint(&name, LintKind::UnusedVariable, name.as_str());
+            }
         }
     }
 
@@ -322,6 +446,124 @@ impl ProgramContext {
         None
     }
 
+    pub fn push_loop_scope(&mut self, label: Option<&str>, is_value_discarded: bool) {
+        self.push_scope(ScopeKind::Loop);
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.label = label.map(|name| name.to_string());
+            scope.is_value_discarded = is_value_discarded;
+        }
+    }
+
+    fn find_loop_scope(&self, label: Option<&str>) -> Option<(usize, u32)> {
+        let mut depth = 0;
+
+        for (index, scope) in self.scopes.iter().enumerate().rev() {
+            if scope.kind == ScopeKind::Loop && (label.is_none() || scope.label.as_deref() == label) {
+                return Some((index, depth));
+            }
+
+            depth += scope.kind.get_depth();
+
+            if scope.kind.is_function() {
+                break;
+            }
+        }
+
+        None
+    }
+
+    pub fn get_loop_scope_depth(&self, label: Option<&str>) -> Option<u32> {
+        self.find_loop_scope(label).map(|(_, depth)| depth)
+    }
+
+    pub fn get_loop_labels(&self) -> Vec<String> {
+        let mut result = vec![];
+
+        for scope in self.scopes.iter().rev() {
+            if let Some(label) = &scope.label {
+                if !result.contains(label) {
+                    result.push(label.clone());
+                }
+            }
+
+            if scope.kind.is_function() {
+                break;
+            }
+        }
+
+        result
+    }
+
+    // the variable holding the value passed to `break`, created by the first `break` that has a value
+    pub fn get_loop_break_variable(&mut self, label: Option<&str>, ty: &Type) -> Option<VariableInfo> {
+        let (index, _) = self.find_loop_scope(label)?;
+        let scope = &mut self.scopes[index];
+
+        if scope.is_value_discarded {
+            return None;
+        }
+
+        Some(scope.break_variable.get_or_insert_with(|| VariableInfo::tmp("loop_result", ty.clone())).clone())
+    }
+
+    pub fn get_current_scope_break_variable(&self) -> Option<VariableInfo> {
+        self.scopes.last().and_then(|scope| scope.break_variable.clone())
+    }
+
+    pub fn narrow_variables(&mut self, variables: &[VariableInfo]) {
+        if let Some(current_scope) = self.scopes.last_mut() {
+            current_scope.narrowed_variables.extend_from_slice(variables);
+        }
+    }
+
+    pub fn unnarrow_variables(&mut self, variables: &[VariableInfo]) {
+        if let Some(current_scope) = self.scopes.last_mut() {
+            for var_info in variables {
+                if let Some(index) = current_scope.narrowed_variables.iter().rposition(|narrowed| narrowed == var_info) {
+                    current_scope.narrowed_variables.remove(index);
+                }
+            }
+        }
+    }
+
+    pub fn forget_narrowing(&mut self, var_info: &VariableInfo) {
+        for scope in self.scopes.iter_mut() {
+            scope.narrowed_variables.retain(|narrowed| narrowed != var_info);
+            scope.suspended_narrowings.retain(|narrowed| narrowed != var_info);
+        }
+    }
+
+    // Called before processing a loop body: a variable assigned in the body may not be narrowed anymore when the next iteration starts
+    pub fn suspend_narrowings(&mut self, variables: &[VariableInfo]) {
+        for var_info in variables {
+            if self.is_variable_narrowed(var_info) {
+                for scope in self.scopes.iter_mut() {
+                    scope.narrowed_variables.retain(|narrowed| narrowed != var_info);
+                }
+
+                if let Some(current_scope) = self.scopes.last_mut() {
+                    current_scope.suspended_narrowings.push(var_info.clone());
+                }
+            }
+        }
+    }
+
+    pub fn get_current_scope_narrowed_variables(&self) -> Vec<VariableInfo> {
+        match self.scopes.last() {
+            Some(scope) => scope.narrowed_variables.clone(),
+            None => vec![],
+        }
+    }
+
+    pub fn is_variable_narrowed(&self, var_info: &VariableInfo) -> bool {
+        self.scopes.iter().any(|scope| scope.narrowed_variables.contains(var_info))
+    }
+
+    pub fn find_var_info(&self, name: &str) -> Option<VariableInfo> {
+        self.scopes.iter().rev().find_map(|scope| scope.get_var_info(name).cloned())
+    }
+
     fn push_var(&mut self, var_info: &VariableInfo) {
         // global scope is handled differently
         if let Some(current_scope) = self.scopes.iter_mut().last() {
@@ -329,6 +571,7 @@ impl ProgramContext {
         }
 
         self.rename_provider.add_occurence(&var_info.name(), &var_info.name());
+        self.semantic_tokens_provider.add_variable_occurence(&var_info.name(), var_info);
         self.hover_provider.set_type(&var_info.name(), &var_info.ty());
     }
 
@@ -471,12 +714,196 @@ impl ProgramContext {
         self.completion_provider = index;
     }
 
+    pub fn add_namespace_completion_area(&mut self, location: &ItemLocation, namespace: Option<(&str, &[String])>) {
+        let mut index = take(&mut self.completion_provider);
+
+        index.add_completion(location, || {
+            let mut available_namespaces = vec![];
+            let mut available_items = vec![];
+
+            match namespace {
+                Some((package_root_path, directories)) => {
+                    for source_file in &self.source_file_list {
+                        if source_file.root_directory_path != package_root_path {
+                            continue;
+                        }
+
+                        let file_directories = get_path_directories(&source_file.file_path, package_root_path);
+
+                        if let Some(subdirectory) = file_directories.get(directories.len()).filter(|_| file_directories.starts_with(directories)) {
+                            if !available_namespaces.contains(subdirectory) {
+                                available_namespaces.push(subdirectory.clone());
+                            }
+                        }
+                    }
+
+                    for type_wrapped in self.types.get_all_from_namespace(package_root_path, directories, location) {
+                        let kind = match type_wrapped.borrow().self_type.is_enum() {
+                            true => CompletionItemKind::Enum,
+                            false => CompletionItemKind::Class,
+                        };
+
+                        available_items.push((type_wrapped.borrow().name.to_string(), kind));
+                    }
+
+                    for typedef_wrapped in self.typedefs.get_all_from_namespace(package_root_path, directories, location) {
+                        available_items.push((typedef_wrapped.borrow().name.to_string(), CompletionItemKind::Class));
+                    }
+
+                    for interface_wrapped in self.interfaces.get_all_from_namespace(package_root_path, directories, location) {
+                        available_items.push((interface_wrapped.borrow().name.to_string(), CompletionItemKind::Interface));
+                    }
+
+                    for function_wrapped in self.functions.get_all_from_namespace(package_root_path, directories, location) {
+                        available_items.push((function_wrapped.borrow().name.to_string(), CompletionItemKind::Function));
+                    }
+
+                    for global_var_wrapped in self.global_vars.get_all_from_namespace(package_root_path, directories, location) {
+                        available_items.push((global_var_wrapped.borrow().name.to_string(), CompletionItemKind::Variable));
+                    }
+                },
+                None => {
+                    available_namespaces.push(PRELUDE_NAMESPACE.to_string());
+                    available_namespaces.push(SELF_NAMESPACE.to_string());
+
+                    for dependency in &self.options.package.dependencies {
+                        available_namespaces.push(dependency.name.clone());
+                    }
+                },
+            }
+
+            CompletionItemGenerator::Namespace(NamespaceCompletionDetails {
+                available_namespaces,
+                available_items,
+            })
+        });
+
+        self.completion_provider = index;
+    }
+
+    pub fn get_namespace_root_path(&self, namespace: &Identifier) -> Option<String> {
+        match namespace.as_str() {
+            PRELUDE_NAMESPACE => Some(self.options.package.get_prelude_src_path()),
+            SELF_NAMESPACE => Some(namespace.location.file.package_root_path.clone()),
+            name => self.options.package.dependencies.iter()
+                .find(|dependency| dependency.name == name)
+                .map(|dependency| dependency.src_path.to_string_lossy().to_string()),
+        }
+    }
+
+    // The first segment of a qualified name is either a namespace or a directory of the current package (`combat::Damage` is `self::combat::Damage`)
+    pub fn get_qualified_import(&self, namespace: &[&Identifier], name: &Identifier) -> Option<ImportDetails> {
+        let (first, rest) = namespace.split_first()?;
+        let mut directories = vec![];
+        let package_root_path = match self.get_namespace_root_path(first) {
+            Some(package_root_path) => package_root_path,
+            None => {
+                directories.push(first.to_string());
+
+                if !self.has_directory(&first.location.file.package_root_path, &directories) {
+                    return None;
+                }
+
+                first.location.file.package_root_path.clone()
+            },
+        };
+
+        directories.extend(rest.iter().map(|segment| segment.to_string()));
+
+        Some(ImportDetails {
+            location: name.location.clone(),
+            name: name.to_string(),
+            package_root_path,
+            directories,
+        })
+    }
+
+    pub fn is_namespace(&self, name: &Identifier) -> bool {
+        self.get_namespace_root_path(name).is_some() || self.has_directory(&name.location.file.package_root_path, &[name.to_string()])
+    }
+
+    pub fn has_directory(&self, package_root_path: &str, directories: &[String]) -> bool {
+        self.source_file_list.iter().any(|source_file| {
+            source_file.root_directory_path == package_root_path && get_path_directories(&source_file.file_path, package_root_path).starts_with(directories)
+        })
+    }
+
+    pub fn add_qualified_name_completion_areas(&mut self, namespace: &[&Identifier], name: &Identifier) {
+        let mut segments = namespace.to_vec();
+
+        segments.push(name);
+        self.add_namespace_completion_area(segments[0], None);
+
+        for i in 1..segments.len() {
+            if let Some(import) = self.get_qualified_import(&segments[..i], segments[i]) {
+                self.add_namespace_completion_area(segments[i], Some((&import.package_root_path, &import.directories)));
+            }
+        }
+    }
+
+    pub fn get_qualified_name(&self, item_location: &ItemLocation, from: &ItemLocation) -> String {
+        let package_root_path = &item_location.file.package_root_path;
+        let namespace = match package_root_path == &from.file.package_root_path {
+            true => SELF_NAMESPACE.to_string(),
+            false => match *package_root_path == self.options.package.get_prelude_src_path() {
+                true => PRELUDE_NAMESPACE.to_string(),
+                false => self.options.package.dependencies.iter()
+                    .find(|dependency| dependency.src_path.to_string_lossy() == package_root_path.as_str())
+                    .map(|dependency| dependency.name.clone())
+                    .unwrap_or_default(),
+            },
+        };
+        let mut segments = vec![namespace];
+
+        segments.extend(get_item_directories(item_location));
+        segments.push(item_location.as_str().to_string());
+        segments.join("::")
+    }
+
+    pub fn add_import(&mut self, import: ImportDetails) {
+        self.types.add_import(import.clone());
+        self.typedefs.add_import(import.clone());
+        self.interfaces.add_import(import.clone());
+        self.functions.add_import(import.clone());
+        self.global_vars.add_import(import);
+    }
+
+    pub fn get_imported_item_location(&self, import: &ImportDetails, check_accessibility: bool) -> Option<ItemLocation> {
+        None
+            .or_else(|| self.types.get_by_import(import, check_accessibility).map(|item| item.borrow().name.location.clone()))
+            .or_else(|| self.typedefs.get_by_import(import, check_accessibility).map(|item| item.borrow().name.location.clone()))
+            .or_else(|| self.interfaces.get_by_import(import, check_accessibility).map(|item| item.borrow().name.location.clone()))
+            .or_else(|| self.functions.get_by_import(import, check_accessibility).map(|item| item.borrow().name.location.clone()))
+            .or_else(|| self.global_vars.get_by_import(import, check_accessibility).map(|item| item.borrow().name.location.clone()))
+    }
+
+    fn check_ambiguous_references(&mut self) {
+        let mut ambiguous_references = self.types.take_ambiguous_references();
+
+        ambiguous_references.extend(self.typedefs.take_ambiguous_references());
+        ambiguous_references.extend(self.interfaces.take_ambiguous_references());
+        ambiguous_references.extend(self.functions.take_ambiguous_references());
+        ambiguous_references.extend(self.global_vars.take_ambiguous_references());
+
+        for (location, candidates) in ambiguous_references {
+            let candidate_names : IndexSet<String> = candidates.iter().map(|candidate| format!("`{}`", self.get_qualified_name(candidate, &location))).collect();
+            let candidate_list : Vec<String> = candidate_names.into_iter().collect();
+
+            self.errors.generic(&location, format!("ambiguous name `{}`, which can refer to {}; add a `use` declaration to pick one", location.as_str(), candidate_list.join(" or ")));
+        }
+    }
+
     pub fn declare_local_variable(&mut self, name: Identifier, ty: Type) -> VariableInfo {
         let kind = match self.scopes.last() {
             Some(_) => VariableKind::Local,
             None => VariableKind::Global,
         };
         let suffix = self.iter_fields_counter.clone();
+
+        if kind.is_local() && self.get_local_var_info(name.as_str()).is_some() {
+            self.lint(&name, LintKind::ShadowedVariable, name.as_str());
+        }
+
         let var_info = VariableInfo::create(name, ty, kind, self.get_function_level(), suffix);
 
         self.push_var(&var_info);
@@ -504,6 +931,8 @@ impl ProgramContext {
             for arg_info in &function_unwrapped.arguments {
                 let var_info = VariableInfo::create(arg_info.name.clone(), arg_info.ty.clone(), VariableKind::Argument, self.get_function_level(), None);
 
+                var_info.set_optional(arg_info.is_optional);
+
                 self.push_var(&var_info);
                 variables.push(var_info);
             }
@@ -512,6 +941,20 @@ impl ProgramContext {
         });
     }
 
+    fn get_local_var_info(&self, name: &str) -> Option<&VariableInfo> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(var_info) = scope.get_var_info(name) {
+                return Some(var_info);
+            }
+
+            if scope.kind.is_function() {
+                break;
+            }
+        }
+
+        None
+    }
+
     pub fn access_var(&mut self, name: &Identifier) -> Option<VariableInfo> {
         let mut closure_access = false;
         let mut result = None;
@@ -534,6 +977,7 @@ impl ProgramContext {
                     }
                 }
 
+                self.used_items.insert(var_info.name().location.clone());
                 result = Some(var_info.clone());
                 break;
             }
@@ -550,22 +994,35 @@ impl ProgramContext {
         }
 
         if let Some(var_info) = &result {
-            match var_info.borrow().name.as_str() {
-                SELF_VAR_NAME | EVENT_VAR_NAME => {
-                    self.definition_provider.set_definition(name, &var_info.ty().get_type_blueprint().borrow().name);
-                },
-                _ => {
-                    self.rename_provider.add_occurence(name, &var_info.name());
-                    self.definition_provider.set_definition(name, &var_info.name());
-                }
-            };
-
-            self.hover_provider.set_type(name, &var_info.ty());
+            self.add_var_access(name, var_info);
         }
 
         result
     }
 
+    pub fn access_global_var(&mut self, name: &Identifier, import: &ImportDetails) -> Option<VariableInfo> {
+        let var_info = self.global_vars.get_by_import(import, true)?.borrow().var_info.clone();
+
+        self.add_var_access(name, &var_info);
+
+        Some(var_info)
+    }
+
+    fn add_var_access(&mut self, name: &Identifier, var_info: &VariableInfo) {
+        match var_info.borrow().name.as_str() {
+            SELF_VAR_NAME | EVENT_VAR_NAME => {
+                self.definition_provider.set_definition(name, &var_info.ty().get_type_blueprint().borrow().name);
+            },
+            _ => {
+                self.rename_provider.add_occurence(name, &var_info.name());
+                self.definition_provider.set_definition(name, &var_info.name());
+            }
+        };
+
+        self.semantic_tokens_provider.add_variable_occurence(name, var_info);
+        self.hover_provider.set_type(name, &var_info.ty());
+    }
+
     pub fn get_type_instance(&mut self, parameters: TypeInstanceParameters) -> Rc<TypeInstanceHeader> {
         let id = parameters.get_id();
 
@@ -671,47 +1128,22 @@ impl ProgramContext {
     }
 
     pub fn parse_source_files(&mut self, provided_cache: Option<&mut FileSystemCache<ParsedSourceFile, ParseError>>) {
+        for error in &self.options.package.errors {
+            self.errors.generic(&error.location, error.message.clone());
+        }
+
         let directories = self.options.package.get_source_directories();
 
         for details in directories {
-            let mut path_list = vec![];
-            let exclude = &details.exclude;
-
-            for file_path in read_directory_recursively(Path::new(&details.root_path)) {
-                let mut ok = false;
-
-                if let Some(extension) = file_path.extension() {
-                    if extension == SOURCE_FILE_EXTENSION {
-                        ok = true;
-                    }
-                }
-
-                if ok {
-                    let base = file_path.strip_prefix(&details.root_path).unwrap();
-                    let first = base.components().next().unwrap().as_os_str().to_str().unwrap();
-                    
-                    if exclude.contains(&first) {
-                        ok = false;
-                    }
-                }
-
-                if ok {
-                    path_list.push(file_path.to_string_lossy().to_string());
-                }
-            }
-
-            path_list.sort();
-
-            for file_path in path_list {
-                self.source_file_list.push(SourceFileDetails {
-                    file_path,
-                    root_directory_path: details.root_path.clone(),
-                });
-            }
+            self.source_file_list.extend(details.get_source_files());
         }
 
         let mut empty_cache = FileSystemCache::new();
         let cache = provided_cache.unwrap_or(&mut empty_cache);
+        let parse_cache = match self.options.use_parse_cache {
+            true => Some(ParseCache::new(&self.options.package.cache_path)),
+            false => None,
+        };
 
         let mut processed_count = 0;
 
@@ -727,7 +1159,10 @@ impl ProgramContext {
 
                 processed_count += 1;
 
-                ParsedSourceFile::parse(file_content, parse_options)
+                match &parse_cache {
+                    Some(parse_cache) => parse_cache.parse(file_content, parse_options),
+                    None => ParsedSourceFile::parse(file_content, parse_options),
+                }
             };
 
             let result = cache.read_file(file_path, parse_function);
@@ -754,6 +1189,7 @@ impl ProgramContext {
         let mut typedefs = vec![];
         let mut functions = vec![];
         let mut global_vars = vec![];
+        let mut use_declarations = vec![];
 
         let parsed_source_files = take(&mut self.parsed_source_files);
 
@@ -767,11 +1203,20 @@ impl ProgramContext {
                     ParsedTopLevelBlock::TypedefDeclaration(typedef_declaration) => typedefs.push(typedef_declaration),
                     ParsedTopLevelBlock::FunctionDeclaration(function_declaration) => functions.push(function_declaration),
                     ParsedTopLevelBlock::GlobalDeclaration(global_declaration) => global_vars.push(global_declaration),
+                    ParsedTopLevelBlock::UseDeclaration(use_declaration) => use_declarations.push(use_declaration),
                     ParsedTopLevelBlock::InProgress(block) => block.process(self),
                 }
             }
         }
 
+        timer.trigger("imports");
+        let mut imports = vec![];
+        for use_declaration in &use_declarations {
+            if let Some(import) = use_declaration.process(self) {
+                imports.push((use_declaration, import));
+            }
+        }
+
         timer.trigger("root tags");
         for root_tag_declaration in &root_tags_declarations {
             root_tag_declaration.process(self);
@@ -932,6 +1377,11 @@ impl ProgramContext {
             global_var_declaration.process(self);
         }
 
+        timer.trigger("imports check");
+        for (use_declaration, import) in &imports {
+            use_declaration.check(import, self);
+        }
+
         timer.trigger("type field default values");
         for type_declaration in &types {
             type_declaration.process_fields_default_values(self);
@@ -977,6 +1427,12 @@ impl ProgramContext {
             type_declaration.process_event_callbacks(self);
         }
 
+        timer.trigger("ambiguous references");
+        self.check_ambiguous_references();
+
+        timer.trigger("unused items");
+        self.check_unused_items();
+
         // timer.display();
 
         self.parsed_source_files = parsed_source_files;
@@ -1286,7 +1742,15 @@ impl ProgramContext {
     }
 
     pub fn generate_output_file(&mut self) {
-        self.output_file = self.output_wat.to_string(0);
+        self.output_file = self.output_wat.to_string(0).into_bytes();
+    }
+
+    pub fn encode_output_file(&mut self) {
+        self.output_file = WasmBinaryEncoder::new().encode(&self.output_wat);
+    }
+
+    pub fn get_output_file(&self) -> &[u8] {
+        &self.output_file
     }
 
     pub fn write_output_file(&self, output_file_path: &str) {
@@ -1298,7 +1762,7 @@ impl ProgramContext {
 
         let mut file = File::create(path).unwrap();
 
-        file.write_all(self.output_file.as_bytes()).unwrap();
+        file.write_all(&self.output_file).unwrap();
     }
 
     pub fn destroy(mut self) {
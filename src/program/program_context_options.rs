@@ -18,6 +18,7 @@ pub struct ProgramContextOptions {
     pub package: Package,
     pub mode: ProgramContextMode,
     pub cursor_location: Option<CursorLocation>,
+    pub use_parse_cache: bool,
 }
 
 impl ProgramContextOptions {
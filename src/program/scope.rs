@@ -6,6 +6,13 @@ use super::{FunctionBlueprint, InterfaceBlueprint, TypeBlueprint, VariableInfo,
 pub struct Scope {
     pub kind: ScopeKind,
     pub variables: HashMap<String, Vec<VariableInfo>>,
+    pub narrowed_variables: Vec<VariableInfo>,
+    // narrowings from outside a loop that its body may invalidate, restored after the loop if they still hold
+    pub suspended_narrowings: Vec<VariableInfo>,
+    pub label: Option<String>,
+    pub break_variable: Option<VariableInfo>,
+    // a loop whose value is discarded cannot be exited with `break <value>`
+    pub is_value_discarded: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -24,7 +31,7 @@ impl Scope {
 
         let a = 6;
 
-        Self { kind, variables }
+        Self { kind, variables, narrowed_variables: vec![], suspended_narrowings: vec![], label: None, break_variable: None, is_value_discarded: false }
     }
 
     pub fn get_var_info(&self, var_name: &str) -> Option<&VariableInfo> {
@@ -1,7 +1,46 @@
 use std::path::{Path, PathBuf};
+use crate::utils::read_directory_recursively;
+use super::{SourceFileDetails, SOURCE_FILE_EXTENSION};
 
 #[derive(Debug)]
 pub struct SourceDirectory {
     pub root_path: String,
+    pub package_root_path: String,
     pub exclude: Vec<&'static str>
+}
+
+impl SourceDirectory {
+    pub fn get_source_files(&self) -> Vec<SourceFileDetails> {
+        let mut path_list = vec![];
+
+        for file_path in read_directory_recursively(Path::new(&self.root_path)) {
+            let mut ok = false;
+
+            if let Some(extension) = file_path.extension() {
+                if extension == SOURCE_FILE_EXTENSION {
+                    ok = true;
+                }
+            }
+
+            if ok {
+                let base = file_path.strip_prefix(&self.root_path).unwrap();
+                let first = base.components().next().unwrap().as_os_str().to_str().unwrap();
+                
+                if self.exclude.contains(&first) {
+                    ok = false;
+                }
+            }
+
+            if ok {
+                path_list.push(file_path.to_string_lossy().to_string());
+            }
+        }
+
+        path_list.sort();
+
+        path_list.into_iter().map(|file_path| SourceFileDetails {
+            file_path,
+            root_directory_path: self.package_root_path.clone(),
+        }).collect()
+    }
 }
\ No newline at end of file
@@ -158,6 +158,14 @@ impl Type {
         self.is_builtin_type(BuiltinType::Float)
     }
 
+    pub fn is_long(&self) -> bool {
+        self.is_builtin_type(BuiltinType::Long)
+    }
+
+    pub fn is_double(&self) -> bool {
+        self.is_builtin_type(BuiltinType::Double)
+    }
+
     pub fn is_string(&self) -> bool {
         self.is_builtin_type(BuiltinType::String)
     }
@@ -179,6 +187,15 @@ impl Type {
         }
     }
 
+    pub fn is_parameter(&self) -> bool {
+        match self.content() {
+            TypeContent::TypeParameter(_) => true,
+            TypeContent::FunctionParameter(_) => true,
+            TypeContent::Associated(_) => true,
+            _ => false
+        }
+    }
+
     pub fn to_type_hint(&self) -> Option<&Self> {
         match self.is_undefined() {
             true => None,
@@ -580,7 +597,8 @@ impl Type {
                                     let expected_type = expected_arg_type.replace_parameters(Some(self), &[]);
                                     let actual_type = actual_arg_type.replace_parameters(Some(self), &[]);
 
-                                    if !actual_type.is_assignable_to(&expected_type) && !actual_type.is_undefined() {
+                                    // an inherited method can take a parent type of the expected one
+                                    if !actual_type.is_assignable_to(&expected_type) && !expected_type.is_assignable_to(&actual_type) && !actual_type.is_undefined() {
                                         details.push(format!("method `{}`, argument #{}: expected {}, got `{}`", expected_method_unwrapped.name.as_str().bold(), i + 1, expected_type, &actual_type));
                                     }
                                 }
@@ -600,8 +618,8 @@ impl Type {
 
                 details.is_empty()
             },
-            TypeContent::TypeParameter(info) | TypeContent::FunctionParameter(info) => info.required_interfaces.contains(interface),
-            TypeContent::Associated(info) => info.associated.required_interfaces.contains(interface),
+            TypeContent::TypeParameter(info) | TypeContent::FunctionParameter(info) => info.required_interfaces.contains(interface) || context.default_interfaces.contains(interface),
+            TypeContent::Associated(info) => info.associated.required_interfaces.contains(interface) || context.default_interfaces.contains(interface),
             TypeContent::Function(_) => false,
         };
 
@@ -733,6 +751,13 @@ impl Type {
         }
     }
 
+    pub fn get_variant_type(&self, variant_info: &EnumVariantInfo) -> Option<Type> {
+        match (self.content(), &variant_info.variant_type) {
+            (TypeContent::Actual(info), Some(variant_type)) => Some(Type::actual(variant_type, info.parameters.clone(), &info.location)),
+            _ => None
+        }
+    }
+
     pub fn get_all_variants(&self) -> Vec<Rc<EnumVariantInfo>> {
         match self.content() {
             TypeContent::Undefined => vec![],
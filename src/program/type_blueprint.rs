@@ -30,6 +30,8 @@ pub struct TypeBlueprint {
 pub enum WasmStackType {
     I32,
     F32,
+    I64,
+    F64,
     Void
 }
 
@@ -44,6 +46,8 @@ pub struct EnumVariantInfo {
     pub owner: Link<TypeBlueprint>,
     pub name: Identifier,
     pub value: usize,
+    // Class holding the fields of the variant, for the enums whose variants carry fields
+    pub variant_type: Option<Link<TypeBlueprint>>,
 }
 
 #[derive(Debug)]
@@ -52,9 +56,9 @@ pub struct FieldInfo {
     pub name: Identifier,
     pub ty: Type,
     pub visibility: FieldVisibility,
-    pub offset: usize,
     pub default_value: Option<Link<FunctionBlueprint>>,
-    pub is_required: bool
+    pub is_required: bool,
+    pub is_optional: bool
 }
 
 #[derive(Debug)]
@@ -77,6 +81,8 @@ impl TypeBlueprint {
         match self.stack_type {
             WasmStackType::I32 => Some("i32"),
             WasmStackType::F32 => Some("f32"),
+            WasmStackType::I64 => Some("i64"),
+            WasmStackType::F64 => Some("f64"),
             WasmStackType::Void => None,
         }
     }
@@ -138,6 +144,16 @@ impl FieldInfo {
     }
 }
 
+impl WasmStackType {
+    // Number of 32-bits memory cells taken by a value of this type; 64-bits values take two
+    pub fn get_cell_count(&self) -> usize {
+        match self {
+            WasmStackType::I64 | WasmStackType::F64 => 2,
+            _ => 1
+        }
+    }
+}
+
 impl GlobalItem for TypeBlueprint {
     fn get_name(&self) -> &Identifier { &self.name }
     fn get_visibility(&self) -> Visibility { self.visibility }
@@ -25,6 +25,18 @@ impl TypeInstanceContent {
             }
         });
 
+        // Variants of an enum are instanced along with it, so they can be created dynamically (e.g by deserialization)
+        let variant_types : Vec<Link<TypeBlueprint>> = header.type_blueprint.with_ref(|type_unwrapped| {
+            type_unwrapped.enum_variants.values().filter_map(|variant_info| variant_info.variant_type.clone()).collect()
+        });
+
+        for variant_type in variant_types {
+            context.get_type_instance(TypeInstanceParameters {
+                type_blueprint: variant_type,
+                type_parameters: instance_parameters.type_parameters.clone(),
+            });
+        }
+
         // let mut ancestors = vec![];
         // let type_index = TypeIndex {
         //     current_type_instance: Some(header.clone()),
@@ -98,6 +98,39 @@ impl TypeInstanceHeader {
         })
     }
 
+    // Fields are laid out in declaration order, each taking as many cells as its resolved type requires
+    pub fn get_field_offset(&self, field_name: &str, context: &mut ProgramContext) -> usize {
+        let mut offset = OBJECT_HEADER_SIZE;
+
+        for (name, field_type) in self.get_field_types() {
+            if name == field_name {
+                return offset;
+            }
+
+            offset += get_type_cell_count(&field_type, context);
+        }
+
+        panic!("type `{}` has no field `{}`", &self.name, field_name);
+    }
+
+    pub fn get_object_size(&self, context: &mut ProgramContext) -> usize {
+        let mut size = OBJECT_HEADER_SIZE;
+
+        for (_, field_type) in self.get_field_types() {
+            size += get_type_cell_count(&field_type, context);
+        }
+
+        size
+    }
+
+    fn get_field_types(&self) -> Vec<(String, Type)> {
+        self.type_blueprint.with_ref(|type_unwrapped| {
+            type_unwrapped.fields.values()
+                .map(|field_info| (field_info.name.to_string(), field_info.ty.replace_parameters(Some(&self.ty), &[])))
+                .collect()
+        })
+    }
+
     pub fn get_type_id(&self) -> usize {
         self.dynamic_method_table_offset
     }
@@ -105,4 +138,8 @@ impl TypeInstanceHeader {
     pub fn has_wasm_type(&self) -> bool {
         self.wasm_type.is_some()
     }
+}
+
+fn get_type_cell_count(ty: &Type, context: &mut ProgramContext) -> usize {
+    ty.resolve(&TypeIndex::empty(), context).type_blueprint.borrow().stack_type.get_cell_count()
 }
\ No newline at end of file
@@ -12,6 +12,7 @@ pub struct VariableInfoContent {
     pub wasm_name: String,
     pub declaration_level: u32,
     pub is_closure_arg: bool,
+    pub is_optional: bool,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -48,7 +49,8 @@ impl VariableInfo {
     pub fn create(name: Identifier, ty: Type, kind: VariableKind, declaration_level: u32, suffix: Option<usize>) -> Self {
         let wasm_name = make_wasm_name(&name, suffix);
         let is_closure_arg = false;
-        let content = VariableInfoContent { name, ty, kind, declaration_level, wasm_name, is_closure_arg };
+        let is_optional = false;
+        let content = VariableInfoContent { name, ty, kind, declaration_level, wasm_name, is_closure_arg, is_optional };
 
         Link::new(content)
     }
@@ -59,7 +61,8 @@ impl VariableInfo {
         let declaration_level = u32::MAX;
         let kind = VariableKind::Local;
         let is_closure_arg = false;
-        let content = VariableInfoContent { name, ty, kind, declaration_level, wasm_name, is_closure_arg };
+        let is_optional = false;
+        let content = VariableInfoContent { name, ty, kind, declaration_level, wasm_name, is_closure_arg, is_optional };
 
         Link::new(content)
     }
@@ -94,6 +97,16 @@ impl VariableInfo {
         });
     }
 
+    pub fn is_optional(&self) -> bool {
+        self.borrow().is_optional
+    }
+
+    pub fn set_optional(&self, is_optional: bool) {
+        self.with_mut(|mut var_info| {
+            var_info.is_optional = is_optional;
+        });
+    }
+
     pub fn mark_as_closure_arg(&self) {
         self.with_mut(|mut var_info| {
             if !var_info.kind.is_global() {
@@ -145,7 +158,8 @@ impl Default for VariableInfo {
             kind: VariableKind::Local,
             declaration_level: u32::MAX,
             wasm_name: String::new(),
-            is_closure_arg: false
+            is_closure_arg: false,
+            is_optional: false
         })
     }
 }
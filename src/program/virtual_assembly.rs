@@ -8,6 +8,8 @@ pub type Vasm = VirtualAssembly;
 #[derive(Debug, Clone)]
 pub struct VirtualAssembly {
     pub ty: Type,
+    // whether the value may be `none` (only checked in packages with `strict-none` enabled)
+    pub is_optional: bool,
     content: Option<VirtualAssemblyContent>
 }
 
@@ -28,7 +30,7 @@ impl VirtualAssembly {
             false => None,
         };
 
-        Self { ty, content }
+        Self { ty, is_optional: false, content }
     }
 
     pub fn destroy(&mut self) {
@@ -48,6 +50,7 @@ impl VirtualAssembly {
 
     pub fn append(mut self, other: Self) -> Self {
         self.ty = other.ty;
+        self.is_optional = other.is_optional;
 
         if let (Some(self_content), Some(other_content)) = (&mut self.content.as_mut(), other.content) {
             self_content.variables.extend(other_content.variables);
@@ -63,6 +66,12 @@ impl VirtualAssembly {
 
     pub fn set_type<T : Borrow<Type>>(mut self, ty: T) -> Self {
         self.ty = ty.borrow().clone();
+        self.is_optional = false;
+        self
+    }
+
+    pub fn set_optional(mut self, is_optional: bool) -> Self {
+        self.is_optional = is_optional;
         self
     }
 
@@ -121,10 +130,22 @@ impl VirtualAssembly {
         self.instruction(|| VirtualInstruction::FloatConstant(value))
     }
 
+    pub fn long(self, value: i64) -> Self {
+        self.instruction(|| VirtualInstruction::LongConstant(value))
+    }
+
+    pub fn double(self, value: f64) -> Self {
+        self.instruction(|| VirtualInstruction::DoubleConstant(value))
+    }
+
     pub fn type_id(self, ty: &Type) -> Self {
         self.instruction(|| VirtualInstruction::TypeId(ty.clone()))
     }
 
+    pub fn object_size(self, ty: &Type) -> Self {
+        self.instruction(|| VirtualInstruction::ObjectSize(ty.clone()))
+    }
+
     pub fn type_name(self, ty: &Type, include_package: bool) -> Self {
         self.instruction(|| VirtualInstruction::TypeName(TypeNameDetails {
             ty: ty.clone(),
@@ -270,21 +291,23 @@ impl VirtualAssembly {
         }))
     }
 
-    pub fn get_field(self, field_type: &Type, field_offset: usize, check_message: Option<&ItemLocation>) -> Self {
+    pub fn get_field(self, object_type: &Type, field_name: &str, field_type: &Type, check_message: Option<&ItemLocation>) -> Self {
         self.instruction(|| VirtualInstruction::FieldAccess(VirtualAccessFieldInfo {
             acess_kind: FieldAccessKind::Get,
+            object_type: object_type.clone(),
+            field_name: field_name.to_string(),
             field_type: field_type.clone(),
-            field_offset,
             check_location: check_message.cloned(),
             value: None,
         }))
     }
 
-    pub fn set_field(self, field_type: &Type, field_offset: usize, check_message: Option<&ItemLocation>, value: Vasm) -> Self {
+    pub fn set_field(self, object_type: &Type, field_name: &str, field_type: &Type, check_message: Option<&ItemLocation>, value: Vasm) -> Self {
         self.instruction(|| VirtualInstruction::FieldAccess(VirtualAccessFieldInfo {
             acess_kind: FieldAccessKind::Set,
+            object_type: object_type.clone(),
+            field_name: field_name.to_string(),
             field_type: field_type.clone(),
-            field_offset,
             check_location: check_message.cloned(),
             value: Some(value),
         }))
@@ -402,6 +425,6 @@ impl VirtualAssembly {
             });
         }
 
-        Self { ty, content }
+        Self { ty, is_optional: self.is_optional, content }
     }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use std::rc::Rc;
 use parsable::ItemLocation;
-use crate::{items::{Identifier, make_string_value_from_literal, make_string_value_from_literal_unchecked}, program::{BuiltinType, CLOSURE_TMP_VAR_NAME, CLOSURE_VARIABLES_TMP_VAR_NAME, CLOSURE_VARIABLES_VAR_NAME, DUPLICATE_INT_WASM_FUNC_NAME, FieldKind, FunctionInstanceParameters, GeneratedItemIndex, ItemGenerator, LOAD_FLOAT_WASM_FUNC_NAME, LOAD_INT_WASM_FUNC_NAME, MEMORY_CELL_BYTE_SIZE, MEM_ALLOC_FUNC_NAME, NEW_METHOD_NAME, NONE_METHOD_NAME, OBJECT_HEADER_SIZE, RETAIN_METHOD_NAME, STORE_FLOAT_WASM_FUNC_NAME, STORE_INT_WASM_FUNC_NAME, SWAP_FLOAT_INT_WASM_FUNC_NAME, SWAP_INT_INT_WASM_FUNC_NAME, SELF_VAR_NAME, TMP_VAR_NAME, TypeInstanceHeader, TypeInstanceParameters}, utils::Link, wat};
+use crate::{items::{Identifier, make_string_value_from_literal, make_string_value_from_literal_unchecked}, program::{BuiltinType, CLOSURE_TMP_VAR_NAME, CLOSURE_VARIABLES_TMP_VAR_NAME, CLOSURE_VARIABLES_VAR_NAME, DUPLICATE_INT_WASM_FUNC_NAME, FieldKind, FunctionInstanceParameters, GeneratedItemIndex, ItemGenerator, LOAD_DOUBLE_WASM_FUNC_NAME, LOAD_FLOAT_WASM_FUNC_NAME, LOAD_INT_WASM_FUNC_NAME, LOAD_LONG_WASM_FUNC_NAME, MEMORY_CELL_BYTE_SIZE, MEM_ALLOC_FUNC_NAME, NEW_METHOD_NAME, NONE_METHOD_NAME, OBJECT_HEADER_SIZE, RETAIN_METHOD_NAME, STORE_DOUBLE_WASM_FUNC_NAME, STORE_FLOAT_WASM_FUNC_NAME, STORE_INT_WASM_FUNC_NAME, STORE_LONG_WASM_FUNC_NAME, SWAP_FLOAT_INT_WASM_FUNC_NAME, SWAP_INT_INT_WASM_FUNC_NAME, SELF_VAR_NAME, TMP_VAR_NAME, TypeInstanceHeader, TypeInstanceParameters}, utils::Link, wat};
 use super::{FunctionBlueprint, FunctionCall, NamedFunctionCallDetails, ProgramContext, ToInt, Type, TypeBlueprint, TypeIndex, VariableInfo, VariableKind, Vasm, Wat, function_blueprint, FunctionKind, CHECK_FIELD_ACCESS_FUNC_NAME};
 
 #[derive(Debug, Clone)]
@@ -13,7 +13,10 @@ pub enum VirtualInstruction {
     Return(Vasm),
     IntConstant(i32),
     FloatConstant(f32),
+    LongConstant(i64),
+    DoubleConstant(f64),
     TypeId(Type),
+    ObjectSize(Type),
     TypeName(TypeNameDetails),
     InitVariable(VirtualInitVariableInfo),
     VariableAccess(VirtualVariableAccessInfo),
@@ -74,8 +77,9 @@ pub struct VirtualCreateObjectInfo {
 #[derive(Debug, Clone)]
 pub struct VirtualAccessFieldInfo {
     pub acess_kind: FieldAccessKind,
+    pub object_type: Type,
+    pub field_name: String,
     pub field_type: Type,
-    pub field_offset: usize,
     pub value: Option<Vasm>,
     pub check_location: Option<ItemLocation>,
 }
@@ -135,7 +139,10 @@ impl VirtualInstruction {
             VirtualInstruction::Return(ret) => ret.collect_variables(list),
             VirtualInstruction::IntConstant(_) => {},
             VirtualInstruction::FloatConstant(_) => {},
+            VirtualInstruction::LongConstant(_) => {},
+            VirtualInstruction::DoubleConstant(_) => {},
             VirtualInstruction::TypeId(_) => {},
+            VirtualInstruction::ObjectSize(_) => {},
             VirtualInstruction::TypeName(_) => {},
             VirtualInstruction::InitVariable(info) => {},
             VirtualInstruction::VariableAccess(info) => info.value.iter().for_each(|vasm| vasm.collect_variables(list)),
@@ -171,7 +178,10 @@ impl VirtualInstruction {
             VirtualInstruction::Return(_) => {},
             VirtualInstruction::IntConstant(_) => {},
             VirtualInstruction::FloatConstant(_) => {},
+            VirtualInstruction::LongConstant(_) => {},
+            VirtualInstruction::DoubleConstant(_) => {},
             VirtualInstruction::TypeId(_) => {},
+            VirtualInstruction::ObjectSize(_) => {},
             VirtualInstruction::TypeName(_) => {},
             VirtualInstruction::InitVariable(_) => {},
             VirtualInstruction::VariableAccess(info) => info.value.iter_mut().for_each(|vasm| vasm.replace_placeholder(location, replacement)),
@@ -219,11 +229,22 @@ impl VirtualInstruction {
             VirtualInstruction::FloatConstant(value) => vec![
                 Wat::const_f32(*value)
             ],
+            VirtualInstruction::LongConstant(value) => vec![
+                Wat::const_i64(*value)
+            ],
+            VirtualInstruction::DoubleConstant(value) => vec![
+                Wat::const_f64(*value)
+            ],
             VirtualInstruction::TypeId(ty) => {
                 let type_instance = ty.resolve(type_index, context);
 
                 vec![Wat::const_i32(type_instance.dynamic_method_table_offset)]
             },
+            VirtualInstruction::ObjectSize(ty) => {
+                let type_instance = ty.resolve(type_index, context);
+
+                vec![Wat::const_i32(type_instance.get_object_size(context))]
+            },
             VirtualInstruction::TypeName(details) => {
                 let resolved_type = details.ty.resolve(type_index, context);
                 let type_name = resolved_type.ty.to_string();
@@ -248,22 +269,20 @@ impl VirtualInstruction {
                             true => {
                                 let mut content = vec![];
 
-                                let (store_func_name, convert_wat) = match wasm_type {
-                                    "i32" => (STORE_INT_WASM_FUNC_NAME, vec![]),
-                                    "f32" => (STORE_FLOAT_WASM_FUNC_NAME, vec![wat!["f32.reinterpret_i32"]]),
-                                    _ => unreachable!()
-                                };
+                                let (_, store_func_name) = get_closure_arg_access_func_names(wasm_type);
+                                let convert_wat = convert_address_to_wasm_type(wasm_type);
+                                let cell_count = get_wasm_type_cell_count(wasm_type);
 
                                 match var_info.kind {
                                     VariableKind::Global => unreachable!(),
                                     VariableKind::Local => {
-                                        content.push(Wat::call(MEM_ALLOC_FUNC_NAME, vec![Wat::const_i32(1i32)]));
+                                        content.push(Wat::call(MEM_ALLOC_FUNC_NAME, vec![Wat::const_i32(cell_count)]));
                                         content.extend(convert_wat);
                                         content.push(Wat::set_local_from_stack(&var_info.wasm_name));
                                     },
                                     VariableKind::Argument => {
                                         content.extend(vec![
-                                            Wat::call(MEM_ALLOC_FUNC_NAME, vec![Wat::const_i32(1i32)]),
+                                            Wat::call(MEM_ALLOC_FUNC_NAME, vec![Wat::const_i32(cell_count)]),
                                             Wat::set_global_from_stack(TMP_VAR_NAME),
                                             Wat::get_global(TMP_VAR_NAME),
                                             Wat::get_local(&var_info.wasm_name),
@@ -300,24 +319,15 @@ impl VirtualInstruction {
                     Some(wasm_type) => info.var_info.with_ref(|var_info| {
                         match var_info.is_closure_arg && info.access_level.is_some() {
                             true => {
+                                let (load_func_name, store_func_name) = get_closure_arg_access_func_names(wasm_type);
                                 let access_func_name = match info.access_kind {
-                                    VariableAccessKind::Get => match wasm_type {
-                                        "i32" => LOAD_INT_WASM_FUNC_NAME,
-                                        "f32" => LOAD_FLOAT_WASM_FUNC_NAME,
-                                        _ => unreachable!()
-                                    },
-                                    VariableAccessKind::Set | VariableAccessKind::Tee => match wasm_type {
-                                        "i32" => STORE_INT_WASM_FUNC_NAME,
-                                        "f32" => STORE_FLOAT_WASM_FUNC_NAME,
-                                        _ => unreachable!()
-                                    },
+                                    VariableAccessKind::Get => load_func_name,
+                                    VariableAccessKind::Set | VariableAccessKind::Tee => store_func_name,
                                 };
 
                                 if info.access_level.as_ref().is_some_and(|content| *content == var_info.declaration_level) {
                                     content.push(Wat::get_local(&var_info.wasm_name));
-                                    if wasm_type == "f32" {
-                                        content.push(wat!["i32.reinterpret_f32"]);
-                                    }
+                                    content.extend(convert_wasm_type_to_address(wasm_type));
                                     content.extend(value_wat);
                                     content.push(Wat::call_from_stack(access_func_name));
                                 } else {
@@ -369,8 +379,10 @@ impl VirtualInstruction {
                         FieldAccessKind::Set => "store",
                     };
 
+                    let field_offset = info.object_type.resolve(type_index, context).get_field_offset(&info.field_name, context);
+
                     content.extend(vec![
-                        wat!["i32.add", Wat::const_i32(info.field_offset)],
+                        wat!["i32.add", Wat::const_i32(field_offset)],
                         wat!["i32.mul", Wat::const_i32(4i32)]
                     ]);
 
@@ -553,11 +565,8 @@ impl VirtualInstruction {
 
                             for var_info in &details.variables {
                                 if let Some(wasm_type) = var_info.ty().resolve(type_index, context).wasm_type {
-                                    let convert_vasm = match wasm_type {
-                                        "i32" => context.vasm(),
-                                        "f32" => context.vasm().raw(wat!["i32.reinterpret_f32"]),
-                                        _ => unreachable!()
-                                    };
+                                    let convert_vasm = convert_wasm_type_to_address(wasm_type).into_iter()
+                                        .fold(context.vasm(), |vasm, wat| vasm.raw(wat));
 
                                     vasm = vasm
                                         .raw(Wat::get_global(CLOSURE_VARIABLES_TMP_VAR_NAME))
@@ -676,7 +685,10 @@ impl VirtualInstruction {
             VirtualInstruction::Return(_) => unreachable!(),
             VirtualInstruction::IntConstant(_) => unreachable!(),
             VirtualInstruction::FloatConstant(_) => unreachable!(),
+            VirtualInstruction::LongConstant(_) => unreachable!(),
+            VirtualInstruction::DoubleConstant(_) => unreachable!(),
             VirtualInstruction::TypeId(_) => unreachable!(),
+            VirtualInstruction::ObjectSize(_) => unreachable!(),
             VirtualInstruction::TypeName(_) => unreachable!(),
             VirtualInstruction::InitVariable(_) => unreachable!(),
             VirtualInstruction::VariableAccess(_) => unreachable!(),
@@ -701,7 +713,10 @@ impl VirtualInstruction {
             VirtualInstruction::Return(_) => None,
             VirtualInstruction::IntConstant(value) => Some(Wat::const_i32(*value)),
             VirtualInstruction::FloatConstant(value) => Some(Wat::const_f32(*value)),
+            VirtualInstruction::LongConstant(value) => Some(Wat::const_i64(*value)),
+            VirtualInstruction::DoubleConstant(value) => Some(Wat::const_f64(*value)),
             VirtualInstruction::TypeId(_) => None,
+            VirtualInstruction::ObjectSize(_) => None,
             VirtualInstruction::TypeName(_) => None,
             VirtualInstruction::InitVariable(_) => None,
             VirtualInstruction::VariableAccess(_) => None,
@@ -726,7 +741,10 @@ impl VirtualInstruction {
             VirtualInstruction::Return(vasm) => VirtualInstruction::Return(vasm.replace_parameters(this_type, function_parameters)),
             VirtualInstruction::IntConstant(value) => VirtualInstruction::IntConstant(*value),
             VirtualInstruction::FloatConstant(value) => VirtualInstruction::FloatConstant(*value),
+            VirtualInstruction::LongConstant(value) => VirtualInstruction::LongConstant(*value),
+            VirtualInstruction::DoubleConstant(value) => VirtualInstruction::DoubleConstant(*value),
             VirtualInstruction::TypeId(ty) => VirtualInstruction::TypeId(ty.replace_parameters(this_type, function_parameters)),
+            VirtualInstruction::ObjectSize(ty) => VirtualInstruction::ObjectSize(ty.replace_parameters(this_type, function_parameters)),
             VirtualInstruction::TypeName(details) => VirtualInstruction::TypeName(TypeNameDetails {
                 ty: details.ty.replace_parameters(this_type, function_parameters),
                 include_package: details.include_package,
@@ -742,8 +760,9 @@ impl VirtualInstruction {
             }),
             VirtualInstruction::FieldAccess(details) => VirtualInstruction::FieldAccess(VirtualAccessFieldInfo {
                 acess_kind: details.acess_kind,
+                object_type: details.object_type.replace_parameters(this_type, function_parameters),
+                field_name: details.field_name.clone(),
                 field_type: details.field_type.replace_parameters(this_type, function_parameters),
-                field_offset: details.field_offset,
                 value: details.value.as_ref().map(|vasm| vasm.replace_parameters(this_type, function_parameters)),
                 check_location: details.check_location.clone(),
             }),
@@ -783,7 +802,11 @@ impl VirtualInstruction {
 
 fn add_field_access_check(check_location: &ItemLocation, content: &mut Vec<Wat>, context: &mut ProgramContext) {
     let field_name_var = context.string_literals.add(&check_location.as_str());
-    let file_name_var = context.string_literals.add(&check_location.file.path[check_location.file.package_root_path.len()+1..]);
+    let file_name = match check_location.file.path.strip_prefix(check_location.file.package_root_path.as_str()) {
+        Some(relative_path) => &relative_path[1..],
+        None => check_location.file.path.as_str(),
+    };
+    let file_name_var = context.string_literals.add(file_name);
     let (line, column) = check_location.file.get_line_col(check_location.start).unwrap();
 
     content.extend(vec![
@@ -793,4 +816,43 @@ fn add_field_access_check(check_location: &ItemLocation, content: &mut Vec<Wat>,
         Wat::const_i32(column),
         Wat::call_from_stack(CHECK_FIELD_ACCESS_FUNC_NAME)
     ]);
-}
\ No newline at end of file
+}
+
+// A closure argument variable holds the address of its value, stored with the wasm type of the value
+
+fn get_closure_arg_access_func_names(wasm_type: &str) -> (&'static str, &'static str) {
+    match wasm_type {
+        "i32" => (LOAD_INT_WASM_FUNC_NAME, STORE_INT_WASM_FUNC_NAME),
+        "f32" => (LOAD_FLOAT_WASM_FUNC_NAME, STORE_FLOAT_WASM_FUNC_NAME),
+        "i64" => (LOAD_LONG_WASM_FUNC_NAME, STORE_LONG_WASM_FUNC_NAME),
+        "f64" => (LOAD_DOUBLE_WASM_FUNC_NAME, STORE_DOUBLE_WASM_FUNC_NAME),
+        _ => unreachable!()
+    }
+}
+
+fn get_wasm_type_cell_count(wasm_type: &str) -> i32 {
+    match wasm_type {
+        "i64" | "f64" => 2,
+        _ => 1
+    }
+}
+
+fn convert_address_to_wasm_type(wasm_type: &str) -> Vec<Wat> {
+    match wasm_type {
+        "i32" => vec![],
+        "f32" => vec![wat!["f32.reinterpret_i32"]],
+        "i64" => vec![wat!["i64.extend_i32_u"]],
+        "f64" => vec![wat!["i64.extend_i32_u"], wat!["f64.reinterpret_i64"]],
+        _ => unreachable!()
+    }
+}
+
+fn convert_wasm_type_to_address(wasm_type: &str) -> Vec<Wat> {
+    match wasm_type {
+        "i32" => vec![],
+        "f32" => vec![wat!["i32.reinterpret_f32"]],
+        "i64" => vec![wat!["i32.wrap_i64"]],
+        "f64" => vec![wat!["i64.reinterpret_f64"], wat!["i32.wrap_i64"]],
+        _ => unreachable!()
+    }
+}
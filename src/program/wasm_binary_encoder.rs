@@ -0,0 +1,731 @@
+use std::collections::HashMap;
+use wasm_encoder::{BlockType, CodeSection, ConstExpr, DataSection, ElementSection, Elements, EntityType, ExportKind, ExportSection, Function, FunctionSection, GlobalSection, GlobalType, ImportSection, IndirectNameMap, Instruction, MemArg, MemorySection, MemoryType, Module, NameMap, NameSection, RefType, TableSection, TableType, TypeSection, ValType};
+use super::Wat;
+
+type Signature = (Vec<ValType>, Vec<ValType>);
+
+pub struct WasmBinaryEncoder {
+    signatures: Vec<Signature>,
+    signature_indexes: HashMap<Signature, u32>,
+    type_indexes: HashMap<String, u32>,
+    function_indexes: HashMap<String, u32>,
+    global_indexes: HashMap<String, u32>,
+    imported_function_count: u32,
+    function_names: NameMap,
+    local_names: IndirectNameMap,
+}
+
+struct FunctionLocals {
+    indexes: HashMap<String, u32>,
+    names: NameMap,
+    types: Vec<ValType>,
+}
+
+impl WasmBinaryEncoder {
+    pub fn new() -> Self {
+        Self {
+            signatures: vec![],
+            signature_indexes: HashMap::new(),
+            type_indexes: HashMap::new(),
+            function_indexes: HashMap::new(),
+            global_indexes: HashMap::new(),
+            imported_function_count: 0,
+            function_names: NameMap::new(),
+            local_names: IndirectNameMap::new(),
+        }
+    }
+
+    pub fn encode(mut self, module: &Wat) -> Vec<u8> {
+        let mut imports = ImportSection::new();
+        let mut functions = FunctionSection::new();
+        let mut tables = TableSection::new();
+        let mut memories = MemorySection::new();
+        let mut globals = GlobalSection::new();
+        let mut exports = ExportSection::new();
+        let mut elements = ElementSection::new();
+        let mut code = CodeSection::new();
+        let mut data = DataSection::new();
+
+        self.index_module_items(module);
+
+        for item in &module.arguments {
+            match item.keyword.as_str() {
+                "type" => {},
+                "import" => {
+                    let function = find_argument(item, "func").unwrap_or_else(|| panic!("unsupported import `{}`", item.to_string(0)));
+                    let type_index = self.get_function_type_index(function);
+
+                    imports.import(&parse_string(&item.arguments[0].keyword), &parse_string(&item.arguments[1].keyword), EntityType::Function(type_index));
+                },
+                "memory" => {
+                    let memory_index = memories.len();
+
+                    for argument in &item.arguments {
+                        match argument.keyword.as_str() {
+                            "export" => { exports.export(&parse_string(&argument.arguments[0].keyword), ExportKind::Memory, memory_index); },
+                            token => {
+                                memories.memory(MemoryType {
+                                    minimum: parse_integer(token) as u64,
+                                    maximum: None,
+                                    memory64: false,
+                                    shared: false,
+                                    page_size_log2: None,
+                                });
+                            },
+                        }
+                    }
+                },
+                "table" => {
+                    tables.table(TableType {
+                        element_type: RefType::FUNCREF,
+                        table64: false,
+                        minimum: parse_integer(&item.arguments[0].keyword) as u64,
+                        maximum: None,
+                        shared: false,
+                    });
+                },
+                "elem" => {
+                    let offset = self.encode_const_expr(&item.arguments[0]);
+                    let indexes : Vec<u32> = item.arguments[1..].iter().map(|argument| resolve_index(&self.function_indexes, &argument.keyword)).collect();
+
+                    elements.active(None, &offset, Elements::Functions(indexes.into()));
+                },
+                "global" => {
+                    let (ty, init) = match item.arguments.len() {
+                        3 => (&item.arguments[1], &item.arguments[2]),
+                        _ => (&item.arguments[0], &item.arguments[1]),
+                    };
+                    let global_type = match ty.keyword.as_str() {
+                        "mut" => GlobalType { val_type: parse_val_type(&ty.arguments[0].keyword), mutable: true, shared: false },
+                        keyword => GlobalType { val_type: parse_val_type(keyword), mutable: false, shared: false },
+                    };
+
+                    globals.global(global_type, &self.encode_const_expr(init));
+                },
+                "func" => {
+                    let function_index = self.imported_function_count + functions.len();
+                    let type_index = self.get_function_type_index(item);
+
+                    functions.function(type_index);
+                    code.function(&self.encode_function(item, function_index, &mut exports));
+                },
+                "export" => {
+                    let name = parse_string(&item.arguments[0].keyword);
+                    let target = &item.arguments[1];
+                    let target_name = &target.arguments[0].keyword;
+
+                    match target.keyword.as_str() {
+                        "func" => exports.export(&name, ExportKind::Func, resolve_index(&self.function_indexes, target_name)),
+                        "global" => exports.export(&name, ExportKind::Global, resolve_index(&self.global_indexes, target_name)),
+                        "memory" => exports.export(&name, ExportKind::Memory, parse_integer(target_name) as u32),
+                        "table" => exports.export(&name, ExportKind::Table, parse_integer(target_name) as u32),
+                        keyword => panic!("unsupported export kind `{}`", keyword),
+                    };
+                },
+                "data" => {
+                    let offset = self.encode_const_expr(&item.arguments[0]);
+                    let mut bytes = vec![];
+
+                    for argument in &item.arguments[1..] {
+                        bytes.extend(parse_bytes(&argument.keyword));
+                    }
+
+                    data.active(0, &offset, bytes);
+                },
+                keyword => panic!("unsupported module field `{}`", keyword),
+            }
+        }
+
+        let mut types = TypeSection::new();
+
+        for (params, results) in &self.signatures {
+            types.ty().function(params.iter().copied(), results.iter().copied());
+        }
+
+        let mut names = NameSection::new();
+        names.functions(&self.function_names);
+        names.locals(&self.local_names);
+
+        let mut result = Module::new();
+
+        result
+            .section(&types)
+            .section(&imports)
+            .section(&functions)
+            .section(&tables)
+            .section(&memories)
+            .section(&globals)
+            .section(&exports)
+            .section(&elements)
+            .section(&code);
+
+        if !data.is_empty() {
+            result.section(&data);
+        }
+
+        result.section(&names);
+        result.finish()
+    }
+
+    fn index_module_items(&mut self, module: &Wat) {
+        let mut function_names = vec![];
+
+        for item in &module.arguments {
+            match item.keyword.as_str() {
+                "type" => {
+                    let name = get_name(&item.arguments[0].keyword);
+                    let index = self.signatures.len() as u32;
+                    let signature = get_signature(&item.arguments[1]);
+
+                    self.signature_indexes.entry(signature.clone()).or_insert(index);
+                    self.signatures.push(signature);
+                    self.type_indexes.insert(name.to_string(), index);
+                },
+                "import" => {
+                    if let Some(function) = find_argument(item, "func") {
+                        let index = self.function_indexes.len() as u32;
+                        let name = get_name(&function.arguments[0].keyword);
+
+                        self.function_indexes.insert(name.to_string(), index);
+                        self.function_names.append(index, name);
+                        self.imported_function_count += 1;
+                    }
+                },
+                "func" => {
+                    function_names.push(get_name(&item.arguments[0].keyword));
+                },
+                "global" => {
+                    let index = self.global_indexes.len() as u32;
+
+                    self.global_indexes.insert(get_name(&item.arguments[0].keyword).to_string(), index);
+                },
+                _ => {}
+            }
+        }
+
+        for name in function_names {
+            let index = self.function_indexes.len() as u32;
+
+            self.function_indexes.insert(name.to_string(), index);
+            self.function_names.append(index, name);
+        }
+    }
+
+    fn get_function_type_index(&mut self, function: &Wat) -> u32 {
+        if let Some(type_use) = find_argument(function, "type") {
+            return resolve_index(&self.type_indexes, &type_use.arguments[0].keyword);
+        }
+
+        self.get_signature_index(get_signature(function))
+    }
+
+    fn get_signature_index(&mut self, signature: Signature) -> u32 {
+        match self.signature_indexes.get(&signature) {
+            Some(index) => *index,
+            None => {
+                let index = self.signatures.len() as u32;
+
+                self.signature_indexes.insert(signature.clone(), index);
+                self.signatures.push(signature);
+
+                index
+            },
+        }
+    }
+
+    fn encode_function(&mut self, function: &Wat, function_index: u32, exports: &mut ExportSection) -> Function {
+        let mut locals = FunctionLocals {
+            indexes: HashMap::new(),
+            names: NameMap::new(),
+            types: vec![],
+        };
+        let mut parameter_count = 0;
+        let mut body = vec![];
+
+        for argument in &function.arguments[1..] {
+            match argument.keyword.as_str() {
+                "export" => { exports.export(&parse_string(&argument.arguments[0].keyword), ExportKind::Func, function_index); },
+                "type" | "result" => {},
+                "param" => parameter_count += locals.declare(argument),
+                "local" => { locals.declare(argument); },
+                _ => body.push(argument),
+            }
+        }
+
+        let mut result = Function::new_with_locals_types(locals.types[parameter_count as usize..].iter().copied());
+
+        for instruction in body {
+            self.encode_instruction(instruction, &locals, &mut result);
+        }
+
+        result.instruction(&Instruction::End);
+        self.local_names.append(function_index, &locals.names);
+
+        result
+    }
+
+    fn get_block_type(&mut self, wat: &Wat) -> BlockType {
+        let (params, results) = get_signature(wat);
+
+        match (params.is_empty(), results.len()) {
+            (true, 0) => BlockType::Empty,
+            (true, 1) => BlockType::Result(results[0]),
+            _ => BlockType::FunctionType(self.get_signature_index((params, results))),
+        }
+    }
+
+    fn encode_const_expr(&self, wat: &Wat) -> ConstExpr {
+        let value = &wat.arguments[0].keyword;
+
+        match wat.keyword.as_str() {
+            "i32.const" => ConstExpr::i32_const(parse_integer(value) as i32),
+            "i64.const" => ConstExpr::i64_const(parse_integer(value)),
+            "f32.const" => ConstExpr::f32_const(f32::from_bits(parse_f32_bits(value)).into()),
+            "f64.const" => ConstExpr::f64_const(f64::from_bits(parse_f64_bits(value)).into()),
+            "global.get" => ConstExpr::global_get(resolve_index(&self.global_indexes, value)),
+            keyword => panic!("unsupported constant expression `{}`", keyword),
+        }
+    }
+
+    fn encode_instruction(&mut self, wat: &Wat, locals: &FunctionLocals, function: &mut Function) {
+        let keyword = wat.keyword.as_str();
+
+        match keyword {
+            "block" | "loop" => {
+                let block_type = self.get_block_type(wat);
+
+                function.instruction(&match keyword {
+                    "block" => Instruction::Block(block_type),
+                    _ => Instruction::Loop(block_type),
+                });
+
+                for argument in wat.arguments.iter().filter(|argument| is_operand(argument)) {
+                    self.encode_instruction(argument, locals, function);
+                }
+
+                function.instruction(&Instruction::End);
+            },
+            "if" => {
+                let then_branch = find_argument(wat, "then");
+                let else_branch = find_argument(wat, "else");
+
+                for argument in wat.arguments.iter().filter(|argument| is_operand(argument) && argument.keyword != "then" && argument.keyword != "else") {
+                    self.encode_instruction(argument, locals, function);
+                }
+
+                let block_type = self.get_block_type(wat);
+
+                function.instruction(&Instruction::If(block_type));
+
+                for argument in then_branch.iter().flat_map(|branch| &branch.arguments) {
+                    self.encode_instruction(argument, locals, function);
+                }
+
+                if let Some(branch) = else_branch {
+                    function.instruction(&Instruction::Else);
+
+                    for argument in &branch.arguments {
+                        self.encode_instruction(argument, locals, function);
+                    }
+                }
+
+                function.instruction(&Instruction::End);
+            },
+            _ => {
+                let immediates : Vec<&Wat> = wat.arguments.iter().filter(|argument| !is_operand(argument)).collect();
+
+                for argument in wat.arguments.iter().filter(|argument| is_operand(argument)) {
+                    self.encode_instruction(argument, locals, function);
+                }
+
+                function.instruction(&self.get_plain_instruction(keyword, &immediates, locals));
+            }
+        }
+    }
+
+    fn get_plain_instruction(&self, keyword: &str, immediates: &[&Wat], locals: &FunctionLocals) -> Instruction<'static> {
+        let immediate = || immediates.first().map(|wat| wat.keyword.as_str()).unwrap_or_else(|| panic!("missing immediate for `{}`", keyword));
+
+        match keyword {
+            "local.get" => Instruction::LocalGet(resolve_index(&locals.indexes, immediate())),
+            "local.set" => Instruction::LocalSet(resolve_index(&locals.indexes, immediate())),
+            "local.tee" => Instruction::LocalTee(resolve_index(&locals.indexes, immediate())),
+            "global.get" => Instruction::GlobalGet(resolve_index(&self.global_indexes, immediate())),
+            "global.set" => Instruction::GlobalSet(resolve_index(&self.global_indexes, immediate())),
+            "call" => Instruction::Call(resolve_index(&self.function_indexes, immediate())),
+            "call_indirect" => {
+                let type_use = immediates.iter().find(|wat| wat.keyword == "type").unwrap_or_else(|| panic!("missing type for `call_indirect`"));
+
+                Instruction::CallIndirect {
+                    type_index: resolve_index(&self.type_indexes, &type_use.arguments[0].keyword),
+                    table_index: 0,
+                }
+            },
+            "br" => Instruction::Br(parse_integer(immediate()) as u32),
+            "br_if" => Instruction::BrIf(parse_integer(immediate()) as u32),
+            "i32.const" => Instruction::I32Const(parse_integer(immediate()) as i32),
+            "i64.const" => Instruction::I64Const(parse_integer(immediate())),
+            "f32.const" => Instruction::F32Const(f32::from_bits(parse_f32_bits(immediate())).into()),
+            "f64.const" => Instruction::F64Const(f64::from_bits(parse_f64_bits(immediate())).into()),
+            "memory.size" => Instruction::MemorySize(0),
+            "memory.grow" => Instruction::MemoryGrow(0),
+            "memory.copy" => Instruction::MemoryCopy { src_mem: 0, dst_mem: 0 },
+            "memory.fill" => Instruction::MemoryFill(0),
+            _ => match get_memory_instruction(keyword, immediates) {
+                Some(instruction) => instruction,
+                None => get_numeric_instruction(keyword).unwrap_or_else(|| panic!("unsupported wasm instruction `{}`", keyword)),
+            }
+        }
+    }
+}
+
+impl FunctionLocals {
+    fn declare(&mut self, declaration: &Wat) -> u32 {
+        let mut count = 0;
+
+        for argument in &declaration.arguments {
+            let index = self.types.len() as u32;
+
+            if argument.keyword.starts_with('$') {
+                let name = get_name(&argument.keyword);
+
+                self.indexes.insert(name.to_string(), index);
+                self.names.append(index, name);
+            } else {
+                self.types.push(parse_val_type(&argument.keyword));
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+impl Default for WasmBinaryEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn find_argument<'a>(wat: &'a Wat, keyword: &str) -> Option<&'a Wat> {
+    wat.arguments.iter().find(|argument| argument.keyword == keyword)
+}
+
+fn get_name(token: &str) -> &str {
+    token.strip_prefix('$').unwrap_or_else(|| panic!("expected name, got `{}`", token))
+}
+
+fn resolve_index(indexes: &HashMap<String, u32>, token: &str) -> u32 {
+    match token.strip_prefix('$') {
+        Some(name) => *indexes.get(name).unwrap_or_else(|| panic!("undefined wasm item `{}`", token)),
+        None => parse_integer(token) as u32,
+    }
+}
+
+fn is_operand(wat: &Wat) -> bool {
+    match wat.keyword.as_str() {
+        "type" | "param" | "result" => false,
+        keyword => !(wat.arguments.is_empty() && is_immediate_token(keyword)),
+    }
+}
+
+fn is_immediate_token(token: &str) -> bool {
+    match token.chars().next() {
+        Some('$' | '-' | '+' | '"') => true,
+        Some(c) if c.is_ascii_digit() => true,
+        _ => token.contains('=') || token.starts_with("nan") || token.starts_with("inf") || token == "NaN",
+    }
+}
+
+fn get_signature(function: &Wat) -> Signature {
+    let mut params = vec![];
+    let mut results = vec![];
+
+    for argument in &function.arguments {
+        let list = match argument.keyword.as_str() {
+            "param" => &mut params,
+            "result" => &mut results,
+            _ => continue,
+        };
+
+        for ty in &argument.arguments {
+            if !ty.keyword.starts_with('$') {
+                list.push(parse_val_type(&ty.keyword));
+            }
+        }
+    }
+
+    (params, results)
+}
+
+fn parse_val_type(token: &str) -> ValType {
+    match token {
+        "i32" => ValType::I32,
+        "i64" => ValType::I64,
+        "f32" => ValType::F32,
+        "f64" => ValType::F64,
+        _ => panic!("unsupported wasm type `{}`", token),
+    }
+}
+
+fn parse_string(token: &str) -> String {
+    String::from_utf8(parse_bytes(token)).unwrap_or_else(|_| panic!("invalid utf-8 string `{}`", token))
+}
+
+fn parse_bytes(token: &str) -> Vec<u8> {
+    let content = token.strip_prefix('"').and_then(|s| s.strip_suffix('"')).unwrap_or_else(|| panic!("expected string, got `{}`", token));
+    let mut result = vec![];
+    let mut bytes = content.bytes();
+
+    while let Some(byte) = bytes.next() {
+        if byte != b'\\' {
+            result.push(byte);
+            continue;
+        }
+
+        match bytes.next() {
+            Some(b'n') => result.push(b'\n'),
+            Some(b't') => result.push(b'\t'),
+            Some(escaped @ (b'\\' | b'"' | b'\'')) => result.push(escaped),
+            Some(first) => {
+                let second = bytes.next().unwrap_or(b'0');
+                let byte = u8::from_str_radix(&format!("{}{}", first as char, second as char), 16).unwrap_or_else(|_| panic!("invalid escape in `{}`", token));
+
+                result.push(byte);
+            },
+            None => {},
+        }
+    }
+
+    result
+}
+
+fn parse_integer(token: &str) -> i64 {
+    let cleaned = token.replace('_', "");
+    let (negative, digits) = match cleaned.strip_prefix('-') {
+        Some(digits) => (true, digits.to_string()),
+        None => (false, cleaned.trim_start_matches('+').to_string()),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => digits.parse::<u64>(),
+    }.unwrap_or_else(|_| panic!("invalid integer `{}`", token)) as i64;
+
+    match negative {
+        true => value.wrapping_neg(),
+        false => value,
+    }
+}
+
+fn parse_float_bits(token: &str, exponent_mask: u64, quiet_nan: u64, sign_bit: u64, parse_finite: fn(&str) -> Option<u64>) -> u64 {
+    let (negative, body) = match token.strip_prefix('-') {
+        Some(body) => (true, body),
+        None => (false, token.trim_start_matches('+')),
+    };
+    let bits = if let Some(payload) = body.strip_prefix("nan:0x") {
+        exponent_mask | u64::from_str_radix(payload, 16).unwrap_or_else(|_| panic!("invalid float `{}`", token))
+    } else if body == "nan" || body == "NaN" {
+        exponent_mask | quiet_nan
+    } else if body == "inf" {
+        exponent_mask
+    } else {
+        parse_finite(body).unwrap_or_else(|| panic!("invalid float `{}`", token))
+    };
+
+    match negative {
+        true => bits | sign_bit,
+        false => bits,
+    }
+}
+
+fn parse_f32_bits(token: &str) -> u32 {
+    parse_float_bits(token, 0x7f80_0000, 0x0040_0000, 0x8000_0000, |s| s.parse::<f32>().ok().map(|f| f.to_bits() as u64)) as u32
+}
+
+fn parse_f64_bits(token: &str) -> u64 {
+    parse_float_bits(token, 0x7ff0_0000_0000_0000, 0x0008_0000_0000_0000, 0x8000_0000_0000_0000, |s| s.parse::<f64>().ok().map(|f| f.to_bits()))
+}
+
+fn get_memory_instruction(keyword: &str, immediates: &[&Wat]) -> Option<Instruction<'static>> {
+    let (natural_align, constructor) : (u32, fn(MemArg) -> Instruction<'static>) = match keyword {
+        "i32.load" => (2, Instruction::I32Load),
+        "i64.load" => (3, Instruction::I64Load),
+        "f32.load" => (2, Instruction::F32Load),
+        "f64.load" => (3, Instruction::F64Load),
+        "i32.load8_s" => (0, Instruction::I32Load8S),
+        "i32.load8_u" => (0, Instruction::I32Load8U),
+        "i32.load16_s" => (1, Instruction::I32Load16S),
+        "i32.load16_u" => (1, Instruction::I32Load16U),
+        "i64.load8_s" => (0, Instruction::I64Load8S),
+        "i64.load8_u" => (0, Instruction::I64Load8U),
+        "i64.load16_s" => (1, Instruction::I64Load16S),
+        "i64.load16_u" => (1, Instruction::I64Load16U),
+        "i64.load32_s" => (2, Instruction::I64Load32S),
+        "i64.load32_u" => (2, Instruction::I64Load32U),
+        "i32.store" => (2, Instruction::I32Store),
+        "i64.store" => (3, Instruction::I64Store),
+        "f32.store" => (2, Instruction::F32Store),
+        "f64.store" => (3, Instruction::F64Store),
+        "i32.store8" => (0, Instruction::I32Store8),
+        "i32.store16" => (1, Instruction::I32Store16),
+        "i64.store8" => (0, Instruction::I64Store8),
+        "i64.store16" => (1, Instruction::I64Store16),
+        "i64.store32" => (2, Instruction::I64Store32),
+        _ => return None,
+    };
+    let mut memarg = MemArg { offset: 0, align: natural_align, memory_index: 0 };
+
+    for immediate in immediates {
+        if let Some(offset) = immediate.keyword.strip_prefix("offset=") {
+            memarg.offset = parse_integer(offset) as u64;
+        } else if let Some(align) = immediate.keyword.strip_prefix("align=") {
+            memarg.align = (parse_integer(align) as u32).trailing_zeros();
+        }
+    }
+
+    Some(constructor(memarg))
+}
+
+fn get_numeric_instruction(keyword: &str) -> Option<Instruction<'static>> {
+    let instruction = match keyword {
+        "unreachable" => Instruction::Unreachable,
+        "nop" => Instruction::Nop,
+        "return" => Instruction::Return,
+        "drop" => Instruction::Drop,
+        "select" => Instruction::Select,
+
+        "i32.eqz" => Instruction::I32Eqz,
+        "i32.eq" => Instruction::I32Eq,
+        "i32.ne" => Instruction::I32Ne,
+        "i32.lt_s" => Instruction::I32LtS,
+        "i32.lt_u" => Instruction::I32LtU,
+        "i32.gt_s" => Instruction::I32GtS,
+        "i32.gt_u" => Instruction::I32GtU,
+        "i32.le_s" => Instruction::I32LeS,
+        "i32.le_u" => Instruction::I32LeU,
+        "i32.ge_s" => Instruction::I32GeS,
+        "i32.ge_u" => Instruction::I32GeU,
+        "i64.eqz" => Instruction::I64Eqz,
+        "i64.eq" => Instruction::I64Eq,
+        "i64.ne" => Instruction::I64Ne,
+        "i64.lt_s" => Instruction::I64LtS,
+        "i64.lt_u" => Instruction::I64LtU,
+        "i64.gt_s" => Instruction::I64GtS,
+        "i64.gt_u" => Instruction::I64GtU,
+        "i64.le_s" => Instruction::I64LeS,
+        "i64.le_u" => Instruction::I64LeU,
+        "i64.ge_s" => Instruction::I64GeS,
+        "i64.ge_u" => Instruction::I64GeU,
+        "f32.eq" => Instruction::F32Eq,
+        "f32.ne" => Instruction::F32Ne,
+        "f32.lt" => Instruction::F32Lt,
+        "f32.gt" => Instruction::F32Gt,
+        "f32.le" => Instruction::F32Le,
+        "f32.ge" => Instruction::F32Ge,
+        "f64.eq" => Instruction::F64Eq,
+        "f64.ne" => Instruction::F64Ne,
+        "f64.lt" => Instruction::F64Lt,
+        "f64.gt" => Instruction::F64Gt,
+        "f64.le" => Instruction::F64Le,
+        "f64.ge" => Instruction::F64Ge,
+
+        "i32.clz" => Instruction::I32Clz,
+        "i32.ctz" => Instruction::I32Ctz,
+        "i32.popcnt" => Instruction::I32Popcnt,
+        "i32.add" => Instruction::I32Add,
+        "i32.sub" => Instruction::I32Sub,
+        "i32.mul" => Instruction::I32Mul,
+        "i32.div_s" => Instruction::I32DivS,
+        "i32.div_u" => Instruction::I32DivU,
+        "i32.rem_s" => Instruction::I32RemS,
+        "i32.rem_u" => Instruction::I32RemU,
+        "i32.and" => Instruction::I32And,
+        "i32.or" => Instruction::I32Or,
+        "i32.xor" => Instruction::I32Xor,
+        "i32.shl" => Instruction::I32Shl,
+        "i32.shr_s" => Instruction::I32ShrS,
+        "i32.shr_u" => Instruction::I32ShrU,
+        "i32.rotl" => Instruction::I32Rotl,
+        "i32.rotr" => Instruction::I32Rotr,
+        "i64.clz" => Instruction::I64Clz,
+        "i64.ctz" => Instruction::I64Ctz,
+        "i64.popcnt" => Instruction::I64Popcnt,
+        "i64.add" => Instruction::I64Add,
+        "i64.sub" => Instruction::I64Sub,
+        "i64.mul" => Instruction::I64Mul,
+        "i64.div_s" => Instruction::I64DivS,
+        "i64.div_u" => Instruction::I64DivU,
+        "i64.rem_s" => Instruction::I64RemS,
+        "i64.rem_u" => Instruction::I64RemU,
+        "i64.and" => Instruction::I64And,
+        "i64.or" => Instruction::I64Or,
+        "i64.xor" => Instruction::I64Xor,
+        "i64.shl" => Instruction::I64Shl,
+        "i64.shr_s" => Instruction::I64ShrS,
+        "i64.shr_u" => Instruction::I64ShrU,
+        "i64.rotl" => Instruction::I64Rotl,
+        "i64.rotr" => Instruction::I64Rotr,
+        "f32.abs" => Instruction::F32Abs,
+        "f32.neg" => Instruction::F32Neg,
+        "f32.ceil" => Instruction::F32Ceil,
+        "f32.floor" => Instruction::F32Floor,
+        "f32.trunc" => Instruction::F32Trunc,
+        "f32.nearest" => Instruction::F32Nearest,
+        "f32.sqrt" => Instruction::F32Sqrt,
+        "f32.add" => Instruction::F32Add,
+        "f32.sub" => Instruction::F32Sub,
+        "f32.mul" => Instruction::F32Mul,
+        "f32.div" => Instruction::F32Div,
+        "f32.min" => Instruction::F32Min,
+        "f32.max" => Instruction::F32Max,
+        "f32.copysign" => Instruction::F32Copysign,
+        "f64.abs" => Instruction::F64Abs,
+        "f64.neg" => Instruction::F64Neg,
+        "f64.ceil" => Instruction::F64Ceil,
+        "f64.floor" => Instruction::F64Floor,
+        "f64.trunc" => Instruction::F64Trunc,
+        "f64.nearest" => Instruction::F64Nearest,
+        "f64.sqrt" => Instruction::F64Sqrt,
+        "f64.add" => Instruction::F64Add,
+        "f64.sub" => Instruction::F64Sub,
+        "f64.mul" => Instruction::F64Mul,
+        "f64.div" => Instruction::F64Div,
+        "f64.min" => Instruction::F64Min,
+        "f64.max" => Instruction::F64Max,
+        "f64.copysign" => Instruction::F64Copysign,
+
+        "i32.wrap_i64" => Instruction::I32WrapI64,
+        "i32.trunc_f32_s" => Instruction::I32TruncF32S,
+        "i32.trunc_f32_u" => Instruction::I32TruncF32U,
+        "i32.trunc_f64_s" => Instruction::I32TruncF64S,
+        "i32.trunc_f64_u" => Instruction::I32TruncF64U,
+        "i64.extend_i32_s" => Instruction::I64ExtendI32S,
+        "i64.extend_i32_u" => Instruction::I64ExtendI32U,
+        "i64.trunc_f32_s" => Instruction::I64TruncF32S,
+        "i64.trunc_f32_u" => Instruction::I64TruncF32U,
+        "i64.trunc_f64_s" => Instruction::I64TruncF64S,
+        "i64.trunc_f64_u" => Instruction::I64TruncF64U,
+        "f32.convert_i32_s" => Instruction::F32ConvertI32S,
+        "f32.convert_i32_u" => Instruction::F32ConvertI32U,
+        "f32.convert_i64_s" => Instruction::F32ConvertI64S,
+        "f32.convert_i64_u" => Instruction::F32ConvertI64U,
+        "f32.demote_f64" => Instruction::F32DemoteF64,
+        "f64.convert_i32_s" => Instruction::F64ConvertI32S,
+        "f64.convert_i32_u" => Instruction::F64ConvertI32U,
+        "f64.convert_i64_s" => Instruction::F64ConvertI64S,
+        "f64.convert_i64_u" => Instruction::F64ConvertI64U,
+        "f64.promote_f32" => Instruction::F64PromoteF32,
+        "i32.reinterpret_f32" => Instruction::I32ReinterpretF32,
+        "i64.reinterpret_f64" => Instruction::I64ReinterpretF64,
+        "f32.reinterpret_i32" => Instruction::F32ReinterpretI32,
+        "f64.reinterpret_i64" => Instruction::F64ReinterpretI64,
+        "i32.extend8_s" => Instruction::I32Extend8S,
+        "i32.extend16_s" => Instruction::I32Extend16S,
+        "i64.extend8_s" => Instruction::I64Extend8S,
+        "i64.extend16_s" => Instruction::I64Extend16S,
+        "i64.extend32_s" => Instruction::I64Extend32S,
+        _ => return None,
+    };
+
+    Some(instruction)
+}
@@ -67,6 +67,14 @@ impl Wat {
         wat!["f32.const", value]
     }
 
+    pub fn const_i64(value: i64) -> Self {
+        wat!["i64.const", value]
+    }
+
+    pub fn const_f64(value: f64) -> Self {
+        wat!["f64.const", value]
+    }
+
     pub fn call<S : Deref<Target=str>, T : ToWatVec>(func_name: S, arguments: T) -> Self {
         wat!["call", Wat::var_name(&func_name), arguments]
     }
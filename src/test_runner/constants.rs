@@ -0,0 +1,6 @@
+pub const OUTPUT_FILE_NAME : &'static str = "output.txt";
+pub const SKIPPED_FILE_NAME : &'static str = "skipped.txt";
+pub const UNFORMATTED_FILE_NAME : &'static str = "unformatted.lt";
+pub const FORMATTED_FILE_NAME : &'static str = "main.lt";
+pub const EXPORTED_MEMORY_NAME : &'static str = "memory";
+pub const INITIALIZE_FUNCTION_NAME : &'static str = "initialize";
@@ -0,0 +1,11 @@
+mod constants;
+mod wasm_host;
+mod test_case;
+mod test_outcome;
+mod test_runner;
+
+pub use constants::*;
+pub use wasm_host::*;
+pub use test_case::*;
+pub use test_outcome::*;
+pub use test_runner::*;
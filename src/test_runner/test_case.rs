@@ -0,0 +1,170 @@
+use std::{fs, path::{Path, PathBuf}};
+use indexmap::IndexSet;
+use parsable::ParseError;
+use crate::{formatter::format_source_file, items::ParsedSourceFile, package::{Package, SRC_DIR_NAME}, program::{BinaryKind, ProgramContext, ProgramContextMode, ProgramContextOptions}, utils::FileSystemCache};
+use super::{execute_cli_module, TestFailure, TestOutcome, FORMATTED_FILE_NAME, OUTPUT_FILE_NAME, SKIPPED_FILE_NAME, UNFORMATTED_FILE_NAME};
+
+#[derive(Debug)]
+pub struct TestCase {
+    pub name: String,
+    pub root_path: PathBuf,
+}
+
+impl TestCase {
+    pub fn discover(test_directory_path: &Path, filters: &[String]) -> Vec<Self> {
+        let mut result = vec![];
+
+        if let Ok(entries) = test_directory_path.read_dir() {
+            for entry in entries.flatten() {
+                let root_path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                let matches_filters = filters.is_empty() || filters.iter().any(|filter| name.contains(filter.as_str()));
+
+                if root_path.join(SRC_DIR_NAME).is_dir() && matches_filters {
+                    result.push(TestCase { name, root_path });
+                }
+            }
+        }
+
+        result.sort_by(|a, b| a.name.cmp(&b.name));
+        result
+    }
+
+    pub fn run(&self, prelude_path: Option<&Path>, cache: &mut FileSystemCache<ParsedSourceFile, ParseError>) -> TestOutcome {
+        if self.root_path.join(SKIPPED_FILE_NAME).exists() {
+            return TestOutcome::Skipped;
+        }
+
+        let expected_output = match fs::read_to_string(self.root_path.join(OUTPUT_FILE_NAME)) {
+            Ok(content) => content.replace('\r', ""),
+            Err(_) => return TestOutcome::Failed(TestFailure {
+                reason: format!("missing `{}`", OUTPUT_FILE_NAME),
+                expected_output: String::new(),
+                actual_output: String::new(),
+            }),
+        };
+
+        if let Some(failure) = self.check_formatting() {
+            return TestOutcome::Failed(failure);
+        }
+
+        let (debug_output, compilation_result) = self.compile(prelude_path, cache);
+        let (actual_output, reason) = match compilation_result {
+            Ok(wasm) => {
+                let execution = execute_cli_module(&wasm);
+                let actual_output = format!("{}{}", debug_output, execution.logs.join("\n"));
+
+                if let Some(line_number) = execution.failed_assertions.first() {
+                    return TestOutcome::Failed(TestFailure {
+                        reason: format!("assertion failed at line {}", line_number),
+                        expected_output,
+                        actual_output,
+                    });
+                }
+
+                let reason = match (&execution.error, expected_output.contains("error:")) {
+                    (Some(error), _) => format!("execution aborted: {}", error),
+                    (None, true) => "expected compilation errors, but the compilation succeeded".to_string(),
+                    (None, false) => "output mismatch".to_string(),
+                };
+
+                (actual_output, reason)
+            },
+            Err(diagnostics) => (format!("{}{}", debug_output, diagnostics), "unexpected compilation errors".to_string()),
+        };
+
+        match actual_output.trim_end() == expected_output.trim_end() {
+            true => TestOutcome::Passed,
+            false => TestOutcome::Failed(TestFailure {
+                reason,
+                expected_output,
+                actual_output,
+            }),
+        }
+    }
+
+    // if the test has an `unformatted.lt` file, formatting it must give `src/main.lt`, which must itself be left untouched by the formatter
+    fn check_formatting(&self) -> Option<TestFailure> {
+        let unformatted_path = self.root_path.join(UNFORMATTED_FILE_NAME);
+        let formatted_path = self.root_path.join(SRC_DIR_NAME).join(FORMATTED_FILE_NAME);
+        let unformatted = fs::read_to_string(&unformatted_path).ok()?.replace('\r', "");
+        let expected_output = fs::read_to_string(&formatted_path).unwrap_or_default().replace('\r', "");
+
+        for (path, content) in [(&unformatted_path, &unformatted), (&formatted_path, &expected_output)] {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+            let actual_output = match format_source_file(content, &path.to_string_lossy()) {
+                Ok(formatted) => formatted,
+                Err(_) => return Some(TestFailure {
+                    reason: format!("cannot format `{}`", file_name),
+                    expected_output: expected_output.clone(),
+                    actual_output: String::new(),
+                }),
+            };
+
+            if actual_output != expected_output {
+                return Some(TestFailure {
+                    reason: format!("formatting `{}` does not give `{}/{}`", file_name, SRC_DIR_NAME, FORMATTED_FILE_NAME),
+                    expected_output: expected_output.clone(),
+                    actual_output,
+                });
+            }
+        }
+
+        None
+    }
+
+    // returns the output printed by the compiler itself (e.g. `#DEBUG_TYPE`), along with the module or the diagnostics
+    fn compile(&self, prelude_path: Option<&Path>, cache: &mut FileSystemCache<ParsedSourceFile, ParseError>) -> (String, Result<Vec<u8>, String>) {
+        let src_path = self.root_path.join(SRC_DIR_NAME).to_string_lossy().to_string();
+        let mut package = match Package::try_from_path(&src_path) {
+            Some(package) => package,
+            None => return (String::new(), Err(format!("`{}` is not inside a Lotus package\n", src_path))),
+        };
+
+        if !package.package_file_exists {
+            package.exclude_engine = true;
+            package.exclude_framework = true;
+        }
+
+        if let Some(prelude_path) = prelude_path {
+            package.prelude_path = prelude_path.to_path_buf();
+        }
+
+        let mut context = ProgramContext::new(ProgramContextOptions {
+            package,
+            mode: ProgramContextMode::Compile(BinaryKind::Cli),
+            cursor_location: None,
+            use_parse_cache: true,
+        });
+
+        context.parse_source_files(Some(cache));
+
+        if !context.has_errors() {
+            context.process_source_files();
+        }
+
+        let debug_output = context.debug_output.iter().map(|line| format!("{}\n", line)).collect();
+        let result = match context.take_errors() {
+            Some(errors) => {
+                // expected outputs are written without colors
+                colored::control::set_override(false);
+
+                let diagnostics : IndexSet<String> = errors.iter().filter_map(|error| error.to_string()).collect();
+
+                colored::control::unset_override();
+
+                Err(diagnostics.into_iter().map(|string| format!("{}\n", string)).collect())
+            },
+            None => {
+                context.resolve_wat();
+                context.encode_output_file();
+
+                Ok(context.get_output_file().to_vec())
+            },
+        };
+
+        context.destroy();
+
+        (debug_output, result)
+    }
+}
@@ -0,0 +1,23 @@
+#[derive(Debug)]
+pub enum TestOutcome {
+    Passed,
+    Failed(TestFailure),
+    Skipped,
+}
+
+#[derive(Debug)]
+pub struct TestFailure {
+    pub reason: String,
+    pub expected_output: String,
+    pub actual_output: String,
+}
+
+impl TestOutcome {
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            TestOutcome::Passed => "ok:",
+            TestOutcome::Failed(_) => "failed:",
+            TestOutcome::Skipped => "skipped:",
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::{path::Path, time::Instant};
+use colored::*;
+use crate::{command_line::{LogLevel, TestOptions}, utils::FileSystemCache};
+use super::{TestCase, TestFailure, TestOutcome};
+
+pub fn run_tests(options: &TestOptions) -> bool {
+    let test_cases = TestCase::discover(Path::new(&options.test_directory_path), &options.filters);
+    let prelude_path = options.prelude.as_deref().map(Path::new);
+    let mut cache = FileSystemCache::new();
+    let mut failed_names = vec![];
+    let mut passed_count = 0;
+    let mut skipped_count = 0;
+    let start = Instant::now();
+
+    for test_case in &test_cases {
+        let test_start = Instant::now();
+        let outcome = test_case.run(prelude_path, &mut cache);
+        let duration = test_start.elapsed().as_secs_f64();
+        let label = match &outcome {
+            TestOutcome::Passed => outcome.get_label().blue().bold(),
+            TestOutcome::Failed(_) => outcome.get_label().red().bold(),
+            TestOutcome::Skipped => outcome.get_label().yellow().bold(),
+        };
+        let display_outcome = match &outcome {
+            TestOutcome::Failed(_) => true,
+            _ => !matches!(options.log_level, LogLevel::Silent),
+        };
+
+        if display_outcome {
+            println!("{} {} ({:.2}s)", label, test_case.name.bold(), duration);
+        }
+
+        match outcome {
+            TestOutcome::Passed => passed_count += 1,
+            TestOutcome::Skipped => skipped_count += 1,
+            TestOutcome::Failed(failure) => {
+                display_failure(&failure, options.log_level);
+                failed_names.push(test_case.name.clone());
+            },
+        }
+    }
+
+    if test_cases.is_empty() {
+        println!("{} no test found in `{}`", "warning:".yellow().bold(), options.test_directory_path);
+    }
+
+    if !failed_names.is_empty() || !matches!(options.log_level, LogLevel::Silent) {
+        let summary = format!("{} passed, {} failed, {} skipped", passed_count, failed_names.len(), skipped_count);
+        let label = match failed_names.is_empty() {
+            true => "test result:".blue().bold(),
+            false => "test result:".red().bold(),
+        };
+
+        println!();
+        println!("{} {} ({:.2}s)", label, summary, start.elapsed().as_secs_f64());
+
+        for name in &failed_names {
+            println!("  - {}", name);
+        }
+    }
+
+    failed_names.is_empty()
+}
+
+fn display_failure(failure: &TestFailure, log_level: LogLevel) {
+    println!("  {}", failure.reason);
+
+    match log_level {
+        LogLevel::Detailed => {
+            println!("  {}", "expected:".magenta().bold());
+            display_indented(&failure.expected_output);
+            println!("  {}", "actual:".magenta().bold());
+            display_indented(&failure.actual_output);
+        },
+        _ => {
+            let mut expected_lines = failure.expected_output.trim_end().lines();
+            let mut actual_lines = failure.actual_output.trim_end().lines();
+            let mut line_number = 1;
+
+            loop {
+                match (expected_lines.next(), actual_lines.next()) {
+                    (None, None) => break,
+                    (expected, actual) if expected == actual => line_number += 1,
+                    (expected, actual) => {
+                        println!("  line {}: expected {}, got {}", line_number, format_line(expected), format_line(actual));
+                        break;
+                    },
+                }
+            }
+        },
+    }
+}
+
+fn display_indented(output: &str) {
+    for line in output.trim_end().lines() {
+        println!("    {}", line);
+    }
+}
+
+fn format_line(line: Option<&str>) -> String {
+    match line {
+        Some(line) => format!("`{}`", line),
+        None => "nothing".to_string(),
+    }
+}
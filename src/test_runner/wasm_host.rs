@@ -0,0 +1,181 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use wasmi::{Caller, Engine, Error, Extern, ExternType, Linker, Memory, Module, Store};
+use crate::program::CLI_EXPORTED_FUNCTION_NAME;
+use super::{EXPORTED_MEMORY_NAME, INITIALIZE_FUNCTION_NAME};
+
+// Imports provided to the module, mirroring `javascript/wasm-initialization.js`; any other import traps when called
+const PROVIDED_IMPORTS : &'static [(&'static str, &'static str)] = &[
+    ("utils", "assert"),
+    ("utils", "float_to_string"),
+    ("utils", "double_to_string"),
+    ("env", "log"),
+    ("env", "log_int"),
+    ("env", "get_current_time"),
+    ("env", "get_current_timestamp"),
+];
+
+#[derive(Debug, Default)]
+pub struct WasmExecution {
+    pub logs: Vec<String>,
+    pub failed_assertions: Vec<i32>,
+    pub error: Option<String>,
+}
+
+pub fn execute_cli_module(wasm: &[u8]) -> WasmExecution {
+    let engine = Engine::default();
+    let mut store = Store::new(&engine, WasmExecution::default());
+    let result = instantiate_and_run(&engine, &mut store, wasm);
+    let mut execution = store.into_data();
+
+    if let Err(error) = result {
+        execution.error = Some(error.to_string());
+    }
+
+    execution
+}
+
+fn instantiate_and_run(engine: &Engine, store: &mut Store<WasmExecution>, wasm: &[u8]) -> Result<(), Error> {
+    let module = Module::new(engine, wasm)?;
+    let mut linker = Linker::new(engine);
+
+    linker.func_wrap("utils", "assert", |mut caller: Caller<'_, WasmExecution>, line_number: i32, success: i32| {
+        if success == 0 {
+            caller.data_mut().failed_assertions.push(line_number);
+        }
+    })?;
+
+    linker.func_wrap("utils", "float_to_string", |mut caller: Caller<'_, WasmExecution>, value: f32, result_addr: i32| -> Result<(), Error> {
+        write_number(&mut caller, value as f64, result_addr)
+    })?;
+
+    linker.func_wrap("utils", "double_to_string", |mut caller: Caller<'_, WasmExecution>, value: f64, result_addr: i32| -> Result<(), Error> {
+        write_number(&mut caller, value, result_addr)
+    })?;
+
+    linker.func_wrap("env", "log", |mut caller: Caller<'_, WasmExecution>, string_addr: i32| -> Result<(), Error> {
+        let memory = get_memory(&caller)?;
+        let string = read_string(memory.data(&caller), string_addr);
+
+        caller.data_mut().logs.push(string);
+
+        Ok(())
+    })?;
+
+    linker.func_wrap("env", "log_int", |mut caller: Caller<'_, WasmExecution>, value: i32| {
+        caller.data_mut().logs.push(value.to_string());
+    })?;
+
+    linker.func_wrap("env", "get_current_time", || -> i32 {
+        get_current_timestamp() as i32
+    })?;
+
+    linker.func_wrap("env", "get_current_timestamp", || -> f64 {
+        get_current_timestamp() as f64
+    })?;
+
+    for import in module.imports() {
+        if let ExternType::Func(func_type) = import.ty() {
+            if PROVIDED_IMPORTS.contains(&(import.module(), import.name())) {
+                continue;
+            }
+
+            let message = format!("`{}.{}` is not available in the embedded interpreter", import.module(), import.name());
+
+            linker.func_new(import.module(), import.name(), func_type.clone(), move |_, _, _| Err(Error::new(message.clone())))?;
+        }
+    }
+
+    let instance = linker.instantiate(&mut *store, &module)?.start(&mut *store)?;
+    let initialize = instance.get_typed_func::<(), ()>(&*store, INITIALIZE_FUNCTION_NAME)?;
+    let main = instance.get_typed_func::<(), ()>(&*store, CLI_EXPORTED_FUNCTION_NAME)?;
+
+    initialize.call(&mut *store, ())?;
+    main.call(&mut *store, ())?;
+
+    Ok(())
+}
+
+fn get_current_timestamp() -> u128 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_millis()).unwrap_or_default()
+}
+
+fn write_number(caller: &mut Caller<'_, WasmExecution>, value: f64, result_addr: i32) -> Result<(), Error> {
+    let string = format_number(value);
+    let memory = get_memory(caller)?;
+    let data = memory.data_mut(caller);
+
+    write_word(data, result_addr, string.len() as i32);
+
+    for (i, c) in string.chars().enumerate() {
+        write_word(data, result_addr + 2 + i as i32, c as i32);
+    }
+
+    Ok(())
+}
+
+fn get_memory(caller: &Caller<'_, WasmExecution>) -> Result<Memory, Error> {
+    caller.get_export(EXPORTED_MEMORY_NAME)
+        .and_then(Extern::into_memory)
+        .ok_or_else(|| Error::new(format!("module does not export `{}`", EXPORTED_MEMORY_NAME)))
+}
+
+// Addresses are expressed in 32-bit words, as in the JavaScript runtime
+fn read_word(data: &[u8], addr: i32) -> i32 {
+    let start = addr as usize * 4;
+
+    match data.get(start..start + 4) {
+        Some(bytes) => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        None => 0,
+    }
+}
+
+fn write_word(data: &mut [u8], addr: i32, value: i32) {
+    let start = addr as usize * 4;
+
+    if let Some(bytes) = data.get_mut(start..start + 4) {
+        bytes.copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn read_string(data: &[u8], string_addr: i32) -> String {
+    if string_addr == 0 {
+        return "null".to_string();
+    }
+
+    let length = read_word(data, string_addr);
+
+    (0..length)
+        .map(|i| char::from_u32(read_word(data, string_addr + 2 + i) as u32).unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+// Formats a number the same way JavaScript's `Number.prototype.toString` does
+fn format_number(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+
+    if value.is_infinite() {
+        return match value > 0.0 {
+            true => "Infinity".to_string(),
+            false => "-Infinity".to_string(),
+        };
+    }
+
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    let absolute_value = value.abs();
+
+    if !(1e-6..1e21).contains(&absolute_value) {
+        let string = format!("{:e}", value);
+
+        return match string.split_once('e') {
+            Some((mantissa, exponent)) if !exponent.starts_with('-') => format!("{}e+{}", mantissa, exponent),
+            _ => string,
+        };
+    }
+
+    format!("{}", value)
+}
@@ -0,0 +1,198 @@
+use std::rc::Rc;
+use parsable::{FileInfo, ItemLocation};
+
+pub trait CachedItem : Sized {
+    fn write_to_cache(&self, writer: &mut CacheWriter);
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self>;
+}
+
+pub struct CacheWriter {
+    bytes: Vec<u8>
+}
+
+pub struct CacheReader<'a> {
+    bytes: &'a [u8],
+    index: usize,
+    file: Rc<FileInfo>
+}
+
+impl CacheWriter {
+    pub fn new() -> Self {
+        Self { bytes: vec![] }
+    }
+
+    pub fn write_usize(&mut self, value: usize) {
+        let mut value = value;
+
+        loop {
+            let byte = (value & 0x7f) as u8;
+
+            value >>= 7;
+
+            match value {
+                0 => {
+                    self.bytes.push(byte);
+                    break;
+                },
+                _ => self.bytes.push(byte | 0x80),
+            }
+        }
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_usize(bytes.len());
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl<'a> CacheReader<'a> {
+    pub fn new(bytes: &'a [u8], file: Rc<FileInfo>) -> Self {
+        Self { bytes, index: 0, file }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.index == self.bytes.len()
+    }
+
+    pub fn read_usize(&mut self) -> Option<usize> {
+        let mut result = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = *self.bytes.get(self.index)?;
+
+            self.index += 1;
+            result |= ((byte & 0x7f) as usize).checked_shl(shift)?;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Some(result);
+            }
+        }
+    }
+
+    pub fn read_u64(&mut self) -> Option<u64> {
+        let bytes = self.bytes.get(self.index..self.index + 8)?;
+
+        self.index += 8;
+
+        Some(u64::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    pub fn read_bytes(&mut self) -> Option<&'a [u8]> {
+        let length = self.read_usize()?;
+        let bytes = self.bytes.get(self.index..self.index.checked_add(length)?)?;
+
+        self.index += length;
+
+        Some(bytes)
+    }
+}
+
+impl CachedItem for ItemLocation {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        writer.write_usize(self.start);
+        writer.write_usize(self.end);
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        let file = reader.file.clone();
+        let start = reader.read_usize()?;
+        let end = reader.read_usize()?;
+
+        Some(ItemLocation { file, start, end })
+    }
+}
+
+impl CachedItem for String {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        writer.write_bytes(self.as_bytes());
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        String::from_utf8(reader.read_bytes()?.to_vec()).ok()
+    }
+}
+
+impl CachedItem for usize {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        writer.write_usize(*self);
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        reader.read_usize()
+    }
+}
+
+impl<T : CachedItem> CachedItem for Box<T> {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        self.as_ref().write_to_cache(writer);
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        T::read_from_cache(reader).map(Box::new)
+    }
+}
+
+impl<T : CachedItem> CachedItem for Option<T> {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        match self {
+            Some(value) => {
+                writer.write_usize(1);
+                value.write_to_cache(writer);
+            },
+            None => writer.write_usize(0),
+        }
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        match reader.read_usize()? {
+            0 => Some(None),
+            1 => Some(Some(T::read_from_cache(reader)?)),
+            _ => None
+        }
+    }
+}
+
+impl<T : CachedItem> CachedItem for Vec<T> {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        writer.write_usize(self.len());
+
+        for item in self {
+            item.write_to_cache(writer);
+        }
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        let length = reader.read_usize()?;
+        let mut result = vec![];
+
+        for _ in 0..length {
+            result.push(T::read_from_cache(reader)?);
+        }
+
+        Some(result)
+    }
+}
+
+impl<A : CachedItem, B : CachedItem> CachedItem for (A, B) {
+    fn write_to_cache(&self, writer: &mut CacheWriter) {
+        self.0.write_to_cache(writer);
+        self.1.write_to_cache(writer);
+    }
+
+    fn read_from_cache(reader: &mut CacheReader) -> Option<Self> {
+        let first = A::read_from_cache(reader)?;
+        let second = B::read_from_cache(reader)?;
+
+        Some((first, second))
+    }
+}
@@ -7,6 +7,8 @@ mod wrapper;
 mod file_system_cache;
 mod strings;
 mod perf_timer;
+mod cached_item;
+mod parse_cache;
 
 pub use link::*;
 pub use sorting::*;
@@ -16,4 +18,6 @@ pub use hash::*;
 pub use wrapper::*;
 pub use file_system_cache::*;
 pub use strings::*;
-pub use perf_timer::*;
\ No newline at end of file
+pub use perf_timer::*;
+pub use cached_item::*;
+pub use parse_cache::*;
\ No newline at end of file
@@ -0,0 +1,73 @@
+use std::{fs, path::{Path, PathBuf}, process, rc::Rc};
+use parsable::{FileInfo, ParseError, ParseOptions, Parsable};
+use crate::items::ParsedSourceFile;
+use super::{compute_hash, CachedItem, CacheWriter, CacheReader};
+
+const PARSE_CACHE_DIR_NAME : &str = "parse";
+
+include!(concat!(env!("OUT_DIR"), "/parse_cache_version.rs"));
+
+pub struct ParseCache {
+    directory_path: PathBuf
+}
+
+impl ParseCache {
+    pub fn new(cache_path: &Path) -> Self {
+        Self {
+            directory_path: cache_path.join(PARSE_CACHE_DIR_NAME)
+        }
+    }
+
+    pub fn parse(&self, file_content: String, parse_options: ParseOptions) -> Result<ParsedSourceFile, ParseError> {
+        let file_path = parse_options.file_path.clone().unwrap_or_default();
+        let package_root_path = parse_options.package_root_path.clone().unwrap_or_default();
+        let entry_path = self.directory_path.join(format!("{:016x}", compute_hash(&file_path)));
+        let content_hash = compute_hash(&file_content);
+
+        if let Ok(bytes) = fs::read(&entry_path) {
+            let file = Rc::new(FileInfo::new(file_content.clone(), file_path.clone(), package_root_path));
+            let mut reader = CacheReader::new(&bytes, file);
+
+            if let Some(parsed_source_file) = read_entry(&mut reader, &file_path, content_hash) {
+                return Ok(parsed_source_file);
+            }
+        }
+
+        let parsed_source_file = ParsedSourceFile::parse(file_content, parse_options)?;
+        let mut writer = CacheWriter::new();
+
+        writer.write_u64(PARSE_CACHE_VERSION);
+        writer.write_u64(content_hash);
+        file_path.write_to_cache(&mut writer);
+        parsed_source_file.write_to_cache(&mut writer);
+
+        // The cache is only an optimization: failing to write it must not fail the build
+        if fs::create_dir_all(&self.directory_path).is_ok() {
+            write_entry(&entry_path, &writer.into_bytes());
+        }
+
+        Ok(parsed_source_file)
+    }
+}
+
+fn read_entry(reader: &mut CacheReader, file_path: &str, content_hash: u64) -> Option<ParsedSourceFile> {
+    if reader.read_u64()? != PARSE_CACHE_VERSION || reader.read_u64()? != content_hash || String::read_from_cache(reader)? != file_path {
+        return None;
+    }
+
+    let parsed_source_file = ParsedSourceFile::read_from_cache(reader)?;
+
+    match reader.is_finished() {
+        true => Some(parsed_source_file),
+        false => None,
+    }
+}
+
+// The entry is written to a temporary file and then moved into place, so that a concurrent compilation never reads a partially written entry
+fn write_entry(entry_path: &Path, bytes: &[u8]) {
+    let tmp_path = entry_path.with_extension(format!("{}.tmp", process::id()));
+
+    if fs::write(&tmp_path, bytes).is_err() || fs::rename(&tmp_path, entry_path).is_err() {
+        fs::remove_file(&tmp_path).ok();
+    }
+}
@@ -48,4 +48,25 @@ pub fn is_blank_string(string: &str) -> bool {
 
 fn is_blank_character(c: u8) -> bool {
     c == b' ' || c == b'\n' || c == b'\r' || c == b'\t'
+}
+
+// Returns `None` if the characters of the query do not appear in order in the candidate (case insensitive), and otherwise a score that is lower for better matches
+pub fn get_fuzzy_match_score(query: &str, candidate: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let candidate = candidate.to_lowercase();
+    let mut candidate_chars = candidate.chars();
+
+    if !query.chars().all(|c| candidate_chars.any(|candidate_char| candidate_char == c)) {
+        return None;
+    }
+
+    if candidate == query {
+        Some(0)
+    } else if candidate.starts_with(&query) {
+        Some(1)
+    } else if candidate.contains(&query) {
+        Some(2)
+    } else {
+        Some(3)
+    }
 }
\ No newline at end of file